@@ -0,0 +1,638 @@
+//! Command-line tool for everyday Etherscan queries
+//!
+//! ```text
+//! keion-etherscan [--network NETWORK] [--api-key KEY] [--output table|json|csv] <COMMAND>
+//! ```
+//!
+//! The API key is read from `ETHERSCAN_API_KEY` when `--api-key` is not given.
+//! List commands (`txs`, `tokens`, `internal`, `logs`) follow pages
+//! automatically; `--limit` stops after a number of rows. Every command is a
+//! thin wrapper around the matching library builder.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use futures::{Stream, StreamExt, TryStreamExt};
+use keion_etherscan::export::{write_stream, Cell, CsvWriter, ExportRecord};
+use keion_etherscan::models::format_units;
+use keion_etherscan::{
+    chrono::DateTime, Closest, CodeFormat, EtherscanClient, EtherscanError, Network, Result,
+};
+use serde::Serialize;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Interval between verification status checks with `verify --wait`
+const VERIFY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Parser)]
+#[command(name = "keion-etherscan", version, about = "Query the Etherscan API")]
+struct Cli {
+    /// Etherscan API key
+    #[arg(long, global = true, env = "ETHERSCAN_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+
+    /// Network to query (mainnet, sepolia, bsc, polygon, ...)
+    #[arg(long, short, global = true, default_value = "mainnet")]
+    network: Network,
+
+    /// Output format
+    #[arg(long, short, global = true, value_enum, default_value_t = Format::Table)]
+    output: Format,
+
+    /// API URL overriding the network's default
+    #[arg(long, global = true, env = "ETHERSCAN_BASE_URL")]
+    base_url: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Aligned columns
+    Table,
+    /// JSON document
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// ETH balance of one or more addresses
+    Balance {
+        /// Addresses to look up
+        #[arg(required = true)]
+        addresses: Vec<String>,
+    },
+    /// Normal transactions of an address
+    Txs {
+        /// Account address
+        address: String,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Token transfers of an address
+    Tokens {
+        /// Account address
+        address: String,
+        /// Only include transfers of this token contract
+        #[arg(long)]
+        contract: Option<String>,
+        /// Token standard
+        #[arg(long, value_enum, default_value_t = Standard::Erc20)]
+        standard: Standard,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Internal transactions of an address
+    Internal {
+        /// Account address
+        address: String,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// ABI of a verified contract
+    Abi {
+        /// Contract address
+        address: String,
+    },
+    /// Source code and compiler settings of a verified contract
+    Source {
+        /// Contract address
+        address: String,
+        /// Print only the source code
+        #[arg(long)]
+        code: bool,
+    },
+    /// Creator and creation transaction of contracts
+    Creation {
+        /// Contract addresses
+        #[arg(required = true)]
+        addresses: Vec<String>,
+    },
+    /// Submit Solidity source code for verification
+    Verify(VerifyArgs),
+    /// Event logs by emitting address and topics
+    Logs {
+        /// Emitting contract address
+        #[arg(long)]
+        address: Option<String>,
+        /// Topic filter as INDEX=TOPIC, e.g. 0=0xddf2...; repeatable
+        #[arg(long = "topic", value_parser = parse_topic)]
+        topics: Vec<(usize, String)>,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Gas price suggestions
+    Gas,
+    /// Block number mined closest to a time
+    BlockByTime {
+        /// Unix timestamp in seconds or an RFC 3339 date and time
+        time: String,
+        /// Which side of the time to search
+        #[arg(long, value_enum, default_value_t = Side::Before)]
+        closest: Side,
+    },
+}
+
+#[derive(Debug, Args)]
+struct RangeArgs {
+    /// First block to include
+    #[arg(long)]
+    start_block: Option<u64>,
+    /// Last block to include
+    #[arg(long)]
+    end_block: Option<u64>,
+    /// Stop after this many rows
+    #[arg(long)]
+    limit: Option<usize>,
+    /// Results requested per page while following pages
+    #[arg(long)]
+    page_size: Option<u32>,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Contract address
+    address: String,
+    /// Source file: a single Solidity file, or standard JSON input with --standard-json
+    #[arg(long)]
+    source: std::path::PathBuf,
+    /// Contract name, e.g. `Token` or `contracts/Token.sol:Token` for standard JSON
+    #[arg(long)]
+    name: String,
+    /// Compiler version, e.g. v0.8.24+commit.e11b9ed9
+    #[arg(long)]
+    compiler: String,
+    /// Optimizer runs; omit when the optimizer was disabled
+    #[arg(long)]
+    runs: Option<u32>,
+    /// ABI-encoded constructor arguments, hex without 0x
+    #[arg(long)]
+    constructor_args: Option<String>,
+    /// EVM version the contract was compiled for
+    #[arg(long)]
+    evm_version: Option<String>,
+    /// License type number as listed by Etherscan
+    #[arg(long)]
+    license: Option<String>,
+    /// The source file is Solidity standard JSON input
+    #[arg(long)]
+    standard_json: bool,
+    /// Wait until the verification has passed or failed
+    #[arg(long)]
+    wait: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Standard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Side {
+    Before,
+    After,
+}
+
+fn parse_topic(value: &str) -> std::result::Result<(usize, String), String> {
+    let (index, topic) = value
+        .split_once('=')
+        .ok_or_else(|| "expected INDEX=TOPIC".to_string())?;
+    let index: usize = index
+        .parse()
+        .map_err(|_| "invalid topic index".to_string())?;
+    if index > 3 {
+        return Err("topic index must be between 0 and 3".to_string());
+    }
+    Ok((index, topic.to_string()))
+}
+
+/// Rows of strings printed as a table or CSV
+#[derive(Debug, Default)]
+struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new<S: Into<String>>(headers: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn from_records<T: ExportRecord>(records: &[T]) -> Self {
+        let mut table = Self::new(T::columns().iter().map(|column| column.name));
+        for record in records {
+            table.push(record.row().into_iter().map(|cell| match cell {
+                Cell::Null => String::new(),
+                Cell::Text(text) => text,
+                Cell::UInt64(value) => value.to_string(),
+                Cell::Bool(value) => value.to_string(),
+            }));
+        }
+        table
+    }
+
+    /// Two-column table of field names and values
+    fn fields<'a>(fields: impl IntoIterator<Item = (&'a str, String)>) -> Self {
+        let mut table = Self::new(["field", "value"]);
+        for (name, value) in fields {
+            table.push([name.to_string(), value]);
+        }
+        table
+    }
+
+    fn push<S: Into<String>>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    fn print(&self, format: Format) {
+        match format {
+            Format::Csv => {
+                for row in std::iter::once(&self.headers).chain(&self.rows) {
+                    let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+                    println!("{}", fields.join(","));
+                }
+            }
+            _ => {
+                let mut widths: Vec<usize> = self.headers.iter().map(|h| h.len()).collect();
+                for row in &self.rows {
+                    for (width, field) in widths.iter_mut().zip(row) {
+                        *width = (*width).max(field.chars().count());
+                    }
+                }
+
+                let line = |row: &[String]| {
+                    let fields: Vec<String> = row
+                        .iter()
+                        .zip(&widths)
+                        .map(|(field, width)| format!("{:<width$}", field, width = width))
+                        .collect();
+                    println!("{}", fields.join("  ").trim_end());
+                };
+                line(&self.headers);
+                let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+                line(&rules);
+                for row in &self.rows {
+                    line(row);
+                }
+            }
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Print a value as JSON, or as a table built from it
+fn print<T: Serialize>(format: Format, value: &T, table: impl FnOnce(&T) -> Table) -> Result<()> {
+    match format {
+        Format::Json => print_json(value),
+        _ => {
+            table(value).print(format);
+            Ok(())
+        }
+    }
+}
+
+/// Print every record of a paginated stream
+///
+/// CSV is written as the pages arrive; tables and JSON need every row first.
+async fn print_records<T, S>(format: Format, stream: S, limit: Option<usize>) -> Result<()>
+where
+    T: ExportRecord,
+    S: Stream<Item = Result<T>>,
+{
+    let stream = stream.take(limit.unwrap_or(usize::MAX));
+    match format {
+        Format::Csv => {
+            let mut writer = CsvWriter::new(std::io::stdout().lock());
+            write_stream(stream, &mut writer).await?;
+            drop(writer.finish()?);
+        }
+        Format::Json => print_json(&stream.try_collect::<Vec<T>>().await?)?,
+        Format::Table => Table::from_records(&stream.try_collect::<Vec<T>>().await?).print(format),
+    }
+    Ok(())
+}
+
+fn eth(wei: Option<u128>) -> String {
+    wei.and_then(|wei| i128::try_from(wei).ok())
+        .map(|wei| format_units(wei, 18))
+        .unwrap_or_default()
+}
+
+fn parse_time(time: &str) -> Result<u64> {
+    if let Ok(seconds) = time.parse() {
+        return Ok(seconds);
+    }
+    DateTime::parse_from_rfc3339(time)
+        .ok()
+        .and_then(|time| u64::try_from(time.timestamp()).ok())
+        .ok_or_else(|| EtherscanError::InvalidParams(format!("Invalid time: {}", time)))
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let api_key = cli.api_key.ok_or_else(|| {
+        EtherscanError::InvalidParams(
+            "An API key is required: pass --api-key or set ETHERSCAN_API_KEY".to_string(),
+        )
+    })?;
+    let mut builder = EtherscanClient::builder()
+        .api_key(api_key)
+        .network(cli.network);
+    if let Some(base_url) = cli.base_url {
+        builder = builder.base_url(base_url);
+    }
+    let client = builder.build()?;
+    let format = cli.output;
+
+    match cli.command {
+        Command::Balance { addresses } => {
+            let balances = if addresses.len() == 1 {
+                vec![client.accounts().balance(&addresses[0]).await?]
+            } else {
+                client
+                    .accounts()
+                    .balance_multi_batched(&addresses)
+                    .await?
+                    .into_result()?
+            };
+            print(format, &balances, |balances| {
+                let mut table = Table::new(["address", "balance_wei", "balance_eth"]);
+                for (balance, address) in balances.iter().zip(&addresses) {
+                    let account = balance.account.as_ref().map(|a| a.as_str().to_string());
+                    table.push([
+                        account.unwrap_or_else(|| address.to_lowercase()),
+                        balance.balance.as_str().to_string(),
+                        eth(balance.balance.as_u128()),
+                    ]);
+                }
+                table
+            })
+        }
+        Command::Txs { address, range } => {
+            let mut query = client.accounts().transactions(address);
+            if let Some(block) = range.start_block {
+                query = query.start_block(block);
+            }
+            if let Some(block) = range.end_block {
+                query = query.end_block(block);
+            }
+            if let Some(size) = range.page_size {
+                query = query.offset(size);
+            }
+            print_records(format, query.stream(), range.limit).await
+        }
+        Command::Tokens {
+            address,
+            contract,
+            standard,
+            range,
+        } => {
+            let accounts = client.accounts();
+            let mut query = match standard {
+                Standard::Erc20 => accounts.token_transfers(address),
+                Standard::Erc721 => accounts.nft_transfers(address),
+                Standard::Erc1155 => accounts.erc1155_transfers(address),
+            };
+            if let Some(contract) = contract {
+                query = query.contract_address(contract);
+            }
+            if let Some(block) = range.start_block {
+                query = query.start_block(block);
+            }
+            if let Some(block) = range.end_block {
+                query = query.end_block(block);
+            }
+            if let Some(size) = range.page_size {
+                query = query.offset(size);
+            }
+            print_records(format, query.stream(), range.limit).await
+        }
+        Command::Internal { address, range } => {
+            let mut query = client
+                .accounts()
+                .internal_transactions()
+                .by_address(address);
+            if let Some(block) = range.start_block {
+                query = query.start_block(block);
+            }
+            if let Some(block) = range.end_block {
+                query = query.end_block(block);
+            }
+            if let Some(size) = range.page_size {
+                query = query.offset(size);
+            }
+            print_records(format, query.stream(), range.limit).await
+        }
+        Command::Abi { address } => {
+            let abi = client.contracts().get_abi(address).await?;
+            let parsed = abi.parse_abi()?;
+            print(format, &parsed, |parsed| {
+                let mut table = Table::new(["type", "signature", "state_mutability"]);
+                for entry in parsed.as_array().into_iter().flatten() {
+                    let field = |key: &str| entry[key].as_str().unwrap_or_default().to_string();
+                    let inputs: Vec<String> = entry["inputs"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(|input| input["type"].as_str().unwrap_or_default().to_string())
+                        .collect();
+                    table.push([
+                        field("type"),
+                        format!("{}({})", field("name"), inputs.join(",")),
+                        field("stateMutability"),
+                    ]);
+                }
+                table
+            })
+        }
+        Command::Source { address, code } => {
+            let sources = client.contracts().get_source_code(address).await?;
+            let source = sources
+                .into_iter()
+                .next()
+                .ok_or_else(|| EtherscanError::parse("Empty getsourcecode response"))?;
+            if code {
+                println!("{}", source.source_code);
+                return Ok(());
+            }
+            print(format, &source, |source| {
+                Table::fields([
+                    ("contract_name", source.contract_name.clone()),
+                    ("compiler_version", source.compiler_version.clone()),
+                    ("optimized", source.is_optimized().to_string()),
+                    ("runs", source.optimization_runs().to_string()),
+                    ("evm_version", source.evm_version.clone()),
+                    ("license", source.license_type.clone()),
+                    ("proxy", source.is_proxy().to_string()),
+                    ("implementation", source.implementation.clone()),
+                    ("verified", source.is_verified().to_string()),
+                ])
+            })
+        }
+        Command::Creation { addresses } => {
+            let creations = client
+                .contracts()
+                .get_contract_creation_batched(&addresses)
+                .await?
+                .into_result()?;
+            print(format, &creations, |creations| {
+                let mut table = Table::new(["contract_address", "creator", "tx_hash"]);
+                for creation in creations {
+                    table.push([
+                        creation.contract_address.as_str(),
+                        creation.contract_creator.as_str(),
+                        creation.tx_hash.as_str(),
+                    ]);
+                }
+                table
+            })
+        }
+        Command::Verify(args) => verify(&client, format, args).await,
+        Command::Logs {
+            address,
+            topics,
+            range,
+        } => {
+            let mut query = client.logs().get_logs();
+            if let Some(address) = address {
+                query = query.address(address);
+            }
+            for (index, topic) in topics {
+                query = query.topic(index, topic);
+            }
+            if let Some(block) = range.start_block {
+                query = query.start_block(block);
+            }
+            if let Some(block) = range.end_block {
+                query = query.end_block(block);
+            }
+            if let Some(size) = range.page_size {
+                query = query.offset(size);
+            }
+            print_records(format, query.stream(), range.limit).await
+        }
+        Command::Gas => {
+            let oracle = client.stats().gas_oracle().await?;
+            print(format, &oracle, |oracle| {
+                Table::fields([
+                    ("last_block", oracle.block().to_string()),
+                    ("safe_gwei", oracle.safe_gas_price.clone()),
+                    ("propose_gwei", oracle.propose_gas_price.clone()),
+                    ("fast_gwei", oracle.fast_gas_price.clone()),
+                    (
+                        "base_fee_gwei",
+                        oracle.suggest_base_fee.clone().unwrap_or_default(),
+                    ),
+                ])
+            })
+        }
+        Command::BlockByTime { time, closest } => {
+            let timestamp = parse_time(&time)?;
+            let closest = match closest {
+                Side::Before => Closest::Before,
+                Side::After => Closest::After,
+            };
+            let block = client
+                .blocks()
+                .block_number_by_timestamp(timestamp, closest)
+                .await?;
+            print(
+                format,
+                &serde_json::json!({ "timestamp": timestamp, "block_number": block }),
+                |_| {
+                    Table::fields([
+                        ("timestamp", timestamp.to_string()),
+                        ("block_number", block.to_string()),
+                    ])
+                },
+            )
+        }
+    }
+}
+
+async fn verify(client: &EtherscanClient, format: Format, args: VerifyArgs) -> Result<()> {
+    let source = std::fs::read_to_string(&args.source).map_err(|e| {
+        EtherscanError::InvalidParams(format!("Failed to read {}: {}", args.source.display(), e))
+    })?;
+
+    let mut builder = client
+        .contracts()
+        .verify_solidity(&args.address)
+        .source_code(source)
+        .contract_name(args.name)
+        .compiler_version(args.compiler)
+        .optimization(args.runs.is_some(), args.runs.unwrap_or(200))
+        .code_format(if args.standard_json {
+            CodeFormat::SolidityStandardJsonInput
+        } else {
+            CodeFormat::SoliditySingleFile
+        });
+    if let Some(arguments) = args.constructor_args {
+        builder = builder.constructor_arguments(arguments);
+    }
+    if let Some(version) = args.evm_version {
+        builder = builder.evm_version(version);
+    }
+    if let Some(license) = args.license {
+        builder = builder.license_type(license);
+    }
+
+    let request = builder.submit().await?;
+    if !args.wait {
+        return print(format, &request, |request| {
+            Table::fields([("guid", request.guid.clone())])
+        });
+    }
+
+    let status = loop {
+        let status = client
+            .contracts()
+            .check_verification_status(&request.guid)
+            .await?;
+        if !status.is_pending() {
+            break status;
+        }
+        tokio::time::sleep(VERIFY_POLL_INTERVAL).await;
+    };
+
+    print(format, &status, |status| {
+        Table::fields([
+            ("guid", request.guid.clone()),
+            ("status", status.status.clone()),
+        ])
+    })?;
+    if status.is_verified() {
+        Ok(())
+    } else {
+        Err(EtherscanError::api(status.status))
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
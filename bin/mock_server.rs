@@ -0,0 +1,46 @@
+//! Standalone mock Etherscan server
+//!
+//! ```text
+//! keion-etherscan-mock [--port PORT] [--seed CHAIN.json] [--api-key KEY] [--rate-limit N]
+//! ```
+//!
+//! The seed file is a JSON-serialized `MockChain`. Point a client at the
+//! printed URL with `EtherscanClientBuilder::base_url`.
+
+use keion_etherscan::mock::{MockChain, MockConfig, MockServer};
+use std::net::SocketAddr;
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: keion-etherscan-mock [--port PORT] [--seed CHAIN.json] [--api-key KEY] [--rate-limit N]"
+    );
+    std::process::exit(2);
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut port = 8080u16;
+    let mut chain = MockChain::new();
+    let mut config = MockConfig::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--port" => port = value().parse().unwrap_or_else(|_| usage()),
+            "--seed" => chain = serde_json::from_str(&std::fs::read_to_string(value())?)?,
+            "--api-key" => config = config.api_key(value()),
+            "--rate-limit" => {
+                config = config.rate_limit(value().parse().unwrap_or_else(|_| usage()))
+            }
+            _ => usage(),
+        }
+    }
+
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+    let server = MockServer::bind(address, chain, config).await?;
+    println!("Mock Etherscan API listening on {}", server.url());
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -0,0 +1,424 @@
+//! Blocking account and token transfer endpoints
+
+use super::{Blocking, EtherscanClient, Iter};
+use crate::{
+    endpoints::accounts,
+    models::{
+        ActivityGroup, ActivityKind, Balance, BalanceSnapshot, BeaconWithdrawal,
+        InternalTransaction, ReconciliationReport, TokenBalance, TokenLedger, TokenTransfer,
+        Transaction, ValidatedBlock,
+    },
+    BatchResult, Result, Sort, Tag,
+};
+use chrono::{DateTime, Utc};
+
+/// Blocking account endpoints
+#[derive(Debug)]
+pub struct Accounts<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Accounts<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    fn inner(&self) -> accounts::Accounts<'a> {
+        self.client.inner.accounts()
+    }
+
+    /// Get the ETH balance of an address
+    pub fn balance<S: AsRef<str>>(&self, address: S) -> Result<Balance> {
+        self.client.runtime.block_on(self.inner().balance(address))
+    }
+
+    /// Get the ETH balance of an address at a block tag
+    pub fn balance_at_block<S: AsRef<str>>(&self, address: S, tag: Tag) -> Result<Balance> {
+        self.client
+            .runtime
+            .block_on(self.inner().balance_at_block(address, tag))
+    }
+
+    /// Get the ETH balances of up to 20 addresses
+    pub fn balance_multi<S: AsRef<str>>(&self, addresses: &[S]) -> Result<Vec<Balance>> {
+        self.client
+            .runtime
+            .block_on(self.inner().balance_multi(addresses))
+    }
+
+    /// Get the ETH balances of any number of addresses, 20 per request
+    pub fn balance_multi_batched<S: AsRef<str>>(
+        &self,
+        addresses: &[S],
+    ) -> Result<BatchResult<Balance>> {
+        self.client
+            .runtime
+            .block_on(self.inner().balance_multi_batched(addresses))
+    }
+
+    /// Get the ERC-20 token balances of an address
+    pub fn token_balances<S: AsRef<str>>(&self, address: S) -> Result<Vec<TokenBalance>> {
+        self.client
+            .runtime
+            .block_on(self.inner().token_balances(address))
+    }
+
+    /// Get the normal transactions of an address
+    pub fn transactions<S: AsRef<str>>(&self, address: S) -> TransactionQueryBuilder<'a> {
+        TransactionQueryBuilder(self.client.wrap(self.inner().transactions(address)))
+    }
+
+    /// Get internal transactions by address, hash or block range
+    pub fn internal_transactions(&self) -> InternalTransactionQueryBuilder<'a> {
+        InternalTransactionQueryBuilder(self.client.wrap(self.inner().internal_transactions()))
+    }
+
+    /// Get the ERC-20 token transfers of an address
+    pub fn token_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+        TokenTransferQueryBuilder(self.client.wrap(self.inner().token_transfers(address)))
+    }
+
+    /// Get the ERC-721 transfers of an address
+    pub fn nft_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+        TokenTransferQueryBuilder(self.client.wrap(self.inner().nft_transfers(address)))
+    }
+
+    /// Get the ERC-1155 transfers of an address
+    pub fn erc1155_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+        TokenTransferQueryBuilder(self.client.wrap(self.inner().erc1155_transfers(address)))
+    }
+
+    /// Get the blocks validated by an address
+    pub fn blocks_validated<S: AsRef<str>>(&self, address: S) -> ValidatedBlocksQueryBuilder<'a> {
+        ValidatedBlocksQueryBuilder(self.client.wrap(self.inner().blocks_validated(address)))
+    }
+
+    /// Get the beacon chain withdrawals of an address
+    pub fn beacon_withdrawals<S: AsRef<str>>(
+        &self,
+        address: S,
+    ) -> BeaconWithdrawalsQueryBuilder<'a> {
+        BeaconWithdrawalsQueryBuilder(self.client.wrap(self.inner().beacon_withdrawals(address)))
+    }
+
+    /// Get a chronological activity feed for an address
+    pub fn activity<S: AsRef<str>>(&self, address: S) -> ActivityQueryBuilder<'a> {
+        ActivityQueryBuilder(self.client.wrap(self.inner().activity(address)))
+    }
+
+    /// Reconcile the reconstructed ETH balance of an address against the chain
+    pub fn reconcile<S: AsRef<str>>(&self, address: S) -> ReconcileQueryBuilder<'a> {
+        ReconcileQueryBuilder(self.client.wrap(self.inner().reconcile(address)))
+    }
+
+    /// Build the token ledger of an address
+    pub fn token_ledger<S: AsRef<str>>(&self, address: S) -> TokenLedgerQueryBuilder<'a> {
+        TokenLedgerQueryBuilder(self.client.wrap(self.inner().token_ledger(address)))
+    }
+
+    /// Get the balance of an address at past blocks
+    pub fn historical_balance<S: AsRef<str>>(
+        &self,
+        address: S,
+    ) -> HistoricalBalanceQueryBuilder<'a> {
+        HistoricalBalanceQueryBuilder(self.client.wrap(self.inner().historical_balance(address)))
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for normal transaction queries
+    TransactionQueryBuilder => accounts::TransactionQueryBuilder {
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn sort(sort: Sort);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+    }
+}
+
+impl<'a> TransactionQueryBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<Transaction>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Iterate over every matching transaction, following pages
+    pub fn iter(self) -> Iter<'a, Transaction> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for token transfer queries
+    TokenTransferQueryBuilder => accounts::TokenTransferQueryBuilder {
+        fn contract_address<S: AsRef<str>>(contract_address: S);
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn sort(sort: Sort);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+    }
+}
+
+impl<'a> TokenTransferQueryBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<TokenTransfer>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Fetch every page of the query
+    pub fn execute_all(self) -> Result<Vec<TokenTransfer>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute_all())
+    }
+
+    /// Iterate over every matching transfer, following pages
+    pub fn iter(self) -> Iter<'a, TokenTransfer> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder choosing how to look up internal transactions
+    InternalTransactionQueryBuilder => accounts::InternalTransactionQueryBuilder {}
+}
+
+impl<'a> InternalTransactionQueryBuilder<'a> {
+    /// Query internal transactions of an address
+    pub fn by_address<S: AsRef<str>>(self, address: S) -> InternalTxByAddressBuilder<'a> {
+        InternalTxByAddressBuilder(self.0.map(|builder| builder.by_address(address)))
+    }
+
+    /// Query internal transactions created by a transaction
+    pub fn by_hash<S: AsRef<str>>(self, tx_hash: S) -> InternalTxByHashBuilder<'a> {
+        InternalTxByHashBuilder(self.0.map(|builder| builder.by_hash(tx_hash)))
+    }
+
+    /// Query internal transactions within a block range
+    pub fn by_block_range(
+        self,
+        start_block: u64,
+        end_block: u64,
+    ) -> InternalTxByBlockRangeBuilder<'a> {
+        InternalTxByBlockRangeBuilder(
+            self.0
+                .map(|builder| builder.by_block_range(start_block, end_block)),
+        )
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for internal transactions of an address
+    InternalTxByAddressBuilder => accounts::InternalTxByAddressBuilder {
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn sort(sort: Sort);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+    }
+}
+
+impl<'a> InternalTxByAddressBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<InternalTransaction>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Iterate over every matching internal transaction, following pages
+    pub fn iter(self) -> Iter<'a, InternalTransaction> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for internal transactions of a transaction
+    InternalTxByHashBuilder => accounts::InternalTxByHashBuilder {}
+}
+
+impl InternalTxByHashBuilder<'_> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<InternalTransaction>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for internal transactions within a block range
+    InternalTxByBlockRangeBuilder => accounts::InternalTxByBlockRangeBuilder {
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn sort(sort: Sort);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+    }
+}
+
+impl InternalTxByBlockRangeBuilder<'_> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<InternalTransaction>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for validated block queries
+    ValidatedBlocksQueryBuilder => accounts::ValidatedBlocksQueryBuilder {
+        fn page(page: u32);
+        fn offset(offset: u32);
+    }
+}
+
+impl<'a> ValidatedBlocksQueryBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<ValidatedBlock>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Iterate over every validated block, newest first, following pages
+    pub fn iter(self) -> Iter<'a, ValidatedBlock> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for beacon chain withdrawal queries
+    BeaconWithdrawalsQueryBuilder => accounts::BeaconWithdrawalsQueryBuilder {
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn sort(sort: Sort);
+    }
+}
+
+impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<BeaconWithdrawal>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Iterate over every matching withdrawal, following pages
+    pub fn iter(self) -> Iter<'a, BeaconWithdrawal> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for historical balance queries
+    HistoricalBalanceQueryBuilder => accounts::HistoricalBalanceQueryBuilder {
+        fn at_block(block_number: u64);
+        fn token<S: AsRef<str>>(contract_address: S);
+        fn range(start: u64, end: u64, step: u64);
+    }
+}
+
+impl HistoricalBalanceQueryBuilder<'_> {
+    /// Blocks sampled by the configured range
+    pub fn sample_blocks(&self) -> Result<Vec<u64>> {
+        self.0.builder.sample_blocks()
+    }
+
+    /// Get the balance at the configured block
+    pub fn execute(self) -> Result<Balance> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Get the balance at every sampled block of the range
+    pub fn execute_series(self) -> Result<Vec<BalanceSnapshot>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute_series())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for activity feeds
+    ActivityQueryBuilder => accounts::ActivityQueryBuilder {
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+        fn sort(sort: Sort);
+        fn offset(offset: u32);
+        fn kinds(kinds: &[ActivityKind]);
+        fn exclude(kind: ActivityKind);
+    }
+}
+
+impl ActivityQueryBuilder<'_> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<ActivityGroup>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for balance reconciliation
+    ReconcileQueryBuilder => accounts::ReconcileQueryBuilder {
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn block_range(start: u64, end: u64);
+        fn since(time: DateTime<Utc>);
+        fn until(time: DateTime<Utc>);
+        fn between(start: DateTime<Utc>, end: DateTime<Utc>);
+        fn checkpoints(blocks: &[u64]);
+        fn checkpoint_every(step: u64);
+    }
+}
+
+impl ReconcileQueryBuilder<'_> {
+    /// Execute the reconciliation
+    pub fn execute(self) -> Result<ReconciliationReport> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for token ledgers
+    TokenLedgerQueryBuilder => accounts::TokenLedgerQueryBuilder {
+        fn contract_address<S: AsRef<str>>(contract_address: S);
+        fn end_block(block: u64);
+        fn until(time: DateTime<Utc>);
+    }
+}
+
+impl TokenLedgerQueryBuilder<'_> {
+    /// Build the ledger
+    pub fn execute(self) -> Result<TokenLedger> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
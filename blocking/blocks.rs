@@ -0,0 +1,33 @@
+//! Blocking block endpoints
+
+use super::EtherscanClient;
+use crate::{Closest, Result};
+use chrono::{DateTime, Utc};
+
+/// Blocking block endpoints
+#[derive(Debug)]
+pub struct Blocks<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Blocks<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the block number closest to a point in time
+    pub fn block_number_by_time(&self, time: DateTime<Utc>, closest: Closest) -> Result<u64> {
+        let blocks = self.client.inner.blocks();
+        self.client
+            .runtime
+            .block_on(blocks.block_number_by_time(time, closest))
+    }
+
+    /// Get the block number closest to a Unix timestamp
+    pub fn block_number_by_timestamp(&self, timestamp: u64, closest: Closest) -> Result<u64> {
+        let blocks = self.client.inner.blocks();
+        self.client
+            .runtime
+            .block_on(blocks.block_number_by_timestamp(timestamp, closest))
+    }
+}
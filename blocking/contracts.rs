@@ -0,0 +1,165 @@
+//! Blocking contract and verification endpoints
+
+#[cfg(feature = "verification")]
+use super::Blocking;
+use super::EtherscanClient;
+#[cfg(feature = "verification")]
+use crate::models::{
+    CodeFormat, LibraryLink, OptimizationSettings, ProxyVerificationStatus, VerificationRequest,
+    VerificationStatus,
+};
+use crate::{
+    endpoints::contracts,
+    models::{ContractAbi, ContractCreation, ContractSource},
+    BatchResult, Result,
+};
+
+/// Blocking contract endpoints
+#[derive(Debug)]
+pub struct Contracts<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Contracts<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    fn inner(&self) -> contracts::Contracts<'a> {
+        self.client.inner.contracts()
+    }
+
+    /// Get the ABI of a verified contract
+    pub fn get_abi<S: AsRef<str>>(&self, address: S) -> Result<ContractAbi> {
+        self.client.runtime.block_on(self.inner().get_abi(address))
+    }
+
+    /// Get the source code of a verified contract
+    pub fn get_source_code<S: AsRef<str>>(&self, address: S) -> Result<Vec<ContractSource>> {
+        self.client
+            .runtime
+            .block_on(self.inner().get_source_code(address))
+    }
+
+    /// Get the creator and creation transaction of up to 5 contracts
+    pub fn get_contract_creation<S: AsRef<str>>(
+        &self,
+        addresses: &[S],
+    ) -> Result<Vec<ContractCreation>> {
+        self.client
+            .runtime
+            .block_on(self.inner().get_contract_creation(addresses))
+    }
+
+    /// Get the creation details of any number of contracts, 5 per request
+    pub fn get_contract_creation_batched<S: AsRef<str>>(
+        &self,
+        addresses: &[S],
+    ) -> Result<BatchResult<ContractCreation>> {
+        self.client
+            .runtime
+            .block_on(self.inner().get_contract_creation_batched(addresses))
+    }
+
+    /// Verify a Solidity contract
+    #[cfg(feature = "verification")]
+    pub fn verify_solidity<S: AsRef<str>>(&self, address: S) -> SolidityVerificationBuilder<'a> {
+        SolidityVerificationBuilder(self.client.wrap(self.inner().verify_solidity(address)))
+    }
+
+    /// Verify a Vyper contract
+    #[cfg(feature = "verification")]
+    pub fn verify_vyper<S: AsRef<str>>(&self, address: S) -> VyperVerificationBuilder<'a> {
+        VyperVerificationBuilder(self.client.wrap(self.inner().verify_vyper(address)))
+    }
+
+    /// Check the status of a verification request
+    #[cfg(feature = "verification")]
+    pub fn check_verification_status<S: AsRef<str>>(&self, guid: S) -> Result<VerificationStatus> {
+        self.client
+            .runtime
+            .block_on(self.inner().check_verification_status(guid))
+    }
+
+    /// Verify a proxy contract and link it to its implementation
+    #[cfg(feature = "verification")]
+    pub fn verify_proxy<S: AsRef<str>>(&self, address: S) -> ProxyVerificationBuilder<'a> {
+        ProxyVerificationBuilder(self.client.wrap(self.inner().verify_proxy(address)))
+    }
+
+    /// Check the status of a proxy verification request
+    #[cfg(feature = "verification")]
+    pub fn check_proxy_verification_status<S: AsRef<str>>(
+        &self,
+        guid: S,
+    ) -> Result<ProxyVerificationStatus> {
+        self.client
+            .runtime
+            .block_on(self.inner().check_proxy_verification_status(guid))
+    }
+}
+
+#[cfg(feature = "verification")]
+blocking_builder! {
+    /// Blocking builder for Solidity contract verification
+    SolidityVerificationBuilder => contracts::SolidityVerificationBuilder {
+        fn source_code<S: Into<String>>(source_code: S);
+        fn contract_name<S: Into<String>>(name: S);
+        fn compiler_version<S: Into<String>>(version: S);
+        fn optimization(enabled: bool, runs: u32);
+        fn optimization_settings(settings: OptimizationSettings);
+        fn constructor_arguments<S: Into<String>>(args: S);
+        fn code_format(format: CodeFormat);
+        fn library<S: Into<String>>(name: S, address: S);
+        fn libraries(libraries: Vec<LibraryLink>);
+        fn license_type<S: Into<String>>(license: S);
+        fn evm_version<S: Into<String>>(version: S);
+    }
+}
+
+#[cfg(feature = "verification")]
+impl SolidityVerificationBuilder<'_> {
+    /// Submit the verification request
+    pub fn submit(self) -> Result<VerificationRequest> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.submit())
+    }
+}
+
+#[cfg(feature = "verification")]
+blocking_builder! {
+    /// Blocking builder for Vyper contract verification
+    VyperVerificationBuilder => contracts::VyperVerificationBuilder {
+        fn source_code<S: Into<String>>(source_code: S);
+        fn contract_name<S: Into<String>>(name: S);
+        fn compiler_version<S: Into<String>>(version: S);
+        fn constructor_arguments<S: Into<String>>(args: S);
+        fn optimization(enabled: bool, runs: u32);
+    }
+}
+
+#[cfg(feature = "verification")]
+impl VyperVerificationBuilder<'_> {
+    /// Submit the verification request
+    pub fn submit(self) -> Result<VerificationRequest> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.submit())
+    }
+}
+
+#[cfg(feature = "verification")]
+blocking_builder! {
+    /// Blocking builder for proxy contract verification
+    ProxyVerificationBuilder => contracts::ProxyVerificationBuilder {
+        fn expected_implementation<S: Into<String>>(implementation: S);
+    }
+}
+
+#[cfg(feature = "verification")]
+impl ProxyVerificationBuilder<'_> {
+    /// Submit the verification request
+    pub fn submit(self) -> Result<VerificationRequest> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.submit())
+    }
+}
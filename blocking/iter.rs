@@ -0,0 +1,35 @@
+//! Blocking iteration over paginated streams
+
+use crate::Result;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use tokio::runtime::Runtime;
+
+/// Iterator over a paginated query, fetching pages as it advances
+pub struct Iter<'a, T> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = Result<T>> + 'a>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(runtime: &'a Runtime, stream: impl Stream<Item = Result<T>> + 'a) -> Self {
+        Self {
+            runtime,
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl<T> std::fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Iter").finish_non_exhaustive()
+    }
+}
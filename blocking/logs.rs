@@ -0,0 +1,48 @@
+//! Blocking event log endpoints
+
+use super::{Blocking, EtherscanClient, Iter};
+use crate::{endpoints::logs, models::TransactionLog, Result};
+
+/// Blocking event log endpoints
+#[derive(Debug)]
+pub struct Logs<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Logs<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Query event logs by emitting address and topics
+    pub fn get_logs(&self) -> LogQueryBuilder<'a> {
+        LogQueryBuilder(self.client.wrap(self.client.inner.logs().get_logs()))
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for event log queries
+    LogQueryBuilder => logs::LogQueryBuilder {
+        fn address<S: AsRef<str>>(address: S);
+        fn topic<S: AsRef<str>>(index: usize, topic: S);
+        fn page(page: u32);
+        fn offset(offset: u32);
+        fn start_block(block: u64);
+        fn end_block(block: u64);
+        fn block_range(start: u64, end: u64);
+    }
+}
+
+impl<'a> LogQueryBuilder<'a> {
+    /// Execute the query
+    pub fn execute(self) -> Result<Vec<TransactionLog>> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+
+    /// Iterate over every matching log in ascending block order, following pages
+    pub fn iter(self) -> Iter<'a, TransactionLog> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
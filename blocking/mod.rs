@@ -0,0 +1,258 @@
+//! Blocking client for code that does not run an async runtime
+//!
+//! [`EtherscanClient`] mirrors the async [`crate::EtherscanClient`]: the same
+//! endpoint groups, the same builders and the same models, except that
+//! `execute`/`submit` block the calling thread and paginated streams become
+//! iterators. Requests run on a single-threaded tokio runtime owned by the
+//! client.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::blocking::EtherscanClient;
+//!
+//! fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let balance = client
+//!         .accounts()
+//!         .balance("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")?;
+//!     println!("Balance: {} ETH", balance.eth().unwrap_or(0.0));
+//!
+//!     for transaction in client
+//!         .accounts()
+//!         .transactions("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!         .iter()
+//!     {
+//!         println!("{}", transaction?.hash);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # Panics
+//!
+//! Like any blocking API built on tokio, calls panic when made from inside an
+//! async runtime. Use the async client there, or move the blocking calls to
+//! [`tokio::task::spawn_blocking`].
+
+use crate::{EtherscanClientBuilder, EtherscanError, KeyUsage, Network, Result};
+use tokio::runtime::Runtime;
+
+/// Blocking Etherscan API client
+///
+/// Wraps an async [`crate::EtherscanClient`] together with the runtime that
+/// drives it.
+#[derive(Debug)]
+pub struct EtherscanClient {
+    inner: crate::EtherscanClient,
+    runtime: Runtime,
+}
+
+impl EtherscanClient {
+    /// Create a new client builder
+    ///
+    /// Configure it as usual and pass the result of `build()` to
+    /// [`EtherscanClient::from_async`], or use the `TryFrom` conversion.
+    pub fn builder() -> EtherscanClientBuilder {
+        EtherscanClientBuilder::new()
+    }
+
+    /// Create a client with just an API key (uses mainnet and defaults)
+    pub fn new<S: Into<String>>(api_key: S) -> Result<Self> {
+        Self::from_async(crate::EtherscanClient::new(api_key)?)
+    }
+
+    /// Wrap an already configured async client
+    pub fn from_async(inner: crate::EtherscanClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| EtherscanError::Internal(format!("Failed to start runtime: {}", e)))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Get the wrapped async client
+    pub fn as_async(&self) -> &crate::EtherscanClient {
+        &self.inner
+    }
+
+    /// Get the current network
+    pub fn network(&self) -> Network {
+        self.inner.network()
+    }
+
+    /// Get the API key, or the first of several (for debugging/logging)
+    pub fn api_key_preview(&self) -> String {
+        self.inner.api_key_preview()
+    }
+
+    /// Calls made today with each API key
+    pub fn key_usage(&self) -> Vec<KeyUsage> {
+        self.inner.key_usage()
+    }
+
+    /// Access account-related endpoints
+    #[cfg(feature = "accounts")]
+    pub fn accounts(&self) -> Accounts<'_> {
+        Accounts::new(self)
+    }
+
+    /// Access transaction-related endpoints
+    pub fn transactions(&self) -> Transactions<'_> {
+        Transactions::new(self)
+    }
+
+    /// Access contract-related endpoints
+    #[cfg(feature = "contracts")]
+    pub fn contracts(&self) -> Contracts<'_> {
+        Contracts::new(self)
+    }
+
+    /// Access block-related endpoints
+    pub fn blocks(&self) -> Blocks<'_> {
+        Blocks::new(self)
+    }
+
+    /// Access token-related endpoints
+    #[cfg(feature = "accounts")]
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self)
+    }
+
+    /// Access event log endpoints
+    #[cfg(feature = "logs")]
+    pub fn logs(&self) -> Logs<'_> {
+        Logs::new(self)
+    }
+
+    /// Access stats-related endpoints
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats<'_> {
+        Stats::new(self)
+    }
+
+    /// Access JSON-RPC proxy endpoints
+    #[cfg(feature = "proxy")]
+    pub fn proxy(&self) -> Proxy<'_> {
+        Proxy::new(self)
+    }
+
+    #[cfg(any(
+        feature = "accounts",
+        feature = "verification",
+        feature = "logs",
+        feature = "proxy"
+    ))]
+    fn wrap<'a, B>(&'a self, builder: B) -> Blocking<'a, B> {
+        Blocking {
+            runtime: &self.runtime,
+            builder,
+        }
+    }
+}
+
+impl TryFrom<crate::EtherscanClient> for EtherscanClient {
+    type Error = EtherscanError;
+
+    fn try_from(inner: crate::EtherscanClient) -> Result<Self> {
+        Self::from_async(inner)
+    }
+}
+
+/// Builder state shared by every blocking builder
+#[cfg(any(
+    feature = "accounts",
+    feature = "verification",
+    feature = "logs",
+    feature = "proxy"
+))]
+#[derive(Debug)]
+struct Blocking<'a, B> {
+    runtime: &'a Runtime,
+    builder: B,
+}
+
+#[cfg(any(
+    feature = "accounts",
+    feature = "verification",
+    feature = "logs",
+    feature = "proxy"
+))]
+impl<'a, B> Blocking<'a, B> {
+    fn map<C>(self, f: impl FnOnce(B) -> C) -> Blocking<'a, C> {
+        Blocking {
+            runtime: self.runtime,
+            builder: f(self.builder),
+        }
+    }
+}
+
+/// Declare a blocking builder wrapping an async one, forwarding its setters
+#[cfg(any(
+    feature = "accounts",
+    feature = "verification",
+    feature = "logs",
+    feature = "proxy"
+))]
+macro_rules! blocking_builder {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $module:ident::$inner:ident {
+            $(fn $setter:ident $(<$g:ident: $bound:path>)? ($($arg:ident: $ty:ty),*);)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name<'a>(pub(super) Blocking<'a, $module::$inner>);
+
+        impl<'a> $name<'a> {
+            $(
+                #[doc = concat!(
+                    "See [`", stringify!($inner), "::", stringify!($setter), "`]",
+                    "(crate::endpoints::", stringify!($module), "::", stringify!($inner),
+                    "::", stringify!($setter), ")"
+                )]
+                pub fn $setter $(<$g: $bound>)? (self, $($arg: $ty),*) -> Self {
+                    Self(self.0.map(|builder| builder.$setter($($arg),*)))
+                }
+            )*
+
+            /// Get the wrapped async builder
+            pub fn into_async(self) -> $module::$inner {
+                self.0.builder
+            }
+        }
+    };
+}
+
+#[cfg(feature = "accounts")]
+mod accounts;
+mod blocks;
+#[cfg(feature = "contracts")]
+mod contracts;
+#[cfg(any(feature = "accounts", feature = "logs", feature = "proxy"))]
+mod iter;
+#[cfg(feature = "logs")]
+mod logs;
+#[cfg(feature = "proxy")]
+mod proxy;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "accounts")]
+mod tokens;
+mod transactions;
+
+#[cfg(feature = "accounts")]
+pub use accounts::*;
+pub use blocks::Blocks;
+#[cfg(feature = "contracts")]
+pub use contracts::*;
+#[cfg(any(feature = "accounts", feature = "logs", feature = "proxy"))]
+pub use iter::Iter;
+#[cfg(feature = "logs")]
+pub use logs::*;
+#[cfg(feature = "proxy")]
+pub use proxy::*;
+#[cfg(feature = "stats")]
+pub use stats::Stats;
+#[cfg(feature = "accounts")]
+pub use tokens::*;
+pub use transactions::Transactions;
@@ -0,0 +1,113 @@
+//! Blocking JSON-RPC proxy endpoints
+
+use super::{Blocking, EtherscanClient, Iter};
+use crate::{
+    endpoints::proxy,
+    models::{Block, ConfirmationOutcome, RpcTransaction, TransactionReceipt},
+    Result,
+};
+use std::time::Duration;
+
+/// Blocking JSON-RPC proxy endpoints
+#[derive(Debug)]
+pub struct Proxy<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Proxy<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    fn inner(&self) -> proxy::Proxy<'a> {
+        self.client.inner.proxy()
+    }
+
+    /// Get the number of the most recent block
+    pub fn block_number(&self) -> Result<u64> {
+        self.client.runtime.block_on(self.inner().block_number())
+    }
+
+    /// Get a block by number, `None` if it does not exist yet
+    pub fn block_by_number(&self, block_number: u64) -> Result<Option<Block>> {
+        self.client
+            .runtime
+            .block_on(self.inner().block_by_number(block_number))
+    }
+
+    /// Get a transaction by hash
+    pub fn transaction_by_hash<S: AsRef<str>>(&self, tx_hash: S) -> Result<Option<RpcTransaction>> {
+        self.client
+            .runtime
+            .block_on(self.inner().transaction_by_hash(tx_hash))
+    }
+
+    /// Get the receipt of a mined transaction
+    pub fn transaction_receipt<S: AsRef<str>>(
+        &self,
+        tx_hash: S,
+    ) -> Result<Option<TransactionReceipt>> {
+        self.client
+            .runtime
+            .block_on(self.inner().transaction_receipt(tx_hash))
+    }
+
+    /// Get the number of transactions sent from an address
+    pub fn transaction_count<S: AsRef<str>>(&self, address: S) -> Result<u64> {
+        self.client
+            .runtime
+            .block_on(self.inner().transaction_count(address))
+    }
+
+    /// Follow the chain head, yielding every new block
+    pub fn watch_blocks(&self) -> BlockWatcher<'a> {
+        BlockWatcher(self.client.wrap(self.inner().watch_blocks()))
+    }
+
+    /// Wait until a transaction is `confirmations` blocks deep
+    pub fn wait_for_confirmations<S: AsRef<str>>(
+        &self,
+        tx_hash: S,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> ConfirmationWaitBuilder<'a> {
+        ConfirmationWaitBuilder(self.client.wrap(self.inner().wait_for_confirmations(
+            tx_hash,
+            confirmations,
+            timeout,
+        )))
+    }
+}
+
+blocking_builder! {
+    /// Blocking chain head follower
+    BlockWatcher => proxy::BlockWatcher {
+        fn poll_interval(interval: Duration);
+        fn start_block(block: u64);
+    }
+}
+
+impl<'a> BlockWatcher<'a> {
+    /// Iterate over new blocks, waiting for each one
+    ///
+    /// The iterator never ends on its own; stop after an error or once the
+    /// block of interest has been seen.
+    pub fn iter(self) -> Iter<'a, Block> {
+        let Blocking { runtime, builder } = self.0;
+        Iter::new(runtime, builder.stream())
+    }
+}
+
+blocking_builder! {
+    /// Blocking wait for transaction confirmations
+    ConfirmationWaitBuilder => proxy::ConfirmationWaitBuilder {
+        fn poll_interval(interval: Duration);
+    }
+}
+
+impl ConfirmationWaitBuilder<'_> {
+    /// Wait for the confirmations, the transaction being dropped or the timeout
+    pub fn execute(&self) -> Result<ConfirmationOutcome> {
+        self.0.runtime.block_on(self.0.builder.execute())
+    }
+}
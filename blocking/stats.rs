@@ -0,0 +1,22 @@
+//! Blocking stats endpoints
+
+use super::EtherscanClient;
+use crate::{models::GasOracle, Result};
+
+/// Blocking stats endpoints
+#[derive(Debug)]
+pub struct Stats<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Stats<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Get safe, standard and fast gas price suggestions
+    pub fn gas_oracle(&self) -> Result<GasOracle> {
+        let stats = self.client.inner.stats();
+        self.client.runtime.block_on(stats.gas_oracle())
+    }
+}
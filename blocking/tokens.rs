@@ -0,0 +1,53 @@
+//! Blocking token endpoints
+
+use super::{accounts::TokenTransferQueryBuilder, Blocking, EtherscanClient};
+use crate::{endpoints::tokens, models::HolderSnapshot, Result};
+
+/// Blocking token endpoints
+#[derive(Debug)]
+pub struct Tokens<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Tokens<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the transfers of a token contract
+    pub fn transfers<S: AsRef<str>>(&self, contract_address: S) -> TokenTransferQueryBuilder<'a> {
+        let tokens = self.client.inner.tokens();
+        TokenTransferQueryBuilder(self.client.wrap(tokens.transfers(contract_address)))
+    }
+
+    /// Get the holders of a token at a block
+    pub fn holder_snapshot<S: AsRef<str>>(
+        &self,
+        contract_address: S,
+        block_number: u64,
+    ) -> HolderSnapshotBuilder<'a> {
+        let tokens = self.client.inner.tokens();
+        HolderSnapshotBuilder(
+            self.client
+                .wrap(tokens.holder_snapshot(contract_address, block_number)),
+        )
+    }
+}
+
+blocking_builder! {
+    /// Blocking builder for token holder snapshots
+    HolderSnapshotBuilder => tokens::HolderSnapshotBuilder {
+        fn exclude<S: AsRef<str>>(address: S);
+        fn exclude_all<S: AsRef<str>>(addresses: &[S]);
+        fn include_zero_and_burn();
+        fn page_size(page_size: u32);
+    }
+}
+
+impl HolderSnapshotBuilder<'_> {
+    /// Build the snapshot
+    pub fn execute(self) -> Result<HolderSnapshot> {
+        let Blocking { runtime, builder } = self.0;
+        runtime.block_on(builder.execute())
+    }
+}
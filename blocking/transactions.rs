@@ -0,0 +1,31 @@
+//! Blocking transaction endpoints
+
+use super::EtherscanClient;
+#[cfg(all(feature = "accounts", feature = "proxy"))]
+use crate::models::TransactionDetails;
+use crate::{models::TransactionStatus, Result};
+
+/// Blocking transaction endpoints
+#[derive(Debug)]
+pub struct Transactions<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Transactions<'a> {
+    pub(super) fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the execution status of a transaction
+    pub fn status<S: AsRef<str>>(&self, tx_hash: S) -> Result<TransactionStatus> {
+        let transactions = self.client.inner.transactions();
+        self.client.runtime.block_on(transactions.status(tx_hash))
+    }
+
+    /// Get the full details of a transaction
+    #[cfg(all(feature = "accounts", feature = "proxy"))]
+    pub fn details<S: AsRef<str>>(&self, tx_hash: S) -> Result<TransactionDetails> {
+        let transactions = self.client.inner.transactions();
+        self.client.runtime.block_on(transactions.details(tx_hash))
+    }
+}
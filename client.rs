@@ -1,48 +1,170 @@
 use reqwest::Client;
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::Instrument;
 use url::Url;
 
+#[cfg(feature = "contracts")]
+use crate::endpoints::Contracts;
+#[cfg(feature = "logs")]
+use crate::endpoints::Logs;
+#[cfg(feature = "proxy")]
+use crate::endpoints::Proxy;
+#[cfg(feature = "stats")]
+use crate::endpoints::Stats;
+#[cfg(feature = "accounts")]
+use crate::endpoints::{Accounts, Tokens};
 use crate::{
-    endpoints::{Accounts, Blocks, Contracts, Stats, Tokens, Transactions},
-    error::{EtherscanError, Result},
-    types::{EtherscanResponse, Network},
+    endpoints::{Action, Blocks, Call, Endpoint, Module, Transactions},
+    error::{EtherscanError, RequestContext, Result, REDACTED},
+    keys::{ApiKey, KeyPool, KeySelection, KeyUsage, DEFAULT_KEY_COOLDOWN},
+    middleware::{Middleware, MiddlewareStack, Request, Response},
+    telemetry,
+    types::{Closest, EtherscanResponse, JsonRpcResponse, Network},
+    vcr::{Vcr, VcrMode},
 };
+#[cfg(all(feature = "accounts", feature = "proxy"))]
+use crate::{indexer::IndexerBuilder, watch::WatchBuilder};
+
+/// Base delay between retries, doubled after every attempt
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Block numbers resolved from timestamps, keyed by network, timestamp and direction
+type BlockTimeCache = std::sync::Mutex<HashMap<(Network, u64, Closest), u64>>;
 
 /// Main client for interacting with the Etherscan API
+///
+/// Cloning is cheap: clones share the HTTP connection pool, rate limiter,
+/// middleware and caches, so a clone can be moved into a spawned task.
 #[derive(Debug, Clone)]
 pub struct EtherscanClient {
+    inner: Arc<ClientInner>,
+}
+
+#[derive(Debug)]
+struct ClientInner {
     http_client: Client,
-    api_key: String,
+    keys: KeyPool,
     base_url: Url,
     network: Network,
+    middleware: MiddlewareStack,
+    max_retries: u32,
+    vcr: Option<Vcr>,
+    block_time_cache: BlockTimeCache,
+}
+
+/// Spaces out requests so that one API key of a client (and all of its
+/// clones) stays under the configured requests-per-second limit
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_second` requests per second
+    pub fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait until the next request slot is available
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
 }
 
 /// Builder for configuring the Etherscan client
 #[derive(Debug)]
 pub struct EtherscanClientBuilder {
-    api_key: Option<String>,
+    api_keys: Vec<ApiKey>,
+    key_selection: KeySelection,
+    key_cooldown: Duration,
+    key_usage_file: Option<PathBuf>,
     network: Network,
     timeout: Option<Duration>,
     user_agent: Option<String>,
     rate_limit: Option<u32>,
+    base_url: Option<String>,
+    middleware: MiddlewareStack,
+    max_retries: u32,
+    vcr: Option<(VcrMode, PathBuf)>,
 }
 
 impl EtherscanClientBuilder {
     /// Create a new builder with default settings
     pub fn new() -> Self {
         Self {
-            api_key: None,
+            api_keys: Vec::new(),
+            key_selection: KeySelection::default(),
+            key_cooldown: DEFAULT_KEY_COOLDOWN,
+            key_usage_file: None,
             network: Network::Mainnet,
             timeout: Some(Duration::from_secs(30)),
             user_agent: Some(format!("keion-etherscan/{}", env!("CARGO_PKG_VERSION"))),
             rate_limit: Some(5), // 5 requests per second default
+            base_url: None,
+            middleware: MiddlewareStack::default(),
+            max_retries: 0,
+            vcr: None,
         }
     }
 
-    /// Set the API key (required)
+    /// Set the API key (required unless [`api_keys`](Self::api_keys) is used)
     pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
-        self.api_key = Some(api_key.into());
+        self.api_keys = vec![ApiKey(api_key.into())];
+        self
+    }
+
+    /// Spread requests over several API keys
+    ///
+    /// Replaces any key set before. See [`crate::keys`].
+    pub fn api_keys<I, S>(mut self, api_keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.api_keys = api_keys
+            .into_iter()
+            .map(|api_key| ApiKey(api_key.into()))
+            .collect();
+        self
+    }
+
+    /// Set how the key for each request is picked (default: round-robin)
+    pub fn key_selection(mut self, selection: KeySelection) -> Self {
+        self.key_selection = selection;
+        self
+    }
+
+    /// Set how long a key is skipped after a rate-limit or invalid-key error
+    /// (default: 60 seconds)
+    pub fn key_cooldown(mut self, cooldown: Duration) -> Self {
+        self.key_cooldown = cooldown;
+        self
+    }
+
+    /// Persist daily call counters per key to a JSON file
+    ///
+    /// Today's counters are loaded from the file when the client is built.
+    /// New calls are added to it in the background and when the client is
+    /// dropped, keeping the counts of other clients sharing the file.
+    pub fn key_usage_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.key_usage_file = Some(path.into());
         self
     }
 
@@ -64,19 +186,57 @@ impl EtherscanClientBuilder {
         self
     }
 
-    /// Set rate limit in requests per second (default: 5)
+    /// Set rate limit in requests per second and API key (default: 5, 0 disables limiting)
     pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
         self.rate_limit = Some(requests_per_second);
         self
     }
 
+    /// Override the API base URL of the network (e.g. for a proxy or a local mock server)
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set how many times a retryable failure (network errors, 5xx responses,
+    /// rate limiting, timeouts) is retried with exponential backoff (default: 0)
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Add a middleware to the request chain
+    ///
+    /// Middleware runs in the order it was added. See [`crate::middleware`].
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Record every request/response pair to a cassette file
+    ///
+    /// Interactions are kept in memory and replace the file's contents when
+    /// the client is dropped or [`EtherscanClient::save_cassette`] is called.
+    /// See [`crate::vcr`].
+    pub fn record_to<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.vcr = Some((VcrMode::Record, path.into()));
+        self
+    }
+
+    /// Serve responses from a cassette file instead of the network
+    ///
+    /// Requests without a recorded counterpart fail. See [`crate::vcr`].
+    pub fn replay_from<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.vcr = Some((VcrMode::Replay, path.into()));
+        self
+    }
+
     /// Build the client
     pub fn build(self) -> Result<EtherscanClient> {
-        let api_key = self.api_key.ok_or(EtherscanError::MissingApiKey)?;
-
         let base_url = self
-            .network
-            .base_url()
+            .base_url
+            .as_deref()
+            .unwrap_or(self.network.base_url())
             .parse()
             .map_err(|e| EtherscanError::InvalidUrl(format!("Invalid base URL: {}", e)))?;
 
@@ -94,11 +254,31 @@ impl EtherscanClientBuilder {
             .build()
             .map_err(|e| EtherscanError::HttpClient(e.to_string()))?;
 
+        let keys = KeyPool::new(
+            self.api_keys,
+            self.rate_limit
+                .filter(|&requests_per_second| requests_per_second > 0),
+            self.key_selection,
+            self.key_cooldown,
+            self.key_usage_file,
+        )?;
+
+        let vcr = self
+            .vcr
+            .map(|(mode, path)| Vcr::new(mode, path))
+            .transpose()?;
+
         Ok(EtherscanClient {
-            http_client,
-            api_key,
-            base_url,
-            network: self.network,
+            inner: Arc::new(ClientInner {
+                http_client,
+                keys,
+                base_url,
+                network: self.network,
+                middleware: self.middleware,
+                max_retries: self.max_retries,
+                vcr,
+                block_time_cache: BlockTimeCache::default(),
+            }),
         })
     }
 }
@@ -122,21 +302,35 @@ impl EtherscanClient {
 
     /// Get the current network
     pub fn network(&self) -> Network {
-        self.network
+        self.inner.network
     }
 
-    /// Get the API key (for debugging/logging)
+    /// Get the API key, or the first of several (for debugging/logging)
     pub fn api_key_preview(&self) -> String {
-        let key = &self.api_key;
-        if key.len() > 8 {
-            format!("{}...{}", &key[..4], &key[key.len() - 4..])
-        } else {
-            "****".to_string()
+        self.inner.keys.key(0).preview()
+    }
+
+    /// Write the interactions recorded so far to the cassette file
+    ///
+    /// Does nothing unless the client was built with
+    /// [`record_to`](EtherscanClientBuilder::record_to). The cassette is also
+    /// saved when the last clone of the client is dropped, but errors can
+    /// only be reported here.
+    pub fn save_cassette(&self) -> Result<()> {
+        match &self.inner.vcr {
+            Some(vcr) if vcr.mode() == VcrMode::Record => vcr.save(),
+            _ => Ok(()),
         }
     }
 
+    /// Calls made today with each API key
+    pub fn key_usage(&self) -> Vec<KeyUsage> {
+        self.inner.keys.usage()
+    }
+
     // API endpoint accessors
     /// Access account-related endpoints
+    #[cfg(feature = "accounts")]
     pub fn accounts(&self) -> Accounts {
         Accounts::new(self)
     }
@@ -147,6 +341,7 @@ impl EtherscanClient {
     }
 
     /// Access contract-related endpoints
+    #[cfg(feature = "contracts")]
     pub fn contracts(&self) -> Contracts {
         Contracts::new(self)
     }
@@ -157,95 +352,462 @@ impl EtherscanClient {
     }
 
     /// Access token-related endpoints
+    #[cfg(feature = "accounts")]
     pub fn tokens(&self) -> Tokens {
         Tokens::new(self)
     }
 
+    /// Access event log endpoints
+    #[cfg(feature = "logs")]
+    pub fn logs(&self) -> Logs<'_> {
+        Logs::new(self)
+    }
+
     /// Access stats-related endpoints
+    #[cfg(feature = "stats")]
     pub fn stats(&self) -> Stats {
         Stats::new(self)
     }
 
+    /// Access JSON-RPC proxy endpoints
+    #[cfg(feature = "proxy")]
+    pub fn proxy(&self) -> Proxy<'_> {
+        Proxy::new(self)
+    }
+
+    /// Index address histories incrementally, resuming from checkpoints
+    ///
+    /// See the [`indexer`](crate::indexer) module for details.
+    #[cfg(all(feature = "accounts", feature = "proxy"))]
+    pub fn indexer(&self) -> IndexerBuilder {
+        IndexerBuilder::new(self)
+    }
+
+    /// Watch an address for new activity
+    ///
+    /// See the [`watch`](crate::watch) module for details.
+    #[cfg(all(feature = "accounts", feature = "proxy"))]
+    pub fn watch<S: AsRef<str>>(&self, address: S) -> WatchBuilder {
+        WatchBuilder::new(self, address.as_ref())
+    }
+
+    pub(crate) fn cached_block_by_time(&self, timestamp: u64, closest: Closest) -> Option<u64> {
+        self.inner
+            .block_time_cache
+            .lock()
+            .expect("block cache lock poisoned")
+            .get(&(self.inner.network, timestamp, closest))
+            .copied()
+    }
+
+    pub(crate) fn cache_block_by_time(&self, timestamp: u64, closest: Closest, block: u64) {
+        self.inner
+            .block_time_cache
+            .lock()
+            .expect("block cache lock poisoned")
+            .insert((self.inner.network, timestamp, closest), block);
+    }
+
+    /// Send any [`Endpoint`] and deserialize its result
+    ///
+    /// Goes through the same rate limiting, middleware, retries and
+    /// record/replay as the built-in calls.
+    pub async fn call<E: Endpoint>(&self, endpoint: &E) -> Result<E::Response> {
+        let request = Request::new(
+            self.inner.network,
+            endpoint.module().as_str(),
+            endpoint.action().as_str(),
+            endpoint.params()?,
+        );
+        self.execute(request, &endpoint.form()?).await
+    }
+
+    /// Call an action this crate does not model, returning the raw `result`
+    ///
+    /// Known module and action names may be passed as strings.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: keion_etherscan::EtherscanClient) -> keion_etherscan::Result<()> {
+    /// let price = client.raw("stats", "ethprice", &[]).await?;
+    /// println!("ETH/USD: {}", price["ethusd"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn raw(
+        &self,
+        module: impl Into<Module>,
+        action: impl Into<Action>,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value> {
+        self.call(&Call::new(module, action).with_params(params))
+            .await
+    }
+
     // Internal methods for making requests
+    #[cfg(any(feature = "accounts", feature = "logs"))]
     pub(crate) async fn get<T>(
         &self,
-        module: &str,
-        action: &str,
+        module: Module,
+        action: Action,
         params: &[(&str, &str)],
     ) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let response = self.make_request(module, action, params).await?;
-        self.parse_response(response).await
+        self.call(&Call::new(module, action).with_params(params))
+            .await
     }
 
-    pub(crate) async fn make_request(
+    /// Send `form` as a POST body, for actions that take large payloads
+    /// such as contract verification
+    #[cfg(feature = "verification")]
+    pub(crate) async fn post<T>(
         &self,
-        module: &str,
-        action: &str,
-        params: &[(&str, &str)],
-    ) -> Result<reqwest::Response> {
-        let mut url = self.base_url.clone();
+        module: Module,
+        action: Action,
+        form: Vec<(String, String)>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.call(&Call::new(module, action).with_form(form)).await
+    }
 
-        // Add query parameters
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.append_pair("module", module);
-            query_pairs.append_pair("action", action);
-            query_pairs.append_pair("apikey", &self.api_key);
+    /// Send a request, as a POST with `form` as its body when `form` is not empty
+    ///
+    /// The form is kept out of [`Request`] so middleware only sees the query.
+    async fn execute<T>(&self, mut request: Request, form: &[(String, String)]) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let span = telemetry::request_span(&request);
+        let started = Instant::now();
+
+        if let Err(error) = self.inner.middleware.on_request(&mut request) {
+            self.inner
+                .middleware
+                .on_error(&request, &error, started.elapsed());
+            telemetry::record_completion(&span, &request, started.elapsed(), 0, error.category());
+            return Err(error);
+        }
 
-            for (key, value) in params {
-                query_pairs.append_pair(key, value);
+        let mut retries = 0;
+        let result = loop {
+            let key = self.inner.keys.select();
+            span.record("api_key", self.inner.keys.key(key).fingerprint());
+
+            let result = match self
+                .make_request(&request, form, key)
+                .instrument(span.clone())
+                .await
+            {
+                Ok((status, text)) => self.parse_response(&request.action, &text).map_err(|e| {
+                    e.with_context(self.request_context(&request).with_response(status, &text))
+                }),
+                Err(error) => Err(error.with_context(self.request_context(&request))),
+            };
+            let result = result.map_err(|e| self.inner.keys.redact(e));
+
+            let error = match &result {
+                Err(error) => error,
+                Ok(_) => break result,
+            };
+
+            self.inner
+                .middleware
+                .on_error(&request, error, started.elapsed());
+
+            // A retry after a key went on cooldown goes to another key at once
+            let rotated = self.inner.keys.cool_down(key, error);
+            if retries >= self.inner.max_retries || !(error.is_retryable() || rotated) {
+                break result;
             }
+
+            let delay = match error {
+                _ if rotated => Duration::ZERO,
+                EtherscanError::RateLimit {
+                    retry_after: Some(seconds),
+                    ..
+                } => Duration::from_secs(*seconds),
+                _ => RETRY_BASE_DELAY * 2u32.saturating_pow(retries),
+            };
+            retries += 1;
+            telemetry::record_retry(&span, retries, error, delay);
+            tokio::time::sleep(delay).await;
+        };
+
+        telemetry::record_completion(
+            &span,
+            &request,
+            started.elapsed(),
+            retries,
+            telemetry::outcome(&result),
+        );
+
+        result
+    }
+
+    /// Send a request with the key at index `key` of the pool, returning the
+    /// status code and body of a successful response
+    async fn make_request(
+        &self,
+        request: &Request,
+        form: &[(String, String)],
+        key: usize,
+    ) -> Result<(u16, String)> {
+        let started = Instant::now();
+        let (status, text) = match &self.inner.vcr {
+            Some(vcr) if vcr.mode() == VcrMode::Replay => vcr.replay(request, form)?,
+            Some(vcr) => {
+                let (status, text) = self.send(request, form, key).await?;
+                vcr.record(request, form, status, &text);
+                (status, text)
+            }
+            None => self.send(request, form, key).await?,
+        };
+
+        self.inner.middleware.on_response(
+            request,
+            &Response {
+                status,
+                body: &text,
+                elapsed: started.elapsed(),
+            },
+        );
+
+        if !(200..300).contains(&status) {
+            let context = self.request_context(request).with_response(status, &text);
+            return Err(EtherscanError::Http {
+                status,
+                message: context.body.clone().unwrap_or_default(),
+                context: Some(Box::new(context)),
+            });
         }
 
-        let request = self.http_client.get(url);
-        let response = request
+        Ok((status, text))
+    }
+
+    /// Send a request over HTTP, returning the status code and body
+    async fn send(
+        &self,
+        request: &Request,
+        form: &[(String, String)],
+        key: usize,
+    ) -> Result<(u16, String)> {
+        let url = self.url(request, &self.inner.keys.key(key).0);
+        self.inner.keys.acquire(key).await;
+
+        let builder = if !form.is_empty() {
+            self.inner.http_client.post(url).form(form)
+        } else {
+            self.inner.http_client.get(url)
+        };
+
+        let response = builder
+            .headers(request.headers.clone())
             .send()
             .await
-            .map_err(|e| EtherscanError::Request(e.to_string()))?;
+            .map_err(EtherscanError::from)?;
 
-        if !response.status().is_success() {
-            return Err(EtherscanError::Http {
-                status: response.status().as_u16(),
-                message: response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string()),
-            });
+        let status = response.status().as_u16();
+        let text = response.text().await.map_err(|e| {
+            EtherscanError::Response(format!("Failed to read response: {}", e.without_url()))
+        })?;
+
+        Ok((status, text))
+    }
+
+    /// Full URL of a request, authenticated with `api_key`
+    fn url(&self, request: &Request, api_key: &str) -> Url {
+        let mut url = self.inner.base_url.clone();
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            query_pairs.append_pair("module", &request.module);
+            query_pairs.append_pair("action", &request.action);
+            query_pairs.append_pair("apikey", api_key);
+
+            for (key, value) in &request.params {
+                query_pairs.append_pair(key, value);
+            }
         }
+        url
+    }
 
-        Ok(response)
+    /// Describe a request for errors, without the API key
+    fn request_context(&self, request: &Request) -> RequestContext {
+        RequestContext {
+            network: request.network,
+            module: request.module.clone(),
+            action: request.action.clone(),
+            url: self.url(request, REDACTED).to_string(),
+            status: None,
+            body: None,
+        }
     }
 
-    async fn parse_response<T>(&self, response: reqwest::Response) -> Result<T>
+    fn parse_response<T>(&self, action: &str, text: &str) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let text = response
-            .text()
-            .await
-            .map_err(|e| EtherscanError::Response(format!("Failed to read response: {}", e)))?;
-
         // First try to parse as EtherscanResponse wrapper
-        match serde_json::from_str::<EtherscanResponse<T>>(&text) {
-            Ok(wrapper) => match wrapper.status.as_str() {
-                "1" => Ok(wrapper.result),
-                "0" => Err(EtherscanError::Api {
-                    message: wrapper.message,
-                    result: None,
-                }),
-                _ => Err(EtherscanError::Response(format!(
-                    "Unknown status: {}",
-                    wrapper.status
-                ))),
-            },
+        let wrapper = match serde_json::from_str::<EtherscanResponse<serde_json::Value>>(text) {
+            Ok(wrapper) => wrapper,
             Err(_) => {
+                // The proxy module answers with a JSON-RPC envelope instead
+                if let Ok(rpc) = serde_json::from_str::<JsonRpcResponse>(text) {
+                    if let Some(error) = rpc.error {
+                        return Err(EtherscanError::api_with_result(
+                            format!("JSON-RPC error {}", error.code),
+                            error.message,
+                        ));
+                    }
+                    return serde_json::from_value(rpc.result.unwrap_or_default())
+                        .map_err(EtherscanError::from);
+                }
+
                 // Fallback: try to parse directly as T
-                serde_json::from_str(&text)
-                    .map_err(|e| EtherscanError::Parse(format!("JSON parse error: {}", e)))
+                return serde_json::from_str(text).map_err(EtherscanError::from);
+            }
+        };
+
+        match wrapper.status.as_str() {
+            "1" => serde_json::from_value(wrapper.result).map_err(EtherscanError::from),
+            // List endpoints report "No transactions found" as status 0 with an empty list
+            "0" if wrapper.result.as_array().is_some_and(Vec::is_empty) => {
+                serde_json::from_value(wrapper.result).map_err(EtherscanError::from)
+            }
+            "0" => Err(Self::api_error(action, wrapper)),
+            _ => Err(EtherscanError::Response(format!(
+                "Unknown status: {}",
+                wrapper.status
+            ))),
+        }
+    }
+
+    /// Map a status `0` response to the matching error
+    fn api_error(action: &str, wrapper: EtherscanResponse<serde_json::Value>) -> EtherscanError {
+        let result = match wrapper.result {
+            serde_json::Value::String(result) => result,
+            serde_json::Value::Null => {
+                return EtherscanError::api(wrapper.message);
+            }
+            other => other.to_string(),
+        };
+
+        let lowercase = result.to_lowercase();
+        if lowercase.contains("rate limit") {
+            EtherscanError::rate_limit(result, None)
+        } else if lowercase.contains("api pro") {
+            EtherscanError::plan_restricted(action, result)
+        } else {
+            EtherscanError::api_with_result(wrapper.message, result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Balance, HexNumber, Transaction};
+
+    fn client() -> EtherscanClient {
+        EtherscanClient::new("test-api-key").unwrap()
+    }
+
+    #[test]
+    fn test_parse_success_response() {
+        let text = r#"{"status":"1","message":"OK","result":[{"account":"0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6","balance":"1000"}]}"#;
+        let balances: Vec<Balance> = client().parse_response("balance", text).unwrap();
+        assert_eq!(balances[0].wei(), "1000");
+    }
+
+    #[test]
+    fn test_parse_empty_list_response() {
+        let text = r#"{"status":"0","message":"No transactions found","result":[]}"#;
+        let transactions: Vec<Transaction> = client().parse_response("txlist", text).unwrap();
+        assert!(transactions.is_empty());
+    }
+
+    #[test]
+    fn test_parse_api_error_response() {
+        let text = r#"{"status":"0","message":"NOTOK","result":"Error! Invalid address format"}"#;
+        let error = client()
+            .parse_response::<Vec<Transaction>>("txlist", text)
+            .unwrap_err();
+
+        match error {
+            EtherscanError::Api {
+                message, result, ..
+            } => {
+                assert_eq!(message, "NOTOK");
+                assert_eq!(result.as_deref(), Some("Error! Invalid address format"));
             }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_rate_limit_response() {
+        let text = r#"{"status":"0","message":"NOTOK","result":"Max calls per sec rate limit reached (5/sec)"}"#;
+        let error = client()
+            .parse_response::<Vec<Transaction>>("txlist", text)
+            .unwrap_err();
+
+        assert!(matches!(error, EtherscanError::RateLimit { .. }));
+        assert!(error.is_retryable());
+        assert_eq!(telemetry::outcome::<()>(&Err(error)), "rate_limit");
+    }
+
+    #[test]
+    fn test_parse_plan_restricted_response() {
+        let text = r#"{"status":"0","message":"NOTOK","result":"Sorry, it looks like you are trying to access an API Pro endpoint. Contact us to upgrade to API Pro."}"#;
+        let error = client()
+            .parse_response::<Balance>("balancehistory", text)
+            .unwrap_err();
+
+        match &error {
+            EtherscanError::PlanRestricted { action, .. } => assert_eq!(action, "balancehistory"),
+            other => panic!("unexpected error: {:?}", other),
         }
+        assert!(!error.is_retryable());
+        assert_eq!(error.category(), "plan");
+    }
+
+    #[test]
+    fn test_parse_json_rpc_response() {
+        let text = r#"{"jsonrpc":"2.0","id":1,"result":"0x10d4f"}"#;
+        let block: HexNumber = client().parse_response("eth_blockNumber", text).unwrap();
+        assert_eq!(block.value(), 68943);
+
+        let text = r#"{"jsonrpc":"2.0","id":1,"result":null}"#;
+        let receipt: Option<HexNumber> = client()
+            .parse_response("eth_getTransactionReceipt", text)
+            .unwrap();
+        assert!(receipt.is_none());
+
+        let text =
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"invalid argument 0"}}"#;
+        let error = client()
+            .parse_response::<HexNumber>("eth_getBlockByNumber", text)
+            .unwrap_err();
+        match error {
+            EtherscanError::Api { result, .. } => {
+                assert_eq!(result.as_deref(), Some("invalid argument 0"))
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spaces_requests() {
+        let limiter = RateLimiter::new(20);
+        let started = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        // The first slot is immediate, the next two wait 50ms each
+        assert!(started.elapsed() >= Duration::from_millis(100));
     }
 }
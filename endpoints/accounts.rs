@@ -1,13 +1,28 @@
+use chrono::{DateTime, Utc};
+use futures::{Stream, TryStreamExt};
+
+pub use crate::endpoints::paging::MAX_RESULT_WINDOW;
 use crate::{
+    endpoints::{
+        batch::{execute_chunked, BatchResult},
+        paging::{paginate, PagedQuery},
+        Action, Endpoint, Module,
+    },
     error::validation::normalize_address,
     models::{
-        Balance, BeaconWithdrawal, InternalTransaction, TokenBalance, TokenTransfer, Transaction,
+        timeline, ActivityEvent, ActivityGroup, ActivityKind, Balance, BalanceReplay,
+        BalanceSnapshot, BeaconWithdrawal, Checkpoint, Direction, InternalTransaction,
+        ReconciliationReport, TokenBalance, TokenLedger, TokenTransfer, Transaction, TxHash,
         ValidatedBlock,
     },
-    types::{Pagination, Sort, Tag, TransactionType},
+    spec::QuerySpec,
+    types::{Closest, Pagination, Sort, Tag, TransactionType},
     EtherscanClient, EtherscanError, Result,
 };
 
+/// Maximum number of addresses accepted by a single `balancemulti` call
+pub const MAX_BALANCE_MULTI_ADDRESSES: usize = 20;
+
 /// Account-related API endpoints
 #[derive(Debug)]
 pub struct Accounts<'a> {
@@ -38,21 +53,14 @@ impl<'a> Accounts<'a> {
     /// }
     /// ```
     pub async fn balance<S: AsRef<str>>(&self, address: S) -> Result<Balance> {
-        let address = normalize_address(address.as_ref())?;
-
-        let params = [("address", address.as_str()), ("tag", "latest")];
-
-        self.client.get("account", "balance", &params).await
+        self.client
+            .call(&GetBalance::new(address, Tag::Latest))
+            .await
     }
 
     /// Get ETH balance for a single address at a specific block
     pub async fn balance_at_block<S: AsRef<str>>(&self, address: S, tag: Tag) -> Result<Balance> {
-        let address = normalize_address(address.as_ref())?;
-        let tag_str = tag.as_str();
-
-        let params = [("address", address.as_str()), ("tag", tag_str.as_str())];
-
-        self.client.get("account", "balance", &params).await
+        self.client.call(&GetBalance::new(address, tag)).await
     }
 
     /// Get ETH balance for multiple addresses (up to 20)
@@ -77,29 +85,59 @@ impl<'a> Accounts<'a> {
     /// }
     /// ```
     pub async fn balance_multi<S: AsRef<str>>(&self, addresses: &[S]) -> Result<Vec<Balance>> {
+        self.client.call(&GetBalanceMulti::new(addresses)).await
+    }
+
+    /// Get ETH balances for any number of addresses
+    ///
+    /// The addresses are split into chunks of 20, which are fetched concurrently
+    /// under the client's rate limit. Results are returned in input order and a
+    /// failed chunk does not discard the balances of the other chunks.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let addresses: Vec<String> = Vec::new(); // thousands of addresses
+    ///     let batch = client.accounts().balance_multi_batched(&addresses).await?;
+    ///     for failure in batch.failures() {
+    ///         eprintln!("chunk {} failed: {:?}", failure.index, failure.result);
+    ///     }
+    ///     let balances = batch.into_results();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn balance_multi_batched<S: AsRef<str>>(
+        &self,
+        addresses: &[S],
+    ) -> Result<BatchResult<Balance>> {
         if addresses.is_empty() {
             return Err(EtherscanError::InvalidParams(
                 "At least one address required".to_string(),
             ));
         }
 
-        if addresses.len() > 20 {
-            return Err(EtherscanError::InvalidParams(
-                "Maximum 20 addresses allowed".to_string(),
-            ));
-        }
-
-        // Validate and normalize all addresses
         let normalized: Result<Vec<String>> = addresses
             .iter()
             .map(|addr| normalize_address(addr.as_ref()))
             .collect();
-        let normalized = normalized?;
 
-        let address_list = normalized.join(",");
-        let params = [("address", address_list.as_str()), ("tag", "latest")];
-
-        self.client.get("account", "balancemulti", &params).await
+        Ok(execute_chunked(
+            normalized?,
+            MAX_BALANCE_MULTI_ADDRESSES,
+            |chunk| async move { self.balance_multi(&chunk).await },
+            |balance: &Balance| {
+                balance
+                    .account
+                    .as_ref()
+                    .map(|account| account.as_str().to_string())
+                    .unwrap_or_default()
+            },
+        )
+        .await)
     }
 
     /// Get normal transactions for an address
@@ -108,80 +146,304 @@ impl<'a> Accounts<'a> {
     /// * `address` - Ethereum address to query
     ///
     /// Returns a `TransactionQueryBuilder` for further configuration
-    pub fn transactions<S: AsRef<str>>(&self, address: S) -> TransactionQueryBuilder<'a> {
+    pub fn transactions<S: AsRef<str>>(&self, address: S) -> TransactionQueryBuilder {
         TransactionQueryBuilder::new(self.client, address.as_ref(), TransactionType::Normal)
     }
 
     /// Get internal transactions - returns a builder for various query types
-    pub fn internal_transactions(&self) -> InternalTransactionQueryBuilder<'a> {
+    pub fn internal_transactions(&self) -> InternalTransactionQueryBuilder {
         InternalTransactionQueryBuilder::new(self.client)
     }
 
     /// Get ERC-20 token transfers for an address
-    pub fn token_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+    pub fn token_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder {
         TokenTransferQueryBuilder::new(self.client, address.as_ref(), TransactionType::Token)
     }
 
     /// Get ERC-721 NFT transfers for an address
-    pub fn nft_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+    pub fn nft_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder {
         TokenTransferQueryBuilder::new(self.client, address.as_ref(), TransactionType::TokenNft)
     }
 
     /// Get ERC-1155 token transfers for an address
-    pub fn erc1155_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder<'a> {
+    pub fn erc1155_transfers<S: AsRef<str>>(&self, address: S) -> TokenTransferQueryBuilder {
         TokenTransferQueryBuilder::new(self.client, address.as_ref(), TransactionType::Token1155)
     }
 
     /// Get list of ERC-20 tokens owned by an address
     pub async fn token_balances<S: AsRef<str>>(&self, address: S) -> Result<Vec<TokenBalance>> {
-        let address = normalize_address(address.as_ref())?;
-
-        let params = [("address", address.as_str()), ("tag", "latest")];
-
-        self.client.get("account", "tokenlist", &params).await
+        self.client.call(&GetTokenList::new(address)).await
     }
 
     /// Get list of blocks validated by address (for validators)
-    pub fn blocks_validated<S: AsRef<str>>(&self, address: S) -> ValidatedBlocksQueryBuilder<'a> {
+    pub fn blocks_validated<S: AsRef<str>>(&self, address: S) -> ValidatedBlocksQueryBuilder {
         ValidatedBlocksQueryBuilder::new(self.client, address.as_ref())
     }
 
     /// Get beacon chain withdrawals for an address
-    pub fn beacon_withdrawals<S: AsRef<str>>(
-        &self,
-        address: S,
-    ) -> BeaconWithdrawalsQueryBuilder<'a> {
+    pub fn beacon_withdrawals<S: AsRef<str>>(&self, address: S) -> BeaconWithdrawalsQueryBuilder {
         BeaconWithdrawalsQueryBuilder::new(self.client, address.as_ref())
     }
 
+    /// Get a chronological activity feed for an address
+    ///
+    /// Fetches normal and internal transactions, token transfers of every
+    /// standard and beacon withdrawals concurrently and merges them into one
+    /// timeline grouped by transaction hash.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::{ActivityKind, EtherscanClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let feed = client
+    ///         .accounts()
+    ///         .activity("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+    ///         .block_range(18_000_000, 18_100_000)
+    ///         .exclude(ActivityKind::BeaconWithdrawal)
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for group in feed {
+    ///         println!("{} {:?} {} events", group.block_number, group.direction(), group.events.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn activity<S: AsRef<str>>(&self, address: S) -> ActivityQueryBuilder {
+        ActivityQueryBuilder::new(self.client, address.as_ref())
+    }
+
+    /// Reconcile an address's ETH history against on-chain balances
+    ///
+    /// Replays normal transactions (value and gas fees paid as sender),
+    /// internal transactions, beacon withdrawals and validated-block rewards
+    /// into a running balance, then compares it with the on-chain balance read
+    /// through [`historical_balance`](Self::historical_balance) at checkpoint
+    /// blocks.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let report = client
+    ///         .accounts()
+    ///         .reconcile("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+    ///         .block_range(18_000_000, 18_100_000)
+    ///         .checkpoint_every(10_000)
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for discrepancy in &report.discrepancies {
+    ///         println!(
+    ///             "{} wei unexplained in blocks {}..={}",
+    ///             discrepancy.introduced, discrepancy.start_block, discrepancy.end_block
+    ///         );
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn reconcile<S: AsRef<str>>(&self, address: S) -> ReconcileQueryBuilder {
+        ReconcileQueryBuilder::new(self.client, address.as_ref())
+    }
+
+    /// Build a token ledger for an address from its transfer history
+    ///
+    /// Fetches ERC-20, ERC-721 and ERC-1155 transfers concurrently. The
+    /// resulting [`TokenLedger`] reports per-token flows, held NFTs and
+    /// ERC-1155 balances, and can be rewound to any earlier block.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let ledger = client
+    ///         .accounts()
+    ///         .token_ledger("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for flow in ledger.at_block(18_000_000).flows()? {
+    ///         println!("{} net {}", flow.token_symbol, flow.net_units());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn token_ledger<S: AsRef<str>>(&self, address: S) -> TokenLedgerQueryBuilder {
+        TokenLedgerQueryBuilder::new(self.client, address.as_ref())
+    }
+
     /// Get historical balance for a single address at a specific block
-    pub fn historical_balance<S: AsRef<str>>(
-        &self,
-        address: S,
-    ) -> HistoricalBalanceQueryBuilder<'a> {
+    pub fn historical_balance<S: AsRef<str>>(&self, address: S) -> HistoricalBalanceQueryBuilder {
         HistoricalBalanceQueryBuilder::new(self.client, address.as_ref())
     }
 }
 
+/// The `balance` action, see [`Accounts::balance`]
+#[derive(Debug, Clone)]
+pub struct GetBalance {
+    address: String,
+    tag: Tag,
+}
+
+impl GetBalance {
+    /// Request the ETH balance of an address at a block tag
+    pub fn new<S: AsRef<str>>(address: S, tag: Tag) -> Self {
+        Self {
+            address: address.as_ref().to_string(),
+            tag,
+        }
+    }
+}
+
+impl Endpoint for GetBalance {
+    type Response = Balance;
+
+    fn module(&self) -> Module {
+        Module::Account
+    }
+
+    fn action(&self) -> Action {
+        Action::Balance
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![
+            ("address".to_string(), normalize_address(&self.address)?),
+            ("tag".to_string(), self.tag.as_str()),
+        ])
+    }
+}
+
+/// The `balancemulti` action, see [`Accounts::balance_multi`]
+#[derive(Debug, Clone)]
+pub struct GetBalanceMulti {
+    addresses: Vec<String>,
+}
+
+impl GetBalanceMulti {
+    /// Request the ETH balances of up to [`MAX_BALANCE_MULTI_ADDRESSES`] addresses
+    pub fn new<S: AsRef<str>>(addresses: &[S]) -> Self {
+        Self {
+            addresses: addresses.iter().map(|a| a.as_ref().to_string()).collect(),
+        }
+    }
+}
+
+impl Endpoint for GetBalanceMulti {
+    type Response = Vec<Balance>;
+
+    fn module(&self) -> Module {
+        Module::Account
+    }
+
+    fn action(&self) -> Action {
+        Action::BalanceMulti
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        if self.addresses.is_empty() {
+            return Err(EtherscanError::InvalidParams(
+                "At least one address required".to_string(),
+            ));
+        }
+
+        if self.addresses.len() > MAX_BALANCE_MULTI_ADDRESSES {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Maximum {} addresses allowed",
+                MAX_BALANCE_MULTI_ADDRESSES
+            )));
+        }
+
+        let normalized: Result<Vec<String>> = self
+            .addresses
+            .iter()
+            .map(|addr| normalize_address(addr))
+            .collect();
+
+        Ok(vec![
+            ("address".to_string(), normalized?.join(",")),
+            ("tag".to_string(), Tag::Latest.as_str()),
+        ])
+    }
+}
+
+/// The `tokenlist` action, see [`Accounts::token_balances`]
+#[derive(Debug, Clone)]
+pub struct GetTokenList {
+    address: String,
+}
+
+impl GetTokenList {
+    /// Request the ERC-20 tokens held by an address
+    pub fn new<S: AsRef<str>>(address: S) -> Self {
+        Self {
+            address: address.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetTokenList {
+    type Response = Vec<TokenBalance>;
+
+    fn module(&self) -> Module {
+        Module::Account
+    }
+
+    fn action(&self) -> Action {
+        Action::TokenList
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![
+            ("address".to_string(), normalize_address(&self.address)?),
+            ("tag".to_string(), Tag::Latest.as_str()),
+        ])
+    }
+}
+
 /// Builder for transaction queries with pagination and filtering
 #[derive(Debug)]
-pub struct TransactionQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct TransactionQueryBuilder {
+    client: EtherscanClient,
     address: String,
     tx_type: TransactionType,
     pagination: Pagination,
 }
 
-impl<'a> TransactionQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str, tx_type: TransactionType) -> Self {
+impl TransactionQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str, tx_type: TransactionType) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             tx_type,
             pagination: Pagination::new(),
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        tx_type: TransactionType,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            tx_type,
+            pagination,
+        }
+    }
+
     /// Set the page number (starting from 1)
     pub fn page(mut self, page: u32) -> Self {
         self.pagination = self.pagination.page(page);
@@ -218,6 +480,36 @@ impl<'a> TransactionQueryBuilder<'a> {
         self
     }
 
+    /// Only include blocks mined at or after `time`
+    ///
+    /// The time is resolved to a block number with `getblocknobytime` when
+    /// the query runs and replaces any explicit start block.
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Only include blocks mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Only include blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::Transactions {
+            address: self.address.clone(),
+            tx_type: self.tx_type,
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
     pub fn get_address(&self) -> &str {
         &self.address
@@ -233,13 +525,41 @@ impl<'a> TransactionQueryBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<Transaction>> {
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination.clone())
+            .await?;
+
+        self.fetch_page(&pagination).await
+    }
+
+    /// Stream every result in ascending block order, following pages
+    ///
+    /// See [`TokenTransferQueryBuilder::execute_all`] for how pages are followed.
+    pub fn stream(self) -> impl Stream<Item = Result<Transaction>> {
+        paginate(self)
+    }
+}
+
+impl PagedQuery for TransactionQueryBuilder {
+    type Item = Transaction;
+
+    fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<Transaction>> {
         let address = normalize_address(&self.address)?;
 
         let mut params = vec![("address", address)];
 
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
-        for (key, value) in pagination_params {
+        for (key, value) in pagination.to_params() {
             params.push((key, value));
         }
 
@@ -247,25 +567,25 @@ impl<'a> TransactionQueryBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", self.tx_type.as_str(), &params_ref)
+            .get(Module::Account, self.tx_type.into(), &params_ref)
             .await
     }
 }
 
 /// Builder for token transfer queries
 #[derive(Debug)]
-pub struct TokenTransferQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct TokenTransferQueryBuilder {
+    client: EtherscanClient,
     address: String,
     tx_type: TransactionType,
     contract_address: Option<String>,
     pagination: Pagination,
 }
 
-impl<'a> TokenTransferQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str, tx_type: TransactionType) -> Self {
+impl TokenTransferQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str, tx_type: TransactionType) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             tx_type,
             contract_address: None,
@@ -273,6 +593,32 @@ impl<'a> TokenTransferQueryBuilder<'a> {
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        tx_type: TransactionType,
+        contract_address: Option<String>,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            tx_type,
+            contract_address,
+            pagination,
+        }
+    }
+
+    /// Query the transfers of a token contract across all addresses
+    pub(crate) fn for_contract(
+        client: &EtherscanClient,
+        contract_address: &str,
+        tx_type: TransactionType,
+    ) -> Self {
+        Self::new(client, "", tx_type).contract_address(contract_address)
+    }
+
     /// Filter by specific token contract address
     pub fn contract_address<S: AsRef<str>>(mut self, contract_address: S) -> Self {
         self.contract_address = Some(contract_address.as_ref().to_string());
@@ -310,6 +656,37 @@ impl<'a> TokenTransferQueryBuilder<'a> {
         self
     }
 
+    /// Only include blocks mined at or after `time`
+    ///
+    /// The time is resolved to a block number with `getblocknobytime` when
+    /// the query runs and replaces any explicit start block.
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Only include blocks mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Only include blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::TokenTransfers {
+            address: self.address.clone(),
+            tx_type: self.tx_type,
+            contract_address: self.contract_address.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_address(&self) -> &str {
@@ -330,19 +707,64 @@ impl<'a> TokenTransferQueryBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<TokenTransfer>> {
-        let address = normalize_address(&self.address)?;
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination.clone())
+            .await?;
 
-        let mut params = vec![("address", address)];
+        self.fetch_page(&pagination).await
+    }
+
+    /// Execute the query and follow it across pages, in ascending block order
+    ///
+    /// Etherscan serves at most 10 000 results per query window, so instead of
+    /// paging the start block is moved to the last block seen and that block
+    /// is fetched again in full. `offset` sets the page size (default 10 000).
+    /// Fails if a single block holds more transfers than a page.
+    pub async fn execute_all(self) -> Result<Vec<TokenTransfer>> {
+        paginate(self).try_collect().await
+    }
+
+    /// Stream every result in ascending block order, following pages
+    ///
+    /// Pages are followed as in [`execute_all`](Self::execute_all).
+    pub fn stream(self) -> impl Stream<Item = Result<TokenTransfer>> {
+        paginate(self)
+    }
+}
+
+impl PagedQuery for TokenTransferQueryBuilder {
+    type Item = TokenTransfer;
+
+    fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<TokenTransfer>> {
+        let mut params = Vec::new();
+        if !self.address.is_empty() {
+            params.push(("address", normalize_address(&self.address)?));
+        }
 
         // Add contract address if specified
-        if let Some(contract_addr) = self.contract_address {
-            let normalized_contract = normalize_address(&contract_addr)?;
+        if let Some(contract_addr) = &self.contract_address {
+            let normalized_contract = normalize_address(contract_addr)?;
             params.push(("contractaddress", normalized_contract));
         }
 
+        if params.is_empty() {
+            return Err(EtherscanError::InvalidParams(
+                "An address or contract address is required".to_string(),
+            ));
+        }
+
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
-        for (key, value) in pagination_params {
+        for (key, value) in pagination.to_params() {
             params.push((key, value));
         }
 
@@ -350,59 +772,70 @@ impl<'a> TokenTransferQueryBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", self.tx_type.as_str(), &params_ref)
+            .get(Module::Account, self.tx_type.into(), &params_ref)
             .await
     }
 }
 
 /// Builder for internal transaction queries with multiple query types
 #[derive(Debug)]
-pub struct InternalTransactionQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct InternalTransactionQueryBuilder {
+    client: EtherscanClient,
 }
 
-impl<'a> InternalTransactionQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient) -> Self {
-        Self { client }
+impl InternalTransactionQueryBuilder {
+    fn new(client: &EtherscanClient) -> Self {
+        Self {
+            client: client.clone(),
+        }
     }
 
     /// Query internal transactions by address
-    pub fn by_address<S: AsRef<str>>(self, address: S) -> InternalTxByAddressBuilder<'a> {
-        InternalTxByAddressBuilder::new(self.client, address.as_ref())
+    pub fn by_address<S: AsRef<str>>(self, address: S) -> InternalTxByAddressBuilder {
+        InternalTxByAddressBuilder::new(&self.client, address.as_ref())
     }
 
     /// Query internal transactions by transaction hash
-    pub fn by_hash<S: AsRef<str>>(self, tx_hash: S) -> InternalTxByHashBuilder<'a> {
-        InternalTxByHashBuilder::new(self.client, tx_hash.as_ref())
+    pub fn by_hash<S: AsRef<str>>(self, tx_hash: S) -> InternalTxByHashBuilder {
+        InternalTxByHashBuilder::new(&self.client, tx_hash.as_ref())
     }
 
     /// Query internal transactions by block range
-    pub fn by_block_range(
-        self,
-        start_block: u64,
-        end_block: u64,
-    ) -> InternalTxByBlockRangeBuilder<'a> {
-        InternalTxByBlockRangeBuilder::new(self.client, start_block, end_block)
+    pub fn by_block_range(self, start_block: u64, end_block: u64) -> InternalTxByBlockRangeBuilder {
+        InternalTxByBlockRangeBuilder::new(&self.client, start_block, end_block)
     }
 }
 
 /// Builder for internal transactions by address
 #[derive(Debug)]
-pub struct InternalTxByAddressBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct InternalTxByAddressBuilder {
+    client: EtherscanClient,
     address: String,
     pagination: Pagination,
 }
 
-impl<'a> InternalTxByAddressBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+impl InternalTxByAddressBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             pagination: Pagination::new(),
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            pagination,
+        }
+    }
+
     /// Set pagination and filtering options
     pub fn page(mut self, page: u32) -> Self {
         self.pagination = self.pagination.page(page);
@@ -434,6 +867,35 @@ impl<'a> InternalTxByAddressBuilder<'a> {
         self
     }
 
+    /// Only include blocks mined at or after `time`
+    ///
+    /// The time is resolved to a block number with `getblocknobytime` when
+    /// the query runs and replaces any explicit start block.
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Only include blocks mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Only include blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::InternalTransactionsByAddress {
+            address: self.address.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_address(&self) -> &str {
@@ -446,12 +908,40 @@ impl<'a> InternalTxByAddressBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<InternalTransaction>> {
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination.clone())
+            .await?;
+
+        self.fetch_page(&pagination).await
+    }
+
+    /// Stream every result in ascending block order, following pages
+    ///
+    /// See [`TokenTransferQueryBuilder::execute_all`] for how pages are followed.
+    pub fn stream(self) -> impl Stream<Item = Result<InternalTransaction>> {
+        paginate(self)
+    }
+}
+
+impl PagedQuery for InternalTxByAddressBuilder {
+    type Item = InternalTransaction;
+
+    fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<InternalTransaction>> {
         let address = normalize_address(&self.address)?;
         let mut params = vec![("address", address)];
 
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
-        for (key, value) in pagination_params {
+        for (key, value) in pagination.to_params() {
             params.push((key, value));
         }
 
@@ -459,59 +949,101 @@ impl<'a> InternalTxByAddressBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", "txlistinternal", &params_ref)
+            .get(Module::Account, Action::TxListInternal, &params_ref)
             .await
     }
 }
 
 /// Builder for internal transactions by transaction hash
 #[derive(Debug)]
-pub struct InternalTxByHashBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct InternalTxByHashBuilder {
+    client: EtherscanClient,
     tx_hash: String,
 }
 
-impl<'a> InternalTxByHashBuilder<'a> {
-    fn new(client: &'a EtherscanClient, tx_hash: &str) -> Self {
+impl InternalTxByHashBuilder {
+    fn new(client: &EtherscanClient, tx_hash: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             tx_hash: tx_hash.to_string(),
         }
     }
 
-    // Getter methods for testing
-
-    pub fn get_tx_hash(&self) -> &str {
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(client: &EtherscanClient, tx_hash: String) -> Self {
+        Self {
+            client: client.clone(),
+            tx_hash,
+        }
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::InternalTransactionsByHash {
+            tx_hash: self.tx_hash.clone(),
+        }
+    }
+
+    // Getter methods for testing
+
+    pub fn get_tx_hash(&self) -> &str {
         &self.tx_hash
     }
 
     /// Execute the query
+    ///
+    /// Etherscan leaves the parent hash out of these rows, so it is set to
+    /// the queried hash. The trace ID is not returned and stays `None`.
     pub async fn execute(self) -> Result<Vec<InternalTransaction>> {
         let params = [("txhash", self.tx_hash.as_str())];
 
-        self.client.get("account", "txlistinternal", &params).await
+        let mut transactions: Vec<InternalTransaction> = self
+            .client
+            .get(Module::Account, Action::TxListInternal, &params)
+            .await?;
+        for transaction in &mut transactions {
+            transaction
+                .hash
+                .get_or_insert_with(|| TxHash::new(&self.tx_hash));
+        }
+        Ok(transactions)
     }
 }
 
 /// Builder for internal transactions by block range
 #[derive(Debug)]
-pub struct InternalTxByBlockRangeBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct InternalTxByBlockRangeBuilder {
+    client: EtherscanClient,
     start_block: u64,
     end_block: u64,
     pagination: Pagination,
 }
 
-impl<'a> InternalTxByBlockRangeBuilder<'a> {
-    fn new(client: &'a EtherscanClient, start_block: u64, end_block: u64) -> Self {
+impl InternalTxByBlockRangeBuilder {
+    fn new(client: &EtherscanClient, start_block: u64, end_block: u64) -> Self {
         Self {
-            client,
+            client: client.clone(),
             start_block,
             end_block,
             pagination: Pagination::new().sort(Sort::Ascending),
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        start_block: u64,
+        end_block: u64,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            start_block,
+            end_block,
+            pagination,
+        }
+    }
+
     /// Set pagination options
     pub fn page(mut self, page: u32) -> Self {
         self.pagination = self.pagination.page(page);
@@ -528,6 +1060,33 @@ impl<'a> InternalTxByBlockRangeBuilder<'a> {
         self
     }
 
+    /// Replace the start block with the first block mined at or after `time`
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Replace the end block with the last block mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Replace the block range with the blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::InternalTransactionsByBlockRange {
+            start_block: self.start_block,
+            end_block: self.end_block,
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_start_block(&self) -> u64 {
@@ -544,13 +1103,21 @@ impl<'a> InternalTxByBlockRangeBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<InternalTransaction>> {
+        let mut pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination)
+            .await?;
+        let start_block = pagination.start_block.take().unwrap_or(self.start_block);
+        let end_block = pagination.end_block.take().unwrap_or(self.end_block);
+
         let mut params = vec![
-            ("startblock", self.start_block.to_string()),
-            ("endblock", self.end_block.to_string()),
+            ("startblock", start_block.to_string()),
+            ("endblock", end_block.to_string()),
         ];
 
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
+        let pagination_params = pagination.to_params();
         for (key, value) in pagination_params {
             params.push((key, value));
         }
@@ -559,28 +1126,41 @@ impl<'a> InternalTxByBlockRangeBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", "txlistinternal", &params_ref)
+            .get(Module::Account, Action::TxListInternal, &params_ref)
             .await
     }
 }
 
 /// Builder for validated blocks query
 #[derive(Debug)]
-pub struct ValidatedBlocksQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct ValidatedBlocksQueryBuilder {
+    client: EtherscanClient,
     address: String,
     pagination: Pagination,
 }
 
-impl<'a> ValidatedBlocksQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+impl ValidatedBlocksQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             pagination: Pagination::new(),
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            pagination,
+        }
+    }
+
     /// Set the page number
     pub fn page(mut self, page: u32) -> Self {
         self.pagination = self.pagination.page(page);
@@ -593,6 +1173,14 @@ impl<'a> ValidatedBlocksQueryBuilder<'a> {
         self
     }
 
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::ValidatedBlocks {
+            address: self.address.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_address(&self) -> &str {
@@ -605,11 +1193,43 @@ impl<'a> ValidatedBlocksQueryBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<ValidatedBlock>> {
+        self.fetch_page(&self.pagination).await
+    }
+
+    /// Stream every validated block, newest first, following pages
+    ///
+    /// `offset` sets the page size (default 10 000). Etherscan serves at most
+    /// 10 000 results per query, so reading past them fails instead of
+    /// ending the stream early.
+    pub fn stream(self) -> impl Stream<Item = Result<ValidatedBlock>> {
+        let page_size = self
+            .pagination
+            .offset
+            .unwrap_or(MAX_RESULT_WINDOW)
+            .clamp(1, MAX_RESULT_WINDOW);
+        let pages =
+            futures::stream::try_unfold((self, 1, false), move |(query, page, done)| async move {
+                if done {
+                    return Ok::<_, EtherscanError>(None);
+                }
+
+                let pagination = query.pagination.clone().page(page).offset(page_size);
+                let blocks = query.fetch_page(&pagination).await?;
+                let done = blocks.len() < page_size as usize;
+                Ok(Some((blocks, (query, page + 1, done))))
+            });
+
+        pages
+            .map_ok(|page| futures::stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<ValidatedBlock>> {
         let address = normalize_address(&self.address)?;
         let mut params = vec![("address", address)];
 
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
+        let pagination_params = pagination.to_params();
         for (key, value) in pagination_params {
             params.push((key, value));
         }
@@ -618,25 +1238,25 @@ impl<'a> ValidatedBlocksQueryBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", "getminedblocks", &params_ref)
+            .get(Module::Account, Action::GetMinedBlocks, &params_ref)
             .await
     }
 }
 
 /// Builder for beacon chain withdrawals query
 #[derive(Debug)]
-pub struct BeaconWithdrawalsQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct BeaconWithdrawalsQueryBuilder {
+    client: EtherscanClient,
     address: String,
     start_block: Option<u64>,
     end_block: Option<u64>,
     pagination: Pagination,
 }
 
-impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+impl BeaconWithdrawalsQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             start_block: None,
             end_block: None,
@@ -644,6 +1264,23 @@ impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
         }
     }
 
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        start_block: Option<u64>,
+        end_block: Option<u64>,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            start_block,
+            end_block,
+            pagination,
+        }
+    }
+
     /// Set the starting block
     pub fn start_block(mut self, block: u64) -> Self {
         self.start_block = Some(block);
@@ -663,6 +1300,26 @@ impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
         self
     }
 
+    /// Only include withdrawals from blocks mined at or after `time`
+    ///
+    /// Replaces any explicit start block when the query runs.
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Only include withdrawals from blocks mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Only include withdrawals between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
     /// Set the page number
     pub fn page(mut self, page: u32) -> Self {
         self.pagination = self.pagination.page(page);
@@ -681,6 +1338,16 @@ impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
         self
     }
 
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::BeaconWithdrawals {
+            address: self.address.clone(),
+            start_block: self.start_block,
+            end_block: self.end_block,
+            pagination: self.pagination.clone(),
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_address(&self) -> &str {
@@ -701,19 +1368,45 @@ impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
 
     /// Execute the query
     pub async fn execute(self) -> Result<Vec<BeaconWithdrawal>> {
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination.clone())
+            .await?;
+
+        self.fetch_page(&pagination).await
+    }
+
+    /// Stream every result in ascending block order, following pages
+    ///
+    /// See [`TokenTransferQueryBuilder::execute_all`] for how pages are followed.
+    pub fn stream(self) -> impl Stream<Item = Result<BeaconWithdrawal>> {
+        paginate(self)
+    }
+}
+
+impl PagedQuery for BeaconWithdrawalsQueryBuilder {
+    type Item = BeaconWithdrawal;
+
+    fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<BeaconWithdrawal>> {
         let address = normalize_address(&self.address)?;
         let mut params = vec![("address", address)];
 
-        // Add block range if specified
-        if let Some(start) = self.start_block {
-            params.push(("startblock", start.to_string()));
-        }
-        if let Some(end) = self.end_block {
-            params.push(("endblock", end.to_string()));
-        }
+        // Block bounds resolved from a time range or moved by paging take precedence
+        let mut pagination = pagination.clone();
+        pagination.start_block = pagination.start_block.or(self.start_block);
+        pagination.end_block = pagination.end_block.or(self.end_block);
 
         // Add pagination parameters
-        let pagination_params = self.pagination.to_params();
+        let pagination_params = pagination.to_params();
         for (key, value) in pagination_params {
             params.push((key, value));
         }
@@ -722,25 +1415,55 @@ impl<'a> BeaconWithdrawalsQueryBuilder<'a> {
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         self.client
-            .get("account", "beaconwithdrawal", &params_ref)
+            .get(Module::Account, Action::BeaconWithdrawal, &params_ref)
             .await
     }
 }
 
-/// Builder for historical balance query
+/// Maximum number of blocks sampled by [`HistoricalBalanceQueryBuilder::execute_series`]
+pub const MAX_BALANCE_SERIES_SAMPLES: u64 = 1_000;
+
+/// Builder for historical balance queries
+///
+/// Balances at a past block use the `balancehistory` (ETH) and
+/// `tokenbalancehistory` (ERC-20) actions, which require an API Pro plan;
+/// keys without access fail with [`EtherscanError::PlanRestricted`]. Without
+/// a block the current balance is returned through the free `balance` and
+/// `tokenbalance` actions.
 #[derive(Debug)]
-pub struct HistoricalBalanceQueryBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct HistoricalBalanceQueryBuilder {
+    client: EtherscanClient,
     address: String,
+    contract_address: Option<String>,
     block_number: Option<u64>,
+    range: Option<(u64, u64, u64)>,
 }
 
-impl<'a> HistoricalBalanceQueryBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+impl HistoricalBalanceQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
+            contract_address: None,
             block_number: None,
+            range: None,
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        contract_address: Option<String>,
+        block_number: Option<u64>,
+        range: Option<(u64, u64, u64)>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            contract_address,
+            block_number,
+            range,
         }
     }
 
@@ -750,6 +1473,31 @@ impl<'a> HistoricalBalanceQueryBuilder<'a> {
         self
     }
 
+    /// Query the balance of an ERC-20 token instead of ETH
+    pub fn token<S: AsRef<str>>(mut self, contract_address: S) -> Self {
+        self.contract_address = Some(contract_address.as_ref().to_string());
+        self
+    }
+
+    /// Sample the balance every `step` blocks from `start` to `end` (inclusive)
+    ///
+    /// Used by [`execute_series`](Self::execute_series). The end block is
+    /// always sampled, even when it is not a multiple of `step` away.
+    pub fn range(mut self, start: u64, end: u64, step: u64) -> Self {
+        self.range = Some((start, end, step));
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::HistoricalBalance {
+            address: self.address.clone(),
+            contract_address: self.contract_address.clone(),
+            block_number: self.block_number,
+            range: self.range,
+        }
+    }
+
     // Getter methods for testing
 
     pub fn get_address(&self) -> &str {
@@ -760,16 +1508,735 @@ impl<'a> HistoricalBalanceQueryBuilder<'a> {
         self.block_number
     }
 
+    /// Token contract, if querying an ERC-20 balance
+    pub fn get_contract_address(&self) -> Option<&str> {
+        self.contract_address.as_deref()
+    }
+
+    /// Blocks sampled by [`execute_series`](Self::execute_series)
+    pub fn sample_blocks(&self) -> Result<Vec<u64>> {
+        let (start, end, step) = self.range.ok_or_else(|| {
+            EtherscanError::InvalidParams("A block range is required for a series".to_string())
+        })?;
+
+        if step == 0 {
+            return Err(EtherscanError::InvalidParams(
+                "Sampling step must be greater than zero".to_string(),
+            ));
+        }
+        if start > end {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Start block {} is after end block {}",
+                start, end
+            )));
+        }
+
+        let samples = (end - start) / step + 1;
+        if samples > MAX_BALANCE_SERIES_SAMPLES {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Range produces {} samples; maximum is {}",
+                samples, MAX_BALANCE_SERIES_SAMPLES
+            )));
+        }
+
+        let mut blocks: Vec<u64> = (start..=end).step_by(step as usize).collect();
+        if blocks.last() != Some(&end) {
+            blocks.push(end);
+        }
+        Ok(blocks)
+    }
+
     /// Execute the query
     pub async fn execute(self) -> Result<Balance> {
         let address = normalize_address(&self.address)?;
-        let block_tag = self
-            .block_number
-            .map(|n| n.to_string())
-            .unwrap_or_else(|| "latest".to_string());
+        let contract_address = self
+            .contract_address
+            .as_deref()
+            .map(normalize_address)
+            .transpose()?;
+
+        self.fetch(&address, contract_address.as_deref(), self.block_number)
+            .await
+    }
+
+    /// Fetch the balance at every block of the configured [`range`](Self::range)
+    ///
+    /// Requests run concurrently under the client's rate limit. The series is
+    /// ordered by block number.
+    pub async fn execute_series(self) -> Result<Vec<BalanceSnapshot>> {
+        let address = normalize_address(&self.address)?;
+        let contract_address = self
+            .contract_address
+            .as_deref()
+            .map(normalize_address)
+            .transpose()?;
+        let blocks = self.sample_blocks()?;
+
+        let balances = futures::future::try_join_all(
+            blocks
+                .iter()
+                .map(|&block| self.fetch(&address, contract_address.as_deref(), Some(block))),
+        )
+        .await?;
+
+        Ok(blocks
+            .into_iter()
+            .zip(balances)
+            .map(|(block_number, balance)| BalanceSnapshot {
+                block_number,
+                balance: balance.balance,
+            })
+            .collect())
+    }
+
+    async fn fetch(
+        &self,
+        address: &str,
+        contract_address: Option<&str>,
+        block_number: Option<u64>,
+    ) -> Result<Balance> {
+        let mut params = vec![("address", address.to_string())];
+        if let Some(contract_address) = contract_address {
+            params.push(("contractaddress", contract_address.to_string()));
+        }
+
+        let action = match (contract_address, block_number) {
+            (None, Some(block)) => {
+                params.push(("blockno", block.to_string()));
+                Action::BalanceHistory
+            }
+            (Some(_), Some(block)) => {
+                params.push(("blockno", block.to_string()));
+                Action::TokenBalanceHistory
+            }
+            (None, None) => {
+                params.push(("tag", Tag::Latest.as_str()));
+                Action::Balance
+            }
+            (Some(_), None) => {
+                params.push(("tag", Tag::Latest.as_str()));
+                Action::TokenBalance
+            }
+        };
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client.get(Module::Account, action, &params_ref).await
+    }
+}
+
+/// Apply the block bounds and page size of a pagination that are set, always in ascending order
+macro_rules! in_range {
+    ($query:expr, $pagination:expr) => {{
+        let mut query = $query.sort(Sort::Ascending);
+        if let Some(block) = $pagination.start_block {
+            query = query.start_block(block);
+        }
+        if let Some(block) = $pagination.end_block {
+            query = query.end_block(block);
+        }
+        if let Some(offset) = $pagination.offset {
+            query = query.page(1).offset(offset);
+        }
+        query
+    }};
+}
+
+/// Run a query built with [`in_range!`], either for its first page or across all pages
+async fn list<Q: PagedQuery>(query: Q, all_pages: bool) -> Result<Vec<Q::Item>> {
+    if all_pages {
+        paginate(query).try_collect().await
+    } else {
+        query.fetch_page(query.pagination()).await
+    }
+}
+
+/// Builder for an address activity feed
+#[derive(Debug)]
+pub struct ActivityQueryBuilder {
+    client: EtherscanClient,
+    address: String,
+    kinds: Vec<ActivityKind>,
+    pagination: Pagination,
+}
+
+impl ActivityQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
+        Self {
+            client: client.clone(),
+            address: address.to_string(),
+            kinds: ActivityKind::ALL.to_vec(),
+            pagination: Pagination::new().sort(Sort::Ascending),
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        kinds: Vec<ActivityKind>,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            kinds,
+            pagination,
+        }
+    }
 
-        let params = [("address", address.as_str()), ("tag", block_tag.as_str())];
+    /// Set the starting block number
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.start_block(block);
+        self
+    }
+
+    /// Set the ending block number
+    pub fn end_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.end_block(block);
+        self
+    }
+
+    /// Set a block range
+    pub fn block_range(mut self, start: u64, end: u64) -> Self {
+        self.pagination = self.pagination.block_range(start, end);
+        self
+    }
+
+    /// Only include blocks mined at or after `time`
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
 
-        self.client.get("account", "balance", &params).await
+    /// Only include blocks mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Only include blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Set the order of the timeline (default: ascending)
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.pagination = self.pagination.sort(sort);
+        self
+    }
+
+    /// Set the page size used when following each kind (default: 10 000)
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.pagination = self.pagination.offset(offset);
+        self
+    }
+
+    /// Only fetch the given kinds of activity
+    pub fn kinds(mut self, kinds: &[ActivityKind]) -> Self {
+        self.kinds = kinds.to_vec();
+        self
+    }
+
+    /// Skip a kind of activity, e.g. beacon withdrawals on chains without them
+    pub fn exclude(mut self, kind: ActivityKind) -> Self {
+        self.kinds.retain(|&k| k != kind);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::Activity {
+            address: self.address.clone(),
+            kinds: self.kinds.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
+    // Getter methods for testing
+
+    /// Address whose activity is fetched
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Kinds of activity that will be fetched
+    pub fn get_kinds(&self) -> &[ActivityKind] {
+        &self.kinds
+    }
+
+    /// Block range, time range and timeline order
+    pub fn get_pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    /// Execute the query
+    ///
+    /// Each kind is followed across pages as in
+    /// [`TokenTransferQueryBuilder::execute_all`], so the feed is complete for
+    /// busy addresses too. Any failing request fails the feed.
+    pub async fn execute(self) -> Result<Vec<ActivityGroup>> {
+        let address = normalize_address(&self.address)?;
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination)
+            .await?;
+        let sort = pagination.sort.unwrap_or(Sort::Ascending);
+
+        let lists = futures::future::try_join_all(
+            self.kinds
+                .iter()
+                .map(|&kind| Self::fetch(&self.client, kind, &address, &pagination)),
+        )
+        .await?;
+
+        Ok(timeline(lists.into_iter().flatten().collect(), sort))
+    }
+
+    /// Fetch one kind of activity in ascending block order, following every page
+    pub(crate) async fn fetch(
+        client: &EtherscanClient,
+        kind: ActivityKind,
+        address: &str,
+        pagination: &Pagination,
+    ) -> Result<Vec<ActivityEvent>> {
+        Self::fetch_kind(client, kind, address, pagination, true).await
+    }
+
+    /// Fetch a single page of one kind of activity, in ascending block order
+    #[cfg(feature = "proxy")]
+    pub(crate) async fn fetch_page(
+        client: &EtherscanClient,
+        kind: ActivityKind,
+        address: &str,
+        pagination: &Pagination,
+    ) -> Result<Vec<ActivityEvent>> {
+        Self::fetch_kind(client, kind, address, pagination, false).await
+    }
+
+    async fn fetch_kind(
+        client: &EtherscanClient,
+        kind: ActivityKind,
+        address: &str,
+        pagination: &Pagination,
+        all_pages: bool,
+    ) -> Result<Vec<ActivityEvent>> {
+        let accounts = Accounts::new(client);
+
+        let events = match kind {
+            ActivityKind::Transaction => {
+                let query = in_range!(accounts.transactions(address), pagination);
+                list(query, all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|transaction| ActivityEvent::Transaction {
+                        direction: Direction::of(
+                            address,
+                            &transaction.from,
+                            transaction.to.as_ref(),
+                        ),
+                        transaction,
+                    })
+                    .collect()
+            }
+            ActivityKind::InternalTransaction => {
+                let query = accounts.internal_transactions().by_address(address);
+                list(in_range!(query, pagination), all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|transaction| ActivityEvent::InternalTransaction {
+                        direction: Direction::of(
+                            address,
+                            &transaction.from,
+                            transaction.to.as_ref(),
+                        ),
+                        transaction,
+                    })
+                    .collect()
+            }
+            ActivityKind::Erc20Transfer => {
+                let query = in_range!(accounts.token_transfers(address), pagination);
+                list(query, all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|transfer| ActivityEvent::Erc20Transfer {
+                        direction: Direction::of(address, &transfer.from, Some(&transfer.to)),
+                        transfer,
+                    })
+                    .collect()
+            }
+            ActivityKind::Erc721Transfer => {
+                let query = in_range!(accounts.nft_transfers(address), pagination);
+                list(query, all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|transfer| ActivityEvent::Erc721Transfer {
+                        direction: Direction::of(address, &transfer.from, Some(&transfer.to)),
+                        transfer,
+                    })
+                    .collect()
+            }
+            ActivityKind::Erc1155Transfer => {
+                let query = in_range!(accounts.erc1155_transfers(address), pagination);
+                list(query, all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|transfer| ActivityEvent::Erc1155Transfer {
+                        direction: Direction::of(address, &transfer.from, Some(&transfer.to)),
+                        transfer,
+                    })
+                    .collect()
+            }
+            ActivityKind::BeaconWithdrawal => {
+                let query = in_range!(accounts.beacon_withdrawals(address), pagination);
+                list(query, all_pages)
+                    .await?
+                    .into_iter()
+                    .map(|withdrawal| ActivityEvent::BeaconWithdrawal { withdrawal })
+                    .collect()
+            }
+        };
+
+        Ok(events)
+    }
+}
+
+/// Builder for a balance reconciliation
+#[derive(Debug)]
+pub struct ReconcileQueryBuilder {
+    client: EtherscanClient,
+    address: String,
+    pagination: Pagination,
+    checkpoints: Vec<u64>,
+    checkpoint_step: Option<u64>,
+}
+
+impl ReconcileQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
+        Self {
+            client: client.clone(),
+            address: address.to_string(),
+            pagination: Pagination::new(),
+            checkpoints: Vec::new(),
+            checkpoint_step: None,
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        pagination: Pagination,
+        checkpoints: Vec<u64>,
+        checkpoint_step: Option<u64>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            pagination,
+            checkpoints,
+            checkpoint_step,
+        }
+    }
+
+    /// Set the first replayed block; the balance before it is read from the chain
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.start_block(block);
+        self
+    }
+
+    /// Set the last replayed block
+    pub fn end_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.end_block(block);
+        self
+    }
+
+    /// Set a block range
+    pub fn block_range(mut self, start: u64, end: u64) -> Self {
+        self.pagination = self.pagination.block_range(start, end);
+        self
+    }
+
+    /// Start replaying at the first block mined at or after `time`
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.since(time);
+        self
+    }
+
+    /// Stop replaying at the last block mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Replay blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.between(start, end);
+        self
+    }
+
+    /// Compare balances at these blocks
+    pub fn checkpoints(mut self, blocks: &[u64]) -> Self {
+        self.checkpoints = blocks.to_vec();
+        self
+    }
+
+    /// Compare balances every `step` blocks across the range, and at its end
+    pub fn checkpoint_every(mut self, step: u64) -> Self {
+        self.checkpoint_step = Some(step);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::Reconcile {
+            address: self.address.clone(),
+            pagination: self.pagination.clone(),
+            checkpoints: self.checkpoints.clone(),
+            checkpoint_step: self.checkpoint_step,
+        }
+    }
+
+    // Getter methods for testing
+
+    /// Address being reconciled
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Block range and time range
+    pub fn get_pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    /// Explicit checkpoint blocks
+    pub fn get_checkpoints(&self) -> &[u64] {
+        &self.checkpoints
+    }
+
+    /// Checkpoint interval, if set
+    pub fn get_checkpoint_step(&self) -> Option<u64> {
+        self.checkpoint_step
+    }
+
+    /// Execute the reconciliation
+    ///
+    /// The balance is compared at the checkpoints and always at the end of the
+    /// range, which defaults to the latest block. Lists are followed across
+    /// pages as in [`TokenTransferQueryBuilder::execute_all`]. On-chain
+    /// balances are read with `balancehistory`, which requires an API Pro
+    /// plan; keys without access fail with [`EtherscanError::PlanRestricted`].
+    /// An amount that does not parse or a balance that overflows fails the
+    /// reconciliation rather than being replayed as zero.
+    pub async fn execute(self) -> Result<ReconciliationReport> {
+        if self.checkpoint_step == Some(0) {
+            return Err(EtherscanError::InvalidParams(
+                "Checkpoint interval must be greater than zero".to_string(),
+            ));
+        }
+
+        let address = normalize_address(&self.address)?;
+        let blocks = self.client.blocks();
+        let mut pagination = blocks.resolve_time_range(self.pagination).await?;
+        let first_block = pagination.start_block.unwrap_or(0);
+        let last_block = match pagination.end_block {
+            Some(block) => block,
+            None => {
+                blocks
+                    .block_number_by_time(Utc::now(), Closest::Before)
+                    .await?
+            }
+        };
+        pagination.end_block = Some(last_block);
+
+        let accounts = Accounts::new(&self.client);
+        let internal = accounts.internal_transactions().by_address(&address);
+        // Validated blocks come newest first and cannot be filtered by block
+        let validated_blocks = accounts
+            .blocks_validated(&address)
+            .stream()
+            .try_take_while(|block| futures::future::ready(Ok(block.block() >= first_block)))
+            .try_filter(|block| futures::future::ready(block.block() <= last_block))
+            .try_collect::<Vec<_>>();
+        let (transactions, internal_transactions, withdrawals, validated_blocks, opening) = futures::try_join!(
+            list(in_range!(accounts.transactions(&address), pagination), true),
+            list(in_range!(internal, pagination), true),
+            list(
+                in_range!(accounts.beacon_withdrawals(&address), pagination),
+                true
+            ),
+            validated_blocks,
+            Self::balance_at(&accounts, &address, first_block.checked_sub(1)),
+        )?;
+
+        let replay = BalanceReplay::new(
+            &address,
+            opening,
+            &transactions,
+            &internal_transactions,
+            &withdrawals,
+            &validated_blocks,
+        )?;
+
+        let mut blocks = self.checkpoints;
+        if let Some(step) = self.checkpoint_step {
+            blocks.extend((first_block..last_block).step_by(step as usize).skip(1));
+        }
+        blocks.push(last_block);
+        blocks.sort_unstable();
+        blocks.dedup();
+
+        let actual = futures::future::try_join_all(
+            blocks
+                .iter()
+                .map(|&block| Self::balance_at(&accounts, &address, Some(block))),
+        )
+        .await?;
+        let checkpoints = blocks
+            .iter()
+            .zip(actual)
+            .map(|(&block_number, actual)| Checkpoint {
+                block_number,
+                expected: replay.balance_at(block_number),
+                actual,
+            })
+            .collect();
+
+        Ok(ReconciliationReport::new(
+            &address,
+            replay,
+            first_block,
+            checkpoints,
+        ))
+    }
+
+    /// On-chain balance after `block` (zero before genesis)
+    async fn balance_at(
+        accounts: &Accounts<'_>,
+        address: &str,
+        block: Option<u64>,
+    ) -> Result<i128> {
+        let Some(block) = block else {
+            return Ok(0);
+        };
+        let balance = accounts
+            .historical_balance(address)
+            .at_block(block)
+            .execute()
+            .await?;
+        balance
+            .balance
+            .as_u128()
+            .and_then(|wei| i128::try_from(wei).ok())
+            .ok_or_else(|| EtherscanError::parse(format!("Invalid balance: {}", balance.wei())))
+    }
+}
+
+/// Builder for a token ledger
+#[derive(Debug)]
+pub struct TokenLedgerQueryBuilder {
+    client: EtherscanClient,
+    address: String,
+    contract_address: Option<String>,
+    pagination: Pagination,
+}
+
+impl TokenLedgerQueryBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
+        Self {
+            client: client.clone(),
+            address: address.to_string(),
+            contract_address: None,
+            pagination: Pagination::new(),
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        contract_address: Option<String>,
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            contract_address,
+            pagination,
+        }
+    }
+
+    /// Only include transfers of one token contract
+    pub fn contract_address<S: AsRef<str>>(mut self, contract_address: S) -> Self {
+        self.contract_address = Some(contract_address.as_ref().to_string());
+        self
+    }
+
+    /// Ignore transfers after `block`
+    pub fn end_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.end_block(block);
+        self
+    }
+
+    /// Ignore transfers mined after `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.pagination = self.pagination.until(time);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::TokenLedger {
+            address: self.address.clone(),
+            contract_address: self.contract_address.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
+    // Getter methods for testing
+
+    /// Address the ledger is built for
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Token contract filter, if set
+    pub fn get_contract_address(&self) -> Option<&str> {
+        self.contract_address.as_deref()
+    }
+
+    /// Block and time bounds
+    pub fn get_pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    /// Execute the query
+    ///
+    /// Each token standard is followed across pages as in
+    /// [`TokenTransferQueryBuilder::execute_all`].
+    pub async fn execute(self) -> Result<TokenLedger> {
+        let address = normalize_address(&self.address)?;
+        let pagination = self
+            .client
+            .blocks()
+            .resolve_time_range(self.pagination)
+            .await?;
+        let accounts = Accounts::new(&self.client);
+        let query = |builder: TokenTransferQueryBuilder| {
+            let builder = in_range!(builder, pagination);
+            match &self.contract_address {
+                Some(contract_address) => builder.contract_address(contract_address),
+                None => builder,
+            }
+        };
+
+        let (erc20, nft, erc1155) = futures::try_join!(
+            query(accounts.token_transfers(&address)).execute_all(),
+            query(accounts.nft_transfers(&address)).execute_all(),
+            query(accounts.erc1155_transfers(&address)).execute_all(),
+        )?;
+
+        Ok(TokenLedger::new(address, erc20, nft, erc1155))
     }
 }
@@ -0,0 +1,308 @@
+//! Typed API modules and actions, and the [`Endpoint`] trait every request goes through
+//!
+//! Built-in calls send an [`Endpoint`] naming their [`Module`] and [`Action`].
+//! Single-request actions have a type of their own whose `Response` is their
+//! model, such as `accounts::GetBalance` or `contracts::GetAbi`. Builders send
+//! a [`Call`] instead when they follow a list across pages, pick the action
+//! from their options (historical balances) or post a form (verification).
+//! The same types let callers reach endpoints this crate does not model yet:
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{Action, Call, EtherscanClient, Module};
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! #[serde(rename_all = "PascalCase")]
+//! struct DailyTxCount {
+//!     transaction_count: u64,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!
+//!     // Untyped: the `result` field as JSON
+//!     let supply = client.raw(Module::Stats, "ethsupply", &[]).await?;
+//!     println!("{}", supply);
+//!
+//!     // Typed: deserialize into your own model
+//!     let call = Call::<Vec<DailyTxCount>>::new(Module::Stats, Action::from("dailytx"))
+//!         .param("startdate", "2024-01-01")
+//!         .param("enddate", "2024-01-31");
+//!     for day in client.call(&call).await? {
+//!         println!("{}", day.transaction_count);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Result, TransactionType};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Declare an enum of API names with an `Other` fallback
+macro_rules! api_names {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Any other name, sent as is
+            Other(String),
+        }
+
+        impl $name {
+            /// Name sent to the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(name) => name,
+                }
+            }
+        }
+
+        /// Known names map to their variant, anything else to `Other`
+        impl From<&str> for $name {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($value => Self::$variant,)*
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(name: String) -> Self {
+                Self::from(name.as_str())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+api_names! {
+    /// API module, the `module` query parameter
+    Module {
+        /// Accounts and their transactions
+        Account => "account",
+        /// Contract ABIs, sources and verification
+        Contract => "contract",
+        /// Transaction status
+        Transaction => "transaction",
+        /// Blocks and block rewards
+        Block => "block",
+        /// Event logs
+        Logs => "logs",
+        /// Ethereum JSON-RPC passthrough
+        Proxy => "proxy",
+        /// Network statistics
+        Stats => "stats",
+        /// Gas prices
+        GasTracker => "gastracker",
+        /// Token information
+        Token => "token",
+    }
+}
+
+api_names! {
+    /// API action, the `action` query parameter
+    Action {
+        /// Ether balance of an address
+        Balance => "balance",
+        /// Ether balances of several addresses
+        BalanceMulti => "balancemulti",
+        /// Ether balance at a past block
+        BalanceHistory => "balancehistory",
+        /// Token balance of an address
+        TokenBalance => "tokenbalance",
+        /// Token balance at a past block
+        TokenBalanceHistory => "tokenbalancehistory",
+        /// Normal transactions
+        TxList => "txlist",
+        /// Internal transactions
+        TxListInternal => "txlistinternal",
+        /// ERC-20 transfers
+        TokenTx => "tokentx",
+        /// ERC-721 transfers
+        TokenNftTx => "tokennfttx",
+        /// ERC-1155 transfers
+        Token1155Tx => "token1155tx",
+        /// Token holdings of an address
+        TokenList => "tokenlist",
+        /// Blocks validated by an address
+        GetMinedBlocks => "getminedblocks",
+        /// Beacon chain withdrawals
+        BeaconWithdrawal => "beaconwithdrawal",
+        /// Contract ABI
+        GetAbi => "getabi",
+        /// Contract source code
+        GetSourceCode => "getsourcecode",
+        /// Contract creator and creation transaction
+        GetContractCreation => "getcontractcreation",
+        /// Submit source code for verification
+        VerifySourceCode => "verifysourcecode",
+        /// Verification status
+        CheckVerifyStatus => "checkverifystatus",
+        /// Submit a proxy for verification
+        VerifyProxyContract => "verifyproxycontract",
+        /// Proxy verification status
+        CheckProxyVerification => "checkproxyverification",
+        /// Transaction execution status
+        GetStatus => "getstatus",
+        /// Block number closest to a timestamp
+        GetBlockNoByTime => "getblocknobytime",
+        /// Event logs
+        GetLogs => "getLogs",
+        /// Current block number
+        EthBlockNumber => "eth_blockNumber",
+        /// Block by number
+        EthGetBlockByNumber => "eth_getBlockByNumber",
+        /// Transaction by hash
+        EthGetTransactionByHash => "eth_getTransactionByHash",
+        /// Transaction receipt
+        EthGetTransactionReceipt => "eth_getTransactionReceipt",
+        /// Transaction count of an address
+        EthGetTransactionCount => "eth_getTransactionCount",
+        /// Gas price oracle
+        GasOracle => "gasoracle",
+    }
+}
+
+impl From<TransactionType> for Action {
+    fn from(tx_type: TransactionType) -> Self {
+        match tx_type {
+            TransactionType::Normal => Action::TxList,
+            TransactionType::Internal => Action::TxListInternal,
+            TransactionType::Token => Action::TokenTx,
+            TransactionType::TokenNft => Action::TokenNftTx,
+            TransactionType::Token1155 => Action::Token1155Tx,
+        }
+    }
+}
+
+/// A request to one API action
+///
+/// [`EtherscanClient::call`](crate::EtherscanClient::call) sends the request
+/// and deserializes the `result` field of the response into `Response`.
+pub trait Endpoint {
+    /// Type the `result` field is deserialized into
+    type Response: DeserializeOwned;
+
+    /// API module
+    fn module(&self) -> Module;
+
+    /// API action
+    fn action(&self) -> Action;
+
+    /// Query parameters other than `module`, `action` and `apikey`
+    ///
+    /// Fails when the request was configured with invalid input.
+    fn params(&self) -> Result<Vec<(String, String)>>;
+
+    /// Form fields sent as a POST body; the request is a GET when empty
+    fn form(&self) -> Result<Vec<(String, String)>> {
+        Ok(Vec::new())
+    }
+}
+
+/// An [`Endpoint`] built from a module, an action and parameters
+///
+/// `T` is the type the result is deserialized into, [`serde_json::Value`]
+/// when left out.
+pub struct Call<T = serde_json::Value> {
+    module: Module,
+    action: Action,
+    params: Vec<(String, String)>,
+    form: Vec<(String, String)>,
+    response: PhantomData<fn() -> T>,
+}
+
+impl<T> Call<T> {
+    /// Create a call without parameters
+    pub fn new(module: impl Into<Module>, action: impl Into<Action>) -> Self {
+        Self {
+            module: module.into(),
+            action: action.into(),
+            params: Vec::new(),
+            form: Vec::new(),
+            response: PhantomData,
+        }
+    }
+
+    /// Add a query parameter
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Add several query parameters
+    pub fn with_params(mut self, params: &[(&str, &str)]) -> Self {
+        self.params.extend(
+            params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        self
+    }
+
+    /// Send `form` as a POST body
+    pub fn with_form(mut self, form: Vec<(String, String)>) -> Self {
+        self.form = form;
+        self
+    }
+}
+
+impl<T: DeserializeOwned> Endpoint for Call<T> {
+    type Response = T;
+
+    fn module(&self) -> Module {
+        self.module.clone()
+    }
+
+    fn action(&self) -> Action {
+        self.action.clone()
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(self.params.clone())
+    }
+
+    fn form(&self) -> Result<Vec<(String, String)>> {
+        Ok(self.form.clone())
+    }
+}
+
+impl<T> Clone for Call<T> {
+    fn clone(&self) -> Self {
+        Self {
+            module: self.module.clone(),
+            action: self.action.clone(),
+            params: self.params.clone(),
+            form: self.form.clone(),
+            response: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Call<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Call")
+            .field("module", &self.module)
+            .field("action", &self.action)
+            .field("params", &self.params)
+            .field("form", &self.form)
+            .finish()
+    }
+}
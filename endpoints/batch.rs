@@ -0,0 +1,154 @@
+//! Chunked, concurrent execution for endpoints that accept a limited number of inputs
+//!
+//! Endpoints such as `balancemulti` (20 addresses) and `getcontractcreation`
+//! (5 addresses) cap the size of a single call. The helpers in this module split
+//! larger inputs into API-sized chunks, run them concurrently (requests are still
+//! spaced by the client's rate limiter), and keep per-chunk failures next to the
+//! successful results.
+
+use crate::Result;
+#[cfg(any(feature = "accounts", feature = "contracts"))]
+use futures::future::join_all;
+#[cfg(any(feature = "accounts", feature = "contracts"))]
+use std::collections::HashMap;
+#[cfg(any(feature = "accounts", feature = "contracts"))]
+use std::future::Future;
+
+/// Outcome of a single chunk of a batched request
+#[derive(Debug)]
+pub struct ChunkResult<T> {
+    /// Position of this chunk within the batch
+    pub index: usize,
+    /// Normalized inputs sent in this chunk
+    pub inputs: Vec<String>,
+    /// Results for this chunk in input order, or the error that made it fail
+    pub result: Result<Vec<T>>,
+}
+
+impl<T> ChunkResult<T> {
+    /// Check if this chunk completed successfully
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Results of a batched request, with chunks kept in input order
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    /// Per-chunk outcomes, ordered as the chunks were cut from the input
+    pub chunks: Vec<ChunkResult<T>>,
+}
+
+impl<T> BatchResult<T> {
+    /// Check if every chunk completed successfully
+    pub fn is_complete(&self) -> bool {
+        self.chunks.iter().all(ChunkResult::is_ok)
+    }
+
+    /// Iterate over all successful results in input order
+    pub fn results(&self) -> impl Iterator<Item = &T> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| chunk.result.as_ref().ok())
+            .flatten()
+    }
+
+    /// Iterate over the chunks that failed
+    pub fn failures(&self) -> impl Iterator<Item = &ChunkResult<T>> {
+        self.chunks.iter().filter(|chunk| !chunk.is_ok())
+    }
+
+    /// Inputs that belong to failed chunks, in input order
+    pub fn failed_inputs(&self) -> Vec<&str> {
+        self.failures()
+            .flat_map(|chunk| chunk.inputs.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Consume the batch and return all successful results in input order,
+    /// dropping failed chunks
+    pub fn into_results(self) -> Vec<T> {
+        self.chunks
+            .into_iter()
+            .filter_map(|chunk| chunk.result.ok())
+            .flatten()
+            .collect()
+    }
+
+    /// Consume the batch, returning all results or the first chunk error
+    pub fn into_result(self) -> Result<Vec<T>> {
+        let mut results = Vec::new();
+        for chunk in self.chunks {
+            results.extend(chunk.result?);
+        }
+        Ok(results)
+    }
+}
+
+/// Split `inputs` into chunks of `chunk_size`, run `fetch` for each chunk
+/// concurrently and reorder every chunk's items to match its inputs.
+///
+/// `key` extracts the input an item belongs to; items whose key is not part of
+/// the chunk are kept after the matched ones in the order the API returned them.
+#[cfg(any(feature = "accounts", feature = "contracts"))]
+pub(crate) async fn execute_chunked<T, F, Fut, K>(
+    inputs: Vec<String>,
+    chunk_size: usize,
+    fetch: F,
+    key: K,
+) -> BatchResult<T>
+where
+    F: Fn(Vec<String>) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+    K: Fn(&T) -> String,
+{
+    let chunks: Vec<Vec<String>> = inputs
+        .chunks(chunk_size.max(1))
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    let outcomes = join_all(chunks.iter().cloned().map(&fetch)).await;
+
+    let chunks = chunks
+        .into_iter()
+        .zip(outcomes)
+        .enumerate()
+        .map(|(index, (inputs, result))| {
+            let result = result.map(|items| reorder(&inputs, items, &key));
+            ChunkResult {
+                index,
+                inputs,
+                result,
+            }
+        })
+        .collect();
+
+    BatchResult { chunks }
+}
+
+#[cfg(any(feature = "accounts", feature = "contracts"))]
+fn reorder<T, K>(inputs: &[String], items: Vec<T>, key: &K) -> Vec<T>
+where
+    K: Fn(&T) -> String,
+{
+    let positions: HashMap<&str, usize> = inputs
+        .iter()
+        .enumerate()
+        .map(|(position, input)| (input.as_str(), position))
+        .collect();
+
+    let mut keyed: Vec<(usize, T)> = items
+        .into_iter()
+        .enumerate()
+        .map(|(returned, item)| {
+            let position = positions
+                .get(key(&item).to_lowercase().as_str())
+                .copied()
+                .unwrap_or(inputs.len() + returned);
+            (position, item)
+        })
+        .collect();
+
+    keyed.sort_by_key(|(position, _)| *position);
+    keyed.into_iter().map(|(_, item)| item).collect()
+}
@@ -1,4 +1,12 @@
-use crate::EtherscanClient;
+#[cfg(any(feature = "accounts", feature = "logs"))]
+use crate::types::Pagination;
+use crate::{
+    endpoints::{Action, Endpoint, Module},
+    models::StringNumber,
+    types::Closest,
+    EtherscanClient, EtherscanError, Result,
+};
+use chrono::{DateTime, Utc};
 
 /// Block-related API endpoints
 #[derive(Debug)]
@@ -11,5 +19,110 @@ impl<'a> Blocks<'a> {
         Self { client }
     }
 
-    // TODO: Implement block endpoints
+    /// Get the block number mined closest to a point in time
+    ///
+    /// Results are cached per network on the client (and shared by its
+    /// clones), so repeated lookups of the same time are free.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::{Closest, EtherscanClient};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let new_year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    ///     let block = client
+    ///         .blocks()
+    ///         .block_number_by_time(new_year, Closest::After)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn block_number_by_time(&self, time: DateTime<Utc>, closest: Closest) -> Result<u64> {
+        let timestamp = u64::try_from(time.timestamp()).map_err(|_| {
+            EtherscanError::InvalidParams(format!("Timestamp before the Unix epoch: {}", time))
+        })?;
+        self.block_number_by_timestamp(timestamp, closest).await
+    }
+
+    /// Get the block number mined closest to a Unix timestamp (in seconds)
+    pub async fn block_number_by_timestamp(&self, timestamp: u64, closest: Closest) -> Result<u64> {
+        if let Some(block) = self.client.cached_block_by_time(timestamp, closest) {
+            return Ok(block);
+        }
+
+        let block = self
+            .client
+            .call(&GetBlockNoByTime::new(timestamp, closest))
+            .await?;
+        self.client
+            .cache_block_by_time(timestamp, closest, block.value());
+        Ok(block.value())
+    }
+
+    /// Resolve the `since`/`until` bounds of a pagination into block numbers
+    ///
+    /// `since` becomes the first block at or after the start time and `until`
+    /// the last block at or before the end time; explicit block bounds are
+    /// replaced.
+    #[cfg(any(feature = "accounts", feature = "logs"))]
+    pub(crate) async fn resolve_time_range(
+        &self,
+        mut pagination: Pagination,
+    ) -> Result<Pagination> {
+        if let (Some(since), Some(until)) = (pagination.since, pagination.until) {
+            if since > until {
+                return Err(EtherscanError::InvalidParams(format!(
+                    "Time range starts at {} after it ends at {}",
+                    since, until
+                )));
+            }
+        }
+
+        if let Some(since) = pagination.since.take() {
+            pagination.start_block = Some(self.block_number_by_time(since, Closest::After).await?);
+        }
+        if let Some(until) = pagination.until.take() {
+            pagination.end_block = Some(self.block_number_by_time(until, Closest::Before).await?);
+        }
+
+        Ok(pagination)
+    }
+}
+
+/// The `getblocknobytime` action, see [`Blocks::block_number_by_timestamp`]
+///
+/// Unlike the method, sending this directly skips the client's block cache.
+#[derive(Debug, Clone, Copy)]
+pub struct GetBlockNoByTime {
+    timestamp: u64,
+    closest: Closest,
+}
+
+impl GetBlockNoByTime {
+    /// Request the block mined closest to a Unix timestamp (in seconds)
+    pub fn new(timestamp: u64, closest: Closest) -> Self {
+        Self { timestamp, closest }
+    }
+}
+
+impl Endpoint for GetBlockNoByTime {
+    type Response = StringNumber;
+
+    fn module(&self) -> Module {
+        Module::Block
+    }
+
+    fn action(&self) -> Action {
+        Action::GetBlockNoByTime
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![
+            ("timestamp".to_string(), self.timestamp.to_string()),
+            ("closest".to_string(), self.closest.as_str().to_string()),
+        ])
+    }
 }
@@ -1,13 +1,26 @@
 use crate::{
+    endpoints::{
+        batch::{execute_chunked, BatchResult},
+        Action, Endpoint, Module,
+    },
     error::validation::normalize_address,
+    models::{ContractAbi, ContractCreation, ContractSource},
+    EtherscanClient, EtherscanError, Result,
+};
+#[cfg(feature = "verification")]
+use crate::{
     models::{
-        CodeFormat, ContractAbi, ContractCreation, ContractSource, LibraryLink,
-        OptimizationSettings, ProxyVerificationStatus, VerificationRequest, VerificationStatus,
+        CodeFormat, LibraryLink, OptimizationSettings, ProxyVerificationStatus,
+        VerificationRequest, VerificationStatus,
     },
-    EtherscanClient, EtherscanError, Result,
+    spec::QuerySpec,
 };
+#[cfg(feature = "verification")]
 use std::collections::HashMap;
 
+/// Maximum number of addresses accepted by a single `getcontractcreation` call
+pub const MAX_CONTRACT_CREATION_ADDRESSES: usize = 5;
+
 /// Contract-related API endpoints
 #[derive(Debug)]
 pub struct Contracts<'a> {
@@ -38,11 +51,7 @@ impl<'a> Contracts<'a> {
     /// }
     /// ```
     pub async fn get_abi<S: AsRef<str>>(&self, address: S) -> Result<ContractAbi> {
-        let address = normalize_address(address.as_ref())?;
-
-        let params = [("address", address.as_str())];
-
-        self.client.get("contract", "getabi", &params).await
+        self.client.call(&GetAbi::new(address)).await
     }
 
     /// Get Contract Source Code for Verified Contract Source Codes
@@ -64,11 +73,7 @@ impl<'a> Contracts<'a> {
     /// }
     /// ```
     pub async fn get_source_code<S: AsRef<str>>(&self, address: S) -> Result<Vec<ContractSource>> {
-        let address = normalize_address(address.as_ref())?;
-
-        let params = [("address", address.as_str())];
-
-        self.client.get("contract", "getsourcecode", &params).await
+        self.client.call(&GetSourceCode::new(address)).await
     }
 
     /// Get Contract Creator and Creation Tx Hash
@@ -96,31 +101,36 @@ impl<'a> Contracts<'a> {
         &self,
         addresses: &[S],
     ) -> Result<Vec<ContractCreation>> {
+        self.client.call(&GetContractCreation::new(addresses)).await
+    }
+
+    /// Get contract creators and creation tx hashes for any number of contracts
+    ///
+    /// The addresses are split into chunks of 5, which are fetched concurrently
+    /// under the client's rate limit. Results are returned in input order and a
+    /// failed chunk does not discard the results of the other chunks.
+    pub async fn get_contract_creation_batched<S: AsRef<str>>(
+        &self,
+        addresses: &[S],
+    ) -> Result<BatchResult<ContractCreation>> {
         if addresses.is_empty() {
             return Err(EtherscanError::InvalidParams(
                 "At least one address required".to_string(),
             ));
         }
 
-        if addresses.len() > 5 {
-            return Err(EtherscanError::InvalidParams(
-                "Maximum 5 addresses allowed".to_string(),
-            ));
-        }
-
-        // Validate and normalize all addresses
         let normalized: Result<Vec<String>> = addresses
             .iter()
             .map(|addr| normalize_address(addr.as_ref()))
             .collect();
-        let normalized = normalized?;
-
-        let address_list = normalized.join(",");
-        let params = [("contractaddresses", address_list.as_str())];
 
-        self.client
-            .get("contract", "getcontractcreation", &params)
-            .await
+        Ok(execute_chunked(
+            normalized?,
+            MAX_CONTRACT_CREATION_ADDRESSES,
+            |chunk| async move { self.get_contract_creation(&chunk).await },
+            |creation: &ContractCreation| creation.contract_address.as_str().to_string(),
+        )
+        .await)
     }
 
     /// Create a Solidity verification builder
@@ -147,7 +157,8 @@ impl<'a> Contracts<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn verify_solidity<S: AsRef<str>>(&self, address: S) -> SolidityVerificationBuilder<'a> {
+    #[cfg(feature = "verification")]
+    pub fn verify_solidity<S: AsRef<str>>(&self, address: S) -> SolidityVerificationBuilder {
         SolidityVerificationBuilder::new(self.client, address.as_ref())
     }
 
@@ -155,7 +166,8 @@ impl<'a> Contracts<'a> {
     ///
     /// # Arguments
     /// * `address` - The contract address to verify
-    pub fn verify_vyper<S: AsRef<str>>(&self, address: S) -> VyperVerificationBuilder<'a> {
+    #[cfg(feature = "verification")]
+    pub fn verify_vyper<S: AsRef<str>>(&self, address: S) -> VyperVerificationBuilder {
         VyperVerificationBuilder::new(self.client, address.as_ref())
     }
 
@@ -177,22 +189,20 @@ impl<'a> Contracts<'a> {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "verification")]
     pub async fn check_verification_status<S: AsRef<str>>(
         &self,
         guid: S,
     ) -> Result<VerificationStatus> {
-        let params = [("guid", guid.as_ref())];
-
-        self.client
-            .get("contract", "checkverifystatus", &params)
-            .await
+        self.client.call(&CheckVerifyStatus::new(guid)).await
     }
 
     /// Create a proxy verification builder
     ///
     /// # Arguments
     /// * `address` - The proxy contract address to verify
-    pub fn verify_proxy<S: AsRef<str>>(&self, address: S) -> ProxyVerificationBuilder<'a> {
+    #[cfg(feature = "verification")]
+    pub fn verify_proxy<S: AsRef<str>>(&self, address: S) -> ProxyVerificationBuilder {
         ProxyVerificationBuilder::new(self.client, address.as_ref())
     }
 
@@ -200,22 +210,210 @@ impl<'a> Contracts<'a> {
     ///
     /// # Arguments
     /// * `guid` - The GUID returned from a proxy verification request
+    #[cfg(feature = "verification")]
     pub async fn check_proxy_verification_status<S: AsRef<str>>(
         &self,
         guid: S,
     ) -> Result<ProxyVerificationStatus> {
-        let params = [("guid", guid.as_ref())];
+        self.client.call(&CheckProxyVerification::new(guid)).await
+    }
+}
 
-        self.client
-            .get("contract", "checkproxyverification", &params)
-            .await
+/// The `getabi` action, see [`Contracts::get_abi`]
+#[derive(Debug, Clone)]
+pub struct GetAbi {
+    address: String,
+}
+
+impl GetAbi {
+    /// Request the ABI of a verified contract
+    pub fn new<S: AsRef<str>>(address: S) -> Self {
+        Self {
+            address: address.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetAbi {
+    type Response = ContractAbi;
+
+    fn module(&self) -> Module {
+        Module::Contract
+    }
+
+    fn action(&self) -> Action {
+        Action::GetAbi
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![(
+            "address".to_string(),
+            normalize_address(&self.address)?,
+        )])
+    }
+}
+
+/// The `getsourcecode` action, see [`Contracts::get_source_code`]
+#[derive(Debug, Clone)]
+pub struct GetSourceCode {
+    address: String,
+}
+
+impl GetSourceCode {
+    /// Request the source code of a verified contract
+    pub fn new<S: AsRef<str>>(address: S) -> Self {
+        Self {
+            address: address.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetSourceCode {
+    type Response = Vec<ContractSource>;
+
+    fn module(&self) -> Module {
+        Module::Contract
+    }
+
+    fn action(&self) -> Action {
+        Action::GetSourceCode
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![(
+            "address".to_string(),
+            normalize_address(&self.address)?,
+        )])
+    }
+}
+
+/// The `getcontractcreation` action, see [`Contracts::get_contract_creation`]
+#[derive(Debug, Clone)]
+pub struct GetContractCreation {
+    addresses: Vec<String>,
+}
+
+impl GetContractCreation {
+    /// Request the creators of up to [`MAX_CONTRACT_CREATION_ADDRESSES`] contracts
+    pub fn new<S: AsRef<str>>(addresses: &[S]) -> Self {
+        Self {
+            addresses: addresses.iter().map(|a| a.as_ref().to_string()).collect(),
+        }
+    }
+}
+
+impl Endpoint for GetContractCreation {
+    type Response = Vec<ContractCreation>;
+
+    fn module(&self) -> Module {
+        Module::Contract
+    }
+
+    fn action(&self) -> Action {
+        Action::GetContractCreation
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        if self.addresses.is_empty() {
+            return Err(EtherscanError::InvalidParams(
+                "At least one address required".to_string(),
+            ));
+        }
+
+        if self.addresses.len() > MAX_CONTRACT_CREATION_ADDRESSES {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Maximum {} addresses allowed",
+                MAX_CONTRACT_CREATION_ADDRESSES
+            )));
+        }
+
+        let normalized: Result<Vec<String>> = self
+            .addresses
+            .iter()
+            .map(|addr| normalize_address(addr))
+            .collect();
+
+        Ok(vec![(
+            "contractaddresses".to_string(),
+            normalized?.join(","),
+        )])
+    }
+}
+
+/// The `checkverifystatus` action, see [`Contracts::check_verification_status`]
+#[cfg(feature = "verification")]
+#[derive(Debug, Clone)]
+pub struct CheckVerifyStatus {
+    guid: String,
+}
+
+#[cfg(feature = "verification")]
+impl CheckVerifyStatus {
+    /// Request the status of a source code verification
+    pub fn new<S: AsRef<str>>(guid: S) -> Self {
+        Self {
+            guid: guid.as_ref().to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "verification")]
+impl Endpoint for CheckVerifyStatus {
+    type Response = VerificationStatus;
+
+    fn module(&self) -> Module {
+        Module::Contract
+    }
+
+    fn action(&self) -> Action {
+        Action::CheckVerifyStatus
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![("guid".to_string(), self.guid.clone())])
+    }
+}
+
+/// The `checkproxyverification` action, see
+/// [`Contracts::check_proxy_verification_status`]
+#[cfg(feature = "verification")]
+#[derive(Debug, Clone)]
+pub struct CheckProxyVerification {
+    guid: String,
+}
+
+#[cfg(feature = "verification")]
+impl CheckProxyVerification {
+    /// Request the status of a proxy verification
+    pub fn new<S: AsRef<str>>(guid: S) -> Self {
+        Self {
+            guid: guid.as_ref().to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "verification")]
+impl Endpoint for CheckProxyVerification {
+    type Response = ProxyVerificationStatus;
+
+    fn module(&self) -> Module {
+        Module::Contract
+    }
+
+    fn action(&self) -> Action {
+        Action::CheckProxyVerification
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![("guid".to_string(), self.guid.clone())])
     }
 }
 
 /// Builder for Solidity contract verification
+#[cfg(feature = "verification")]
 #[derive(Debug)]
-pub struct SolidityVerificationBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct SolidityVerificationBuilder {
+    client: EtherscanClient,
     address: String,
     source_code: Option<String>,
     contract_name: Option<String>,
@@ -228,10 +426,11 @@ pub struct SolidityVerificationBuilder<'a> {
     evm_version: Option<String>,
 }
 
-impl<'a> SolidityVerificationBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+#[cfg(feature = "verification")]
+impl SolidityVerificationBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             source_code: None,
             contract_name: None,
@@ -245,6 +444,36 @@ impl<'a> SolidityVerificationBuilder<'a> {
         }
     }
 
+    /// Rebuild the submission from the fields of a [`QuerySpec`]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        source_code: Option<String>,
+        contract_name: Option<String>,
+        compiler_version: Option<String>,
+        optimization_settings: OptimizationSettings,
+        constructor_arguments: Option<String>,
+        code_format: CodeFormat,
+        libraries: Vec<LibraryLink>,
+        license_type: Option<String>,
+        evm_version: Option<String>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            source_code,
+            contract_name,
+            compiler_version,
+            optimization_settings,
+            constructor_arguments,
+            code_format,
+            libraries,
+            license_type,
+            evm_version,
+        }
+    }
+
     /// Set the source code for verification
     pub fn source_code<S: Into<String>>(mut self, source_code: S) -> Self {
         self.source_code = Some(source_code.into());
@@ -315,6 +544,22 @@ impl<'a> SolidityVerificationBuilder<'a> {
         self
     }
 
+    /// Serializable form of the submission
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::VerifySolidity {
+            address: self.address.clone(),
+            source_code: self.source_code.clone(),
+            contract_name: self.contract_name.clone(),
+            compiler_version: self.compiler_version.clone(),
+            optimization_settings: self.optimization_settings.clone(),
+            constructor_arguments: self.constructor_arguments.clone(),
+            code_format: self.code_format,
+            libraries: self.libraries.clone(),
+            license_type: self.license_type.clone(),
+            evm_version: self.evm_version.clone(),
+        }
+    }
+
     /// Get the source code (for testing)
     pub fn get_source_code(&self) -> &Option<String> {
         &self.source_code
@@ -411,18 +656,21 @@ impl<'a> SolidityVerificationBuilder<'a> {
             form_data.insert(address_key, library.address.clone());
         }
 
-        // TODO: Implement POST method in client for verification endpoints
-        // For now, return a placeholder to allow compilation
-        Err(EtherscanError::InvalidParams(
-            "POST method not yet implemented".to_string(),
-        ))
+        self.client
+            .post(
+                Module::Contract,
+                Action::VerifySourceCode,
+                form_data.into_iter().collect(),
+            )
+            .await
     }
 }
 
 /// Builder for Vyper contract verification
+#[cfg(feature = "verification")]
 #[derive(Debug)]
-pub struct VyperVerificationBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct VyperVerificationBuilder {
+    client: EtherscanClient,
     address: String,
     source_code: Option<String>,
     contract_name: Option<String>,
@@ -431,10 +679,11 @@ pub struct VyperVerificationBuilder<'a> {
     optimization_settings: OptimizationSettings,
 }
 
-impl<'a> VyperVerificationBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+#[cfg(feature = "verification")]
+impl VyperVerificationBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             source_code: None,
             contract_name: None,
@@ -444,6 +693,27 @@ impl<'a> VyperVerificationBuilder<'a> {
         }
     }
 
+    /// Rebuild the submission from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        source_code: Option<String>,
+        contract_name: Option<String>,
+        compiler_version: Option<String>,
+        constructor_arguments: Option<String>,
+        optimization_settings: OptimizationSettings,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            source_code,
+            contract_name,
+            compiler_version,
+            constructor_arguments,
+            optimization_settings,
+        }
+    }
+
     /// Set the source code for verification
     pub fn source_code<S: Into<String>>(mut self, source_code: S) -> Self {
         self.source_code = Some(source_code.into());
@@ -478,6 +748,18 @@ impl<'a> VyperVerificationBuilder<'a> {
         self
     }
 
+    /// Serializable form of the submission
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::VerifyVyper {
+            address: self.address.clone(),
+            source_code: self.source_code.clone(),
+            contract_name: self.contract_name.clone(),
+            compiler_version: self.compiler_version.clone(),
+            constructor_arguments: self.constructor_arguments.clone(),
+            optimization_settings: self.optimization_settings.clone(),
+        }
+    }
+
     /// Get the source code (for testing)
     pub fn get_source_code(&self) -> &Option<String> {
         &self.source_code
@@ -543,37 +825,62 @@ impl<'a> VyperVerificationBuilder<'a> {
             CodeFormat::VyperJson.as_str().to_string(),
         );
 
-        // TODO: Implement POST method in client for verification endpoints
-        // For now, return a placeholder to allow compilation
-        Err(EtherscanError::InvalidParams(
-            "POST method not yet implemented".to_string(),
-        ))
+        self.client
+            .post(
+                Module::Contract,
+                Action::VerifySourceCode,
+                form_data.into_iter().collect(),
+            )
+            .await
     }
 }
 
 /// Builder for proxy contract verification
+#[cfg(feature = "verification")]
 #[derive(Debug)]
-pub struct ProxyVerificationBuilder<'a> {
-    client: &'a EtherscanClient,
+pub struct ProxyVerificationBuilder {
+    client: EtherscanClient,
     address: String,
     expected_implementation: Option<String>,
 }
 
-impl<'a> ProxyVerificationBuilder<'a> {
-    fn new(client: &'a EtherscanClient, address: &str) -> Self {
+#[cfg(feature = "verification")]
+impl ProxyVerificationBuilder {
+    fn new(client: &EtherscanClient, address: &str) -> Self {
         Self {
-            client,
+            client: client.clone(),
             address: address.to_string(),
             expected_implementation: None,
         }
     }
 
+    /// Rebuild the submission from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: String,
+        expected_implementation: Option<String>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            expected_implementation,
+        }
+    }
+
     /// Set the expected implementation address
     pub fn expected_implementation<S: Into<String>>(mut self, implementation: S) -> Self {
         self.expected_implementation = Some(implementation.into());
         self
     }
 
+    /// Serializable form of the submission
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::VerifyProxy {
+            address: self.address.clone(),
+            expected_implementation: self.expected_implementation.clone(),
+        }
+    }
+
     /// Get the expected implementation (for testing)
     pub fn get_expected_implementation(&self) -> &Option<String> {
         &self.expected_implementation
@@ -591,10 +898,12 @@ impl<'a> ProxyVerificationBuilder<'a> {
             form_data.insert("expectedimplementation".to_string(), normalized_impl);
         }
 
-        // TODO: Implement POST method in client for verification endpoints
-        // For now, return a placeholder to allow compilation
-        Err(EtherscanError::InvalidParams(
-            "POST method not yet implemented".to_string(),
-        ))
+        self.client
+            .post(
+                Module::Contract,
+                Action::VerifyProxyContract,
+                form_data.into_iter().collect(),
+            )
+            .await
     }
 }
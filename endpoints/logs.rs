@@ -0,0 +1,255 @@
+//! Event log endpoints (`logs` module)
+
+use crate::{
+    endpoints::{
+        paging::{paginate, PagedQuery},
+        Action, Module,
+    },
+    error::validation::normalize_address,
+    models::TransactionLog,
+    spec::QuerySpec,
+    EtherscanClient, EtherscanError, Pagination, Result,
+};
+use futures::Stream;
+
+/// Maximum number of logs returned by a single `getLogs` call
+pub const MAX_LOGS_PER_PAGE: u32 = 1_000;
+
+/// Number of indexed topics a log can be filtered on
+pub const MAX_TOPICS: usize = 4;
+
+/// Event log API endpoints
+#[derive(Debug)]
+pub struct Logs<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Logs<'a> {
+    /// Create the endpoint group for `client`
+    pub fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Query event logs by emitting address and topics
+    ///
+    /// At least an address or one topic is required. When several topics are
+    /// set, a log must match all of them.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let transfers = client.logs()
+    ///         .get_logs()
+    ///         .address("0xdAC17F958D2ee523a2206206994597C13D831ec7")
+    ///         .topic(0, "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+    ///         .block_range(17_000_000, 17_000_100)
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_logs(&self) -> LogQueryBuilder {
+        LogQueryBuilder::new(self.client)
+    }
+}
+
+/// Builder for event log queries
+#[derive(Debug)]
+pub struct LogQueryBuilder {
+    client: EtherscanClient,
+    address: Option<String>,
+    topics: [Option<String>; MAX_TOPICS],
+    /// First topic index set past the last topic, reported when the query runs
+    invalid_topic: Option<usize>,
+    pagination: Pagination,
+}
+
+impl LogQueryBuilder {
+    fn new(client: &EtherscanClient) -> Self {
+        Self {
+            client: client.clone(),
+            address: None,
+            topics: Default::default(),
+            invalid_topic: None,
+            pagination: Pagination::new(),
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        address: Option<String>,
+        topics: [Option<String>; MAX_TOPICS],
+        pagination: Pagination,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            address,
+            topics,
+            invalid_topic: None,
+            pagination,
+        }
+    }
+
+    /// Only include logs emitted by `address`
+    pub fn address<S: AsRef<str>>(mut self, address: S) -> Self {
+        self.address = Some(address.as_ref().to_string());
+        self
+    }
+
+    /// Only include logs whose topic at `index` (0 to 3) equals `topic`
+    ///
+    /// A larger index makes the query fail with [`EtherscanError::InvalidParams`].
+    pub fn topic<S: AsRef<str>>(mut self, index: usize, topic: S) -> Self {
+        match self.topics.get_mut(index) {
+            Some(slot) => *slot = Some(topic.as_ref().to_lowercase()),
+            None => {
+                self.invalid_topic.get_or_insert(index);
+            }
+        }
+        self
+    }
+
+    /// Set the page number (starting from 1)
+    pub fn page(mut self, page: u32) -> Self {
+        self.pagination = self.pagination.page(page);
+        self
+    }
+
+    /// Set the number of logs per page (max 1000)
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.pagination = self.pagination.offset(offset);
+        self
+    }
+
+    /// Set the starting block number
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.start_block(block);
+        self
+    }
+
+    /// Set the ending block number
+    pub fn end_block(mut self, block: u64) -> Self {
+        self.pagination = self.pagination.end_block(block);
+        self
+    }
+
+    /// Set a block range
+    pub fn block_range(mut self, start: u64, end: u64) -> Self {
+        self.pagination = self.pagination.block_range(start, end);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::Logs {
+            address: self.address.clone(),
+            topics: self.topics.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
+    /// Get the address filter (for testing)
+    pub fn get_address(&self) -> &Option<String> {
+        &self.address
+    }
+
+    /// Get the topic filters by index (for testing)
+    pub fn get_topics(&self) -> &[Option<String>] {
+        &self.topics
+    }
+
+    /// Get the block range and paging (for testing)
+    pub fn get_pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    /// Execute the query
+    pub async fn execute(self) -> Result<Vec<TransactionLog>> {
+        self.fetch_page(&self.pagination).await
+    }
+
+    /// Stream every matching log in ascending block order, following pages
+    ///
+    /// Pages hold up to 1000 logs unless a smaller `offset` is set.
+    pub fn stream(mut self) -> impl Stream<Item = Result<TransactionLog>> {
+        let page_size = self
+            .pagination
+            .offset
+            .unwrap_or(MAX_LOGS_PER_PAGE)
+            .min(MAX_LOGS_PER_PAGE);
+        self.pagination = self.pagination.offset(page_size);
+        paginate(self)
+    }
+}
+
+impl PagedQuery for LogQueryBuilder {
+    type Item = TransactionLog;
+
+    fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<TransactionLog>> {
+        if let Some(index) = self.invalid_topic {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Topic index {} is out of range; logs have topics 0 to {}",
+                index,
+                MAX_TOPICS - 1
+            )));
+        }
+
+        let set_topics: Vec<usize> = (0..MAX_TOPICS)
+            .filter(|&index| self.topics[index].is_some())
+            .collect();
+        if self.address.is_none() && set_topics.is_empty() {
+            return Err(EtherscanError::InvalidParams(
+                "An address or at least one topic is required".to_string(),
+            ));
+        }
+
+        let mut params: Vec<(String, String)> = Vec::new();
+        if let Some(address) = &self.address {
+            params.push(("address".to_string(), normalize_address(address)?));
+        }
+        for &index in &set_topics {
+            let topic = self.topics[index].clone().unwrap_or_default();
+            params.push((format!("topic{}", index), topic));
+        }
+        for (position, &first) in set_topics.iter().enumerate() {
+            for &second in &set_topics[position + 1..] {
+                params.push((format!("topic{}_{}_opr", first, second), "and".to_string()));
+            }
+        }
+
+        if let Some(block) = pagination.start_block {
+            params.push(("fromBlock".to_string(), block.to_string()));
+        }
+        if let Some(block) = pagination.end_block {
+            params.push(("toBlock".to_string(), block.to_string()));
+        }
+        if let Some(page) = pagination.page {
+            params.push(("page".to_string(), page.to_string()));
+        }
+        if let Some(offset) = pagination.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        self.client
+            .get(Module::Logs, Action::GetLogs, &params_ref)
+            .await
+    }
+}
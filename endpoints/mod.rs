@@ -1,43 +1,37 @@
 //! API endpoint implementations for the Etherscan client
 
-use crate::EtherscanClient;
-
+#[cfg(feature = "accounts")]
 pub mod accounts;
+pub mod api;
+pub mod batch;
 pub mod blocks;
+#[cfg(feature = "contracts")]
 pub mod contracts;
+#[cfg(feature = "logs")]
+pub mod logs;
+#[cfg(any(feature = "accounts", feature = "logs"))]
+pub(crate) mod paging;
+#[cfg(feature = "proxy")]
+pub mod proxy;
+#[cfg(feature = "stats")]
 pub mod stats;
+#[cfg(feature = "accounts")]
 pub mod tokens;
 pub mod transactions;
 
+#[cfg(feature = "accounts")]
 pub use accounts::Accounts;
+pub use api::{Action, Call, Endpoint, Module};
+pub use batch::{BatchResult, ChunkResult};
 pub use blocks::Blocks;
+#[cfg(feature = "contracts")]
 pub use contracts::Contracts;
+#[cfg(feature = "logs")]
+pub use logs::Logs;
+#[cfg(feature = "proxy")]
+pub use proxy::Proxy;
+#[cfg(feature = "stats")]
 pub use stats::Stats;
+#[cfg(feature = "accounts")]
 pub use tokens::Tokens;
 pub use transactions::Transactions;
-
-/// Base trait for all endpoint groups
-pub trait EndpointGroup {
-    fn new(client: &EtherscanClient) -> Self
-    where
-        Self: Sized;
-}
-
-/// Macro to implement common endpoint functionality
-macro_rules! impl_endpoint {
-    ($name:ident) => {
-        impl<'a> $name<'a> {
-            pub fn new(client: &'a EtherscanClient) -> Self {
-                Self { client }
-            }
-        }
-
-        impl<'a> EndpointGroup for $name<'a> {
-            fn new(client: &EtherscanClient) -> Self {
-                Self::new(client)
-            }
-        }
-    };
-}
-
-pub(crate) use impl_endpoint;
@@ -0,0 +1,99 @@
+//! Following list queries across pages
+
+use crate::{
+    models::BlockchainData,
+    types::{Pagination, Sort},
+    EtherscanClient, EtherscanError, Result,
+};
+use futures::{Stream, TryStreamExt};
+
+/// Largest `page × offset` window the list endpoints serve
+pub const MAX_RESULT_WINDOW: u32 = 10_000;
+
+/// A list query that can be followed across pages by moving its start block
+pub(crate) trait PagedQuery {
+    type Item: BlockchainData;
+
+    fn client(&self) -> &EtherscanClient;
+
+    fn pagination(&self) -> &Pagination;
+
+    async fn fetch_page(&self, pagination: &Pagination) -> Result<Vec<Self::Item>>;
+}
+
+/// Stream the results of a query in ascending block order, across pages
+///
+/// Etherscan serves at most 10 000 results per query window, so instead of
+/// paging the start block is moved to the last block seen and that block is
+/// fetched again in full. `offset` sets the page size (default 10 000). Fails
+/// if a single block holds more results than a page.
+pub(crate) fn paginate<'a, Q: PagedQuery + 'a>(
+    query: Q,
+) -> impl Stream<Item = Result<Q::Item>> + 'a {
+    let pages = futures::stream::try_unfold(
+        (query, None::<Pagination>, false),
+        |(query, pagination, done)| async move {
+            if done {
+                return Ok::<_, EtherscanError>(None);
+            }
+
+            let mut pagination = match pagination {
+                Some(pagination) => pagination,
+                None => {
+                    let pagination = query
+                        .client()
+                        .blocks()
+                        .resolve_time_range(query.pagination().clone())
+                        .await?;
+                    let page_size = pagination
+                        .offset
+                        .unwrap_or(MAX_RESULT_WINDOW)
+                        .clamp(1, MAX_RESULT_WINDOW);
+                    pagination.page(1).offset(page_size).sort(Sort::Ascending)
+                }
+            };
+            let page_size = pagination.offset.unwrap_or(MAX_RESULT_WINDOW);
+
+            let mut page = query.fetch_page(&pagination).await?;
+            let block = |item: &Q::Item| item.block_number().unwrap_or_default();
+            match split_page(&mut page, page_size, block)? {
+                None => Ok(Some((page, (query, None, true)))),
+                Some(last_block) => {
+                    pagination.start_block = Some(last_block);
+                    Ok(Some((page, (query, Some(pagination), false))))
+                }
+            }
+        },
+    );
+
+    pages
+        .map_ok(|page| futures::stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// Drop the last block of a full page, as it may continue on the next page
+///
+/// Returns the dropped block, where the next page starts, or `None` if the
+/// page is not full and so ends the query. Fails if the whole page is a
+/// single block.
+pub(crate) fn split_page<T>(
+    page: &mut Vec<T>,
+    page_size: u32,
+    block: impl Fn(&T) -> u64,
+) -> Result<Option<u64>> {
+    if page.len() < page_size as usize {
+        return Ok(None);
+    }
+
+    let first_block = page.first().map_or(0, &block);
+    let last_block = page.last().map_or(0, &block);
+    if first_block == last_block {
+        return Err(EtherscanError::InvalidParams(format!(
+            "Block {} has more than {} results; increase the page size",
+            last_block, page_size
+        )));
+    }
+
+    page.retain(|item| block(item) < last_block);
+    Ok(Some(last_block))
+}
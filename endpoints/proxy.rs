@@ -0,0 +1,463 @@
+//! JSON-RPC proxy endpoints
+
+use crate::{
+    endpoints::{Action, Endpoint, Module},
+    error::validation::normalize_address,
+    models::{Address, Block, ConfirmationOutcome, HexNumber, RpcTransaction, TransactionReceipt},
+    spec::QuerySpec,
+    EtherscanClient, EtherscanError, Result,
+};
+use futures::Stream;
+use std::time::Duration;
+
+/// Default time between two polls
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+/// Geth/Parity JSON-RPC proxy endpoints (`module=proxy`)
+#[derive(Debug)]
+pub struct Proxy<'a> {
+    client: &'a EtherscanClient,
+}
+
+impl<'a> Proxy<'a> {
+    /// Create the endpoint group for a client
+    pub fn new(client: &'a EtherscanClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the number of the most recent block
+    pub async fn block_number(&self) -> Result<u64> {
+        let block = self.client.call(&EthBlockNumber).await?;
+        Ok(block.value())
+    }
+
+    /// Get a block header by number, with transaction hashes only
+    ///
+    /// Returns `None` if the block has not been mined yet.
+    pub async fn block_by_number(&self, block_number: u64) -> Result<Option<Block>> {
+        self.client
+            .call(&EthGetBlockByNumber::new(block_number))
+            .await
+    }
+
+    /// Get a transaction by hash
+    ///
+    /// Returns `None` if the node does not know the transaction.
+    pub async fn transaction_by_hash<S: AsRef<str>>(
+        &self,
+        tx_hash: S,
+    ) -> Result<Option<RpcTransaction>> {
+        self.client
+            .call(&EthGetTransactionByHash::new(tx_hash))
+            .await
+    }
+
+    /// Get the receipt of a transaction by hash
+    ///
+    /// Returns `None` while the transaction is pending.
+    pub async fn transaction_receipt<S: AsRef<str>>(
+        &self,
+        tx_hash: S,
+    ) -> Result<Option<TransactionReceipt>> {
+        self.client
+            .call(&EthGetTransactionReceipt::new(tx_hash))
+            .await
+    }
+
+    /// Get the number of transactions mined from an address, i.e. its next nonce
+    pub async fn transaction_count<S: AsRef<str>>(&self, address: S) -> Result<u64> {
+        let count = self
+            .client
+            .call(&EthGetTransactionCount::new(address))
+            .await?;
+        Ok(count.value())
+    }
+
+    /// Follow the chain head block by block
+    pub fn watch_blocks(&self) -> BlockWatcher {
+        BlockWatcher::new(self.client)
+    }
+
+    /// Wait until a transaction is `confirmations` blocks deep
+    ///
+    /// The mined block counts as the first confirmation, so `1` resolves as
+    /// soon as a receipt exists. Waiting longer than `timeout` fails with
+    /// [`EtherscanError::Timeout`].
+    pub fn wait_for_confirmations<S: AsRef<str>>(
+        &self,
+        tx_hash: S,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> ConfirmationWaitBuilder {
+        ConfirmationWaitBuilder {
+            client: self.client.clone(),
+            tx_hash: tx_hash.as_ref().to_lowercase(),
+            confirmations,
+            timeout,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+/// The `eth_blockNumber` action, see [`Proxy::block_number`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EthBlockNumber;
+
+impl Endpoint for EthBlockNumber {
+    type Response = HexNumber;
+
+    fn module(&self) -> Module {
+        Module::Proxy
+    }
+
+    fn action(&self) -> Action {
+        Action::EthBlockNumber
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(Vec::new())
+    }
+}
+
+/// The `eth_getBlockByNumber` action, see [`Proxy::block_by_number`]
+#[derive(Debug, Clone, Copy)]
+pub struct EthGetBlockByNumber {
+    block_number: u64,
+}
+
+impl EthGetBlockByNumber {
+    /// Request a block header, with transaction hashes only
+    pub fn new(block_number: u64) -> Self {
+        Self { block_number }
+    }
+}
+
+impl Endpoint for EthGetBlockByNumber {
+    type Response = Option<Block>;
+
+    fn module(&self) -> Module {
+        Module::Proxy
+    }
+
+    fn action(&self) -> Action {
+        Action::EthGetBlockByNumber
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![
+            (
+                "tag".to_string(),
+                HexNumber::from(self.block_number).to_string(),
+            ),
+            ("boolean".to_string(), "false".to_string()),
+        ])
+    }
+}
+
+/// The `eth_getTransactionByHash` action, see [`Proxy::transaction_by_hash`]
+#[derive(Debug, Clone)]
+pub struct EthGetTransactionByHash {
+    tx_hash: String,
+}
+
+impl EthGetTransactionByHash {
+    /// Request a transaction by hash
+    pub fn new<S: AsRef<str>>(tx_hash: S) -> Self {
+        Self {
+            tx_hash: tx_hash.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for EthGetTransactionByHash {
+    type Response = Option<RpcTransaction>;
+
+    fn module(&self) -> Module {
+        Module::Proxy
+    }
+
+    fn action(&self) -> Action {
+        Action::EthGetTransactionByHash
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![("txhash".to_string(), self.tx_hash.clone())])
+    }
+}
+
+/// The `eth_getTransactionReceipt` action, see [`Proxy::transaction_receipt`]
+#[derive(Debug, Clone)]
+pub struct EthGetTransactionReceipt {
+    tx_hash: String,
+}
+
+impl EthGetTransactionReceipt {
+    /// Request the receipt of a transaction by hash
+    pub fn new<S: AsRef<str>>(tx_hash: S) -> Self {
+        Self {
+            tx_hash: tx_hash.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for EthGetTransactionReceipt {
+    type Response = Option<TransactionReceipt>;
+
+    fn module(&self) -> Module {
+        Module::Proxy
+    }
+
+    fn action(&self) -> Action {
+        Action::EthGetTransactionReceipt
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![("txhash".to_string(), self.tx_hash.clone())])
+    }
+}
+
+/// The `eth_getTransactionCount` action, see [`Proxy::transaction_count`]
+#[derive(Debug, Clone)]
+pub struct EthGetTransactionCount {
+    address: String,
+}
+
+impl EthGetTransactionCount {
+    /// Request the number of transactions mined from an address
+    pub fn new<S: AsRef<str>>(address: S) -> Self {
+        Self {
+            address: address.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for EthGetTransactionCount {
+    type Response = HexNumber;
+
+    fn module(&self) -> Module {
+        Module::Proxy
+    }
+
+    fn action(&self) -> Action {
+        Action::EthGetTransactionCount
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![
+            ("address".to_string(), normalize_address(&self.address)?),
+            ("tag".to_string(), "latest".to_string()),
+        ])
+    }
+}
+
+/// Chain head follower created by [`Proxy::watch_blocks`]
+#[derive(Debug)]
+pub struct BlockWatcher {
+    client: EtherscanClient,
+    poll_interval: Duration,
+    start_block: Option<u64>,
+}
+
+impl BlockWatcher {
+    fn new(client: &EtherscanClient) -> Self {
+        Self {
+            client: client.clone(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            start_block: None,
+        }
+    }
+
+    /// Set the time between two `eth_blockNumber` polls (default: 12 seconds)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Yield blocks from this one on instead of only blocks after the current head
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.start_block = Some(block);
+        self
+    }
+
+    // Getter methods for testing
+
+    /// Time between two polls
+    pub fn get_poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// First block to yield, if set
+    pub fn get_start_block(&self) -> Option<u64> {
+        self.start_block
+    }
+
+    /// Start following the head
+    ///
+    /// Every block is yielded once and in order, even when several were mined
+    /// between two polls. The stream never ends; a failed request yields an
+    /// error and is retried on the next poll.
+    pub fn stream(self) -> impl Stream<Item = Result<Block>> {
+        struct State {
+            watcher: BlockWatcher,
+            next: Option<u64>,
+            head: u64,
+            polled: bool,
+        }
+
+        let state = State {
+            watcher: self,
+            next: None,
+            head: 0,
+            polled: false,
+        };
+
+        futures::stream::unfold(state, |mut state| async move {
+            let client = state.watcher.client.clone();
+            let proxy = client.proxy();
+            loop {
+                if let Some(next) = state.next.filter(|&next| next <= state.head) {
+                    match proxy.block_by_number(next).await {
+                        Ok(Some(block)) => {
+                            state.next = Some(next + 1);
+                            return Some((Ok(block), state));
+                        }
+                        // The node behind the API has not seen it yet
+                        Ok(None) => state.head = next.saturating_sub(1),
+                        Err(e) => return Some((Err(e), state)),
+                    }
+                }
+
+                if state.polled {
+                    tokio::time::sleep(state.watcher.poll_interval).await;
+                }
+                state.polled = true;
+
+                match proxy.block_number().await {
+                    Ok(head) => {
+                        state.head = head;
+                        let start = state.watcher.start_block.unwrap_or(head + 1);
+                        state.next.get_or_insert(start);
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        })
+    }
+}
+
+/// Confirmation waiter created by [`Proxy::wait_for_confirmations`]
+#[derive(Debug)]
+pub struct ConfirmationWaitBuilder {
+    client: EtherscanClient,
+    tx_hash: String,
+    confirmations: u64,
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl ConfirmationWaitBuilder {
+    /// Rebuild the wait from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        tx_hash: String,
+        confirmations: u64,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            tx_hash,
+            confirmations,
+            timeout,
+            poll_interval,
+        }
+    }
+
+    /// Set the time between two checks (default: 12 seconds)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Serializable form of the wait
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::WaitForConfirmations {
+            tx_hash: self.tx_hash.clone(),
+            confirmations: self.confirmations,
+            timeout: self.timeout,
+            poll_interval: self.poll_interval,
+        }
+    }
+
+    // Getter methods for testing
+
+    /// Transaction hash waited for
+    pub fn get_tx_hash(&self) -> &str {
+        &self.tx_hash
+    }
+
+    /// Required confirmations
+    pub fn get_confirmations(&self) -> u64 {
+        self.confirmations
+    }
+
+    /// Maximum time to wait
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Wait for the transaction
+    ///
+    /// A transaction that disappears before being mined is reported as
+    /// [`Replaced`](ConfirmationOutcome::Replaced) if its sender has used the
+    /// nonce since, and as [`Dropped`](ConfirmationOutcome::Dropped) otherwise.
+    /// A mined transaction that is reorged out is waited for again.
+    pub async fn execute(&self) -> Result<ConfirmationOutcome> {
+        tokio::time::timeout(self.timeout, self.wait())
+            .await
+            .map_err(|_| {
+                EtherscanError::Timeout(format!(
+                    "Transaction {} did not reach {} confirmations within {:?}",
+                    self.tx_hash, self.confirmations, self.timeout
+                ))
+            })?
+    }
+
+    async fn wait(&self) -> Result<ConfirmationOutcome> {
+        let proxy = self.client.proxy();
+        let mut sender: Option<(Address, u64)> = None;
+
+        loop {
+            if let Some(receipt) = proxy.transaction_receipt(&self.tx_hash).await? {
+                let head = proxy.block_number().await?;
+                let confirmations = (head + 1).saturating_sub(receipt.block());
+                if confirmations >= self.confirmations {
+                    return Ok(ConfirmationOutcome::Confirmed {
+                        receipt: Box::new(receipt),
+                        confirmations,
+                    });
+                }
+            } else {
+                match proxy.transaction_by_hash(&self.tx_hash).await? {
+                    Some(transaction) => {
+                        sender = Some((transaction.from, transaction.nonce.value()));
+                    }
+                    // Unknown hashes may not have propagated yet, so only a
+                    // transaction that was seen before counts as gone
+                    None => {
+                        if let Some((from, nonce)) = &sender {
+                            let next_nonce = proxy.transaction_count(from.as_str()).await?;
+                            return Ok(if next_nonce > *nonce {
+                                ConfirmationOutcome::Replaced { nonce: *nonce }
+                            } else {
+                                ConfirmationOutcome::Dropped
+                            });
+                        }
+                    }
+                }
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::EtherscanClient;
+use crate::{
+    endpoints::{Action, Endpoint, Module},
+    models::GasOracle,
+    EtherscanClient, Result,
+};
 
 /// Stats-related API endpoints
 #[derive(Debug)]
@@ -11,5 +15,41 @@ impl<'a> Stats<'a> {
         Self { client }
     }
 
-    // TODO: Implement stats endpoints
+    /// Get safe, standard and fast gas price suggestions
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let oracle = client.stats().gas_oracle().await?;
+    ///     println!("Standard: {} gwei", oracle.propose_gas_price);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn gas_oracle(&self) -> Result<GasOracle> {
+        self.client.call(&GetGasOracle).await
+    }
+}
+
+/// The `gasoracle` action, see [`Stats::gas_oracle`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GetGasOracle;
+
+impl Endpoint for GetGasOracle {
+    type Response = GasOracle;
+
+    fn module(&self) -> Module {
+        Module::GasTracker
+    }
+
+    fn action(&self) -> Action {
+        Action::GasOracle
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(Vec::new())
+    }
 }
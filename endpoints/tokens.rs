@@ -1,4 +1,10 @@
-use crate::EtherscanClient;
+use crate::{
+    endpoints::accounts::TokenTransferQueryBuilder,
+    models::{HolderSnapshot, DEAD_ADDRESS, ZERO_ADDRESS},
+    spec::QuerySpec,
+    types::TransactionType,
+    EtherscanClient, EtherscanError, Result,
+};
 
 /// Token-related API endpoints
 #[derive(Debug)]
@@ -11,5 +17,159 @@ impl<'a> Tokens<'a> {
         Self { client }
     }
 
-    // TODO: Implement token endpoints
+    /// Get the ERC-20 transfers of a token across all addresses
+    pub fn transfers<S: AsRef<str>>(&self, contract_address: S) -> TokenTransferQueryBuilder {
+        TokenTransferQueryBuilder::for_contract(
+            self.client,
+            contract_address.as_ref(),
+            TransactionType::Token,
+        )
+    }
+
+    /// Get every holder of an ERC-20 token and their balance at a block
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let snapshot = client
+    ///         .tokens()
+    ///         .holder_snapshot("0x1f9840a85d5af5bf1d1762f925bdaddc4201f984", 18_000_000)
+    ///         .exclude("0x1a9c8182c09f50c8318d769245bea52c32be35bc")
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     std::fs::write("airdrop.csv", snapshot.to_csv()).unwrap();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn holder_snapshot<S: AsRef<str>>(
+        &self,
+        contract_address: S,
+        block_number: u64,
+    ) -> HolderSnapshotBuilder {
+        HolderSnapshotBuilder::new(self.client, contract_address.as_ref(), block_number)
+    }
+}
+
+/// Builder for a token holder snapshot
+#[derive(Debug)]
+pub struct HolderSnapshotBuilder {
+    client: EtherscanClient,
+    contract_address: String,
+    block_number: u64,
+    excluded: Vec<String>,
+    page_size: Option<u32>,
+}
+
+impl HolderSnapshotBuilder {
+    fn new(client: &EtherscanClient, contract_address: &str, block_number: u64) -> Self {
+        Self {
+            client: client.clone(),
+            contract_address: contract_address.to_string(),
+            block_number,
+            excluded: vec![ZERO_ADDRESS.to_string(), DEAD_ADDRESS.to_string()],
+            page_size: None,
+        }
+    }
+
+    /// Rebuild the query from the fields of a [`QuerySpec`]
+    pub(crate) fn from_parts(
+        client: &EtherscanClient,
+        contract_address: String,
+        block_number: u64,
+        excluded: Vec<String>,
+        page_size: Option<u32>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            contract_address,
+            block_number,
+            excluded,
+            page_size,
+        }
+    }
+
+    /// Leave an address out of the snapshot, e.g. a treasury or the token itself
+    pub fn exclude<S: AsRef<str>>(mut self, address: S) -> Self {
+        self.excluded.push(address.as_ref().to_lowercase());
+        self
+    }
+
+    /// Leave several addresses out of the snapshot
+    pub fn exclude_all<S: AsRef<str>>(mut self, addresses: &[S]) -> Self {
+        self.excluded
+            .extend(addresses.iter().map(|a| a.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Keep the zero and burn addresses, which are excluded by default
+    pub fn include_zero_and_burn(mut self) -> Self {
+        self.excluded
+            .retain(|address| address != ZERO_ADDRESS && address != DEAD_ADDRESS);
+        self
+    }
+
+    /// Set the number of transfers fetched per request (max 10 000)
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Serializable form of the query
+    pub fn spec(&self) -> QuerySpec {
+        QuerySpec::HolderSnapshot {
+            contract_address: self.contract_address.clone(),
+            block_number: self.block_number,
+            excluded: self.excluded.clone(),
+            page_size: self.page_size,
+        }
+    }
+
+    // Getter methods for testing
+
+    /// Token contract address
+    pub fn get_contract_address(&self) -> &str {
+        &self.contract_address
+    }
+
+    /// Snapshot block
+    pub fn get_block_number(&self) -> u64 {
+        self.block_number
+    }
+
+    /// Addresses left out of the snapshot
+    pub fn get_excluded(&self) -> &[String] {
+        &self.excluded
+    }
+
+    /// Execute the snapshot
+    ///
+    /// Fetches every transfer of the token up to the block, following
+    /// pagination automatically, and replays them into holder balances.
+    pub async fn execute(self) -> Result<HolderSnapshot> {
+        if self.page_size == Some(0) {
+            return Err(EtherscanError::InvalidParams(
+                "Page size must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut query = Tokens::new(&self.client)
+            .transfers(&self.contract_address)
+            .end_block(self.block_number);
+        if let Some(page_size) = self.page_size {
+            query = query.offset(page_size);
+        }
+        let transfers = query.execute_all().await?;
+
+        HolderSnapshot::new(
+            &self.contract_address,
+            self.block_number,
+            &transfers,
+            &self.excluded,
+        )
+    }
 }
@@ -1,4 +1,14 @@
-use crate::EtherscanClient;
+use crate::{
+    endpoints::{Action, Endpoint, Module},
+    models::TransactionStatus,
+    EtherscanClient, Result,
+};
+#[cfg(all(feature = "accounts", feature = "proxy"))]
+use crate::{
+    error::validation::validate_tx_hash,
+    models::{Timestamp, TransactionDetails},
+    EtherscanError,
+};
 
 /// Transaction-related API endpoints
 #[derive(Debug)]
@@ -11,5 +21,103 @@ impl<'a> Transactions<'a> {
         Self { client }
     }
 
-    // TODO: Implement transaction endpoints
+    /// Get the contract execution status of a transaction
+    pub async fn status<S: AsRef<str>>(&self, tx_hash: S) -> Result<TransactionStatus> {
+        self.client.call(&GetStatus::new(tx_hash)).await
+    }
+
+    /// Get everything about a transaction in one call
+    ///
+    /// Fetches the transaction, its receipt and its internal transactions
+    /// concurrently, then the block timestamp and, for reverted transactions,
+    /// the revert reason. Token transfers are decoded from the receipt logs,
+    /// and the effective gas price and total fee are computed exactly in wei.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use keion_etherscan::EtherscanClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> keion_etherscan::Result<()> {
+    ///     let client = EtherscanClient::new("YOUR_API_KEY")?;
+    ///     let details = client
+    ///         .transactions()
+    ///         .details("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060")
+    ///         .await?;
+    ///     println!("{:?} paid {:?} wei", details.status, details.fee);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(all(feature = "accounts", feature = "proxy"))]
+    pub async fn details<S: AsRef<str>>(&self, tx_hash: S) -> Result<TransactionDetails> {
+        let tx_hash = tx_hash.as_ref().to_lowercase();
+        validate_tx_hash(&tx_hash)?;
+
+        let proxy = self.client.proxy();
+        let (transaction, receipt, internal_transactions) = futures::try_join!(
+            proxy.transaction_by_hash(&tx_hash),
+            proxy.transaction_receipt(&tx_hash),
+            self.client
+                .accounts()
+                .internal_transactions()
+                .by_hash(&tx_hash)
+                .execute(),
+        )?;
+        let transaction = transaction.ok_or_else(|| {
+            EtherscanError::api_with_result("Transaction not found", tx_hash.clone())
+        })?;
+
+        let timestamp = match transaction.block() {
+            Some(block) => proxy
+                .block_by_number(block)
+                .await?
+                .map(|block| Timestamp::from(block.timestamp_value())),
+            None => None,
+        };
+        let revert_reason = match &receipt {
+            Some(receipt) if !receipt.is_successful() => {
+                self.status(&tx_hash).await?.reason().map(str::to_string)
+            }
+            _ => None,
+        };
+
+        Ok(TransactionDetails::new(
+            transaction,
+            receipt,
+            internal_transactions,
+            timestamp,
+            revert_reason,
+        ))
+    }
+}
+
+/// The `getstatus` action, see [`Transactions::status`]
+#[derive(Debug, Clone)]
+pub struct GetStatus {
+    tx_hash: String,
+}
+
+impl GetStatus {
+    /// Request the execution status of a transaction
+    pub fn new<S: AsRef<str>>(tx_hash: S) -> Self {
+        Self {
+            tx_hash: tx_hash.as_ref().to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetStatus {
+    type Response = TransactionStatus;
+
+    fn module(&self) -> Module {
+        Module::Transaction
+    }
+
+    fn action(&self) -> Action {
+        Action::GetStatus
+    }
+
+    fn params(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![("txhash".to_string(), self.tx_hash.clone())])
+    }
 }
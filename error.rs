@@ -1,8 +1,63 @@
+use crate::Network;
 use std::fmt;
+use std::sync::Arc;
 
 /// Result type alias for keion-etherscan operations
 pub type Result<T> = std::result::Result<T, EtherscanError>;
 
+/// Underlying error of an [`EtherscanError`], returned by `source()`
+pub type ErrorSource = Arc<dyn std::error::Error + Send + Sync>;
+
+/// Longest response body kept in a [`RequestContext`], in bytes
+pub const MAX_ERROR_BODY_LEN: usize = 2048;
+
+/// Replaces the API key wherever it would appear in an error
+pub const REDACTED: &str = "REDACTED";
+
+/// The request an error happened in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Network the request was sent to
+    pub network: Network,
+    /// API module
+    pub module: String,
+    /// API action
+    pub action: String,
+    /// Request URL with the API key replaced by [`REDACTED`]
+    pub url: String,
+    /// HTTP status, when a response was received
+    pub status: Option<u16>,
+    /// Response body, truncated to [`MAX_ERROR_BODY_LEN`] bytes
+    pub body: Option<String>,
+}
+
+impl RequestContext {
+    /// Attach a response, truncating its body
+    pub(crate) fn with_response(mut self, status: u16, body: &str) -> Self {
+        self.status = Some(status);
+        self.body = Some(truncate(body, MAX_ERROR_BODY_LEN));
+        self
+    }
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} on {}", self.module, self.action, self.network)
+    }
+}
+
+/// Cut `text` to at most `max` bytes on a character boundary
+fn truncate(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &text[..end])
+}
+
 /// Comprehensive error types for the Etherscan client
 #[derive(Debug, Clone)]
 pub enum EtherscanError {
@@ -16,14 +71,23 @@ pub enum EtherscanError {
     HttpClient(String),
 
     /// Network request failed
-    Request(String),
+    Request {
+        /// Error message
+        message: String,
+        /// Request that failed
+        context: Option<Box<RequestContext>>,
+        /// Underlying transport error
+        source: Option<ErrorSource>,
+    },
 
     /// HTTP error response
     Http {
         /// HTTP status code
         status: u16,
-        /// Error message
+        /// Error message, the truncated response body
         message: String,
+        /// Request that failed
+        context: Option<Box<RequestContext>>,
     },
 
     /// API returned an error status
@@ -32,13 +96,30 @@ pub enum EtherscanError {
         message: String,
         /// Optional result details
         result: Option<String>,
+        /// Request that failed
+        context: Option<Box<RequestContext>>,
+    },
+
+    /// The endpoint is not available on the API key's plan
+    PlanRestricted {
+        /// Action that was rejected
+        action: String,
+        /// Message from the API
+        message: String,
     },
 
     /// Failed to parse response
     Response(String),
 
     /// JSON parsing error
-    Parse(String),
+    Parse {
+        /// Error message
+        message: String,
+        /// Request whose response failed to parse
+        context: Option<Box<RequestContext>>,
+        /// Underlying deserialization error
+        source: Option<ErrorSource>,
+    },
 
     /// Invalid address format
     InvalidAddress(String),
@@ -76,11 +157,30 @@ pub enum EtherscanError {
 }
 
 impl EtherscanError {
+    /// Create a new request error
+    pub fn request<S: Into<String>>(message: S) -> Self {
+        EtherscanError::Request {
+            message: message.into(),
+            context: None,
+            source: None,
+        }
+    }
+
+    /// Create a new HTTP error
+    pub fn http<S: Into<String>>(status: u16, message: S) -> Self {
+        EtherscanError::Http {
+            status,
+            message: message.into(),
+            context: None,
+        }
+    }
+
     /// Create a new API error
     pub fn api<S: Into<String>>(message: S) -> Self {
         EtherscanError::Api {
             message: message.into(),
             result: None,
+            context: None,
         }
     }
 
@@ -89,6 +189,16 @@ impl EtherscanError {
         EtherscanError::Api {
             message: message.into(),
             result: Some(result.into()),
+            context: None,
+        }
+    }
+
+    /// Create a new parse error
+    pub fn parse<S: Into<String>>(message: S) -> Self {
+        EtherscanError::Parse {
+            message: message.into(),
+            context: None,
+            source: None,
         }
     }
 
@@ -100,6 +210,14 @@ impl EtherscanError {
         }
     }
 
+    /// Create a new plan restriction error
+    pub fn plan_restricted<S: Into<String>, M: Into<String>>(action: S, message: M) -> Self {
+        EtherscanError::PlanRestricted {
+            action: action.into(),
+            message: message.into(),
+        }
+    }
+
     /// Create a new unsupported network error
     pub fn unsupported_network<S: Into<String>>(network: S, feature: S) -> Self {
         EtherscanError::UnsupportedNetwork {
@@ -108,10 +226,107 @@ impl EtherscanError {
         }
     }
 
+    /// Request the error happened in, when it came from a request
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            EtherscanError::Request { context, .. }
+            | EtherscanError::Http { context, .. }
+            | EtherscanError::Api { context, .. }
+            | EtherscanError::Parse { context, .. } => context.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Attach the request to an error that does not have one yet
+    pub(crate) fn with_context(mut self, request: RequestContext) -> Self {
+        if let EtherscanError::Request { context, .. }
+        | EtherscanError::Http { context, .. }
+        | EtherscanError::Api { context, .. }
+        | EtherscanError::Parse { context, .. } = &mut self
+        {
+            context.get_or_insert_with(|| Box::new(request));
+        }
+        self
+    }
+
+    /// Replace every occurrence of `secret` in the error's text
+    pub(crate) fn redact(self, secret: &str) -> Self {
+        if secret.is_empty() {
+            return self;
+        }
+        let clean = |text: String| {
+            if text.contains(secret) {
+                text.replace(secret, REDACTED)
+            } else {
+                text
+            }
+        };
+        let clean_context = |context: Option<Box<RequestContext>>| {
+            context.map(|mut context| {
+                context.url = clean(context.url);
+                context.body = context.body.map(clean);
+                context
+            })
+        };
+
+        match self {
+            EtherscanError::Request {
+                message,
+                context,
+                source,
+            } => EtherscanError::Request {
+                message: clean(message),
+                context: clean_context(context),
+                source,
+            },
+            EtherscanError::Http {
+                status,
+                message,
+                context,
+            } => EtherscanError::Http {
+                status,
+                message: clean(message),
+                context: clean_context(context),
+            },
+            EtherscanError::Api {
+                message,
+                result,
+                context,
+            } => EtherscanError::Api {
+                message: clean(message),
+                result: result.map(clean),
+                context: clean_context(context),
+            },
+            EtherscanError::Parse {
+                message,
+                context,
+                source,
+            } => EtherscanError::Parse {
+                message: clean(message),
+                context: clean_context(context),
+                source,
+            },
+            EtherscanError::PlanRestricted { action, message } => EtherscanError::PlanRestricted {
+                action,
+                message: clean(message),
+            },
+            EtherscanError::RateLimit {
+                retry_after,
+                message,
+            } => EtherscanError::RateLimit {
+                retry_after,
+                message: clean(message),
+            },
+            EtherscanError::Response(message) => EtherscanError::Response(clean(message)),
+            EtherscanError::Timeout(message) => EtherscanError::Timeout(clean(message)),
+            other => other,
+        }
+    }
+
     /// Check if this error is retryable
     pub fn is_retryable(&self) -> bool {
         match self {
-            EtherscanError::Request(_) => true,
+            EtherscanError::Request { .. } => true,
             EtherscanError::Http { status, .. } => *status >= 500,
             EtherscanError::RateLimit { .. } => true,
             EtherscanError::Timeout(_) => true,
@@ -119,17 +334,27 @@ impl EtherscanError {
         }
     }
 
+    /// Check if the API rejected the API key itself
+    pub fn is_invalid_api_key(&self) -> bool {
+        matches!(
+            self,
+            EtherscanError::Api { result: Some(result), .. }
+                if result.to_lowercase().contains("invalid api key")
+        )
+    }
+
     /// Get the error category for logging/metrics
     pub fn category(&self) -> &'static str {
         match self {
             EtherscanError::MissingApiKey => "configuration",
             EtherscanError::InvalidUrl(_) => "configuration",
             EtherscanError::HttpClient(_) => "configuration",
-            EtherscanError::Request(_) => "network",
+            EtherscanError::Request { .. } => "network",
             EtherscanError::Http { .. } => "http",
             EtherscanError::Api { .. } => "api",
+            EtherscanError::PlanRestricted { .. } => "plan",
             EtherscanError::Response(_) => "parsing",
-            EtherscanError::Parse(_) => "parsing",
+            EtherscanError::Parse { .. } => "parsing",
             EtherscanError::InvalidAddress(_) => "validation",
             EtherscanError::InvalidTxHash(_) => "validation",
             EtherscanError::InvalidBlock(_) => "validation",
@@ -154,21 +379,32 @@ impl fmt::Display for EtherscanError {
             EtherscanError::HttpClient(msg) => {
                 write!(f, "HTTP client error: {}", msg)
             }
-            EtherscanError::Request(msg) => {
-                write!(f, "Request failed: {}", msg)
+            EtherscanError::Request { message, .. } => {
+                write!(f, "Request failed: {}", message)
             }
-            EtherscanError::Http { status, message } => {
+            EtherscanError::Http {
+                status, message, ..
+            } => {
                 write!(f, "HTTP error {}: {}", status, message)
             }
-            EtherscanError::Api { message, result } => match result {
+            EtherscanError::Api {
+                message, result, ..
+            } => match result {
                 Some(result) => write!(f, "API error: {} (result: {})", message, result),
                 None => write!(f, "API error: {}", message),
             },
+            EtherscanError::PlanRestricted { action, message } => {
+                write!(
+                    f,
+                    "Action '{}' is not available on this API plan: {}",
+                    action, message
+                )
+            }
             EtherscanError::Response(msg) => {
                 write!(f, "Response error: {}", msg)
             }
-            EtherscanError::Parse(msg) => {
-                write!(f, "Parse error: {}", msg)
+            EtherscanError::Parse { message, .. } => {
+                write!(f, "Parse error: {}", message)
             }
             EtherscanError::InvalidAddress(addr) => {
                 write!(f, "Invalid Ethereum address: {}", addr)
@@ -206,32 +442,51 @@ impl fmt::Display for EtherscanError {
             EtherscanError::Internal(msg) => {
                 write!(f, "Internal error: {}", msg)
             }
+        }?;
+
+        match self.context() {
+            Some(context) => write!(f, " ({})", context),
+            None => Ok(()),
         }
     }
 }
 
 impl std::error::Error for EtherscanError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            EtherscanError::Request { source, .. } | EtherscanError::Parse { source, .. } => {
+                source.as_deref().map(|source| source as _)
+            }
+            _ => None,
+        }
     }
 }
 
 // Convenient conversion from common error types
+
+/// The URL, which holds the API key, is stripped from the error
 impl From<reqwest::Error> for EtherscanError {
     fn from(err: reqwest::Error) -> Self {
+        let err = err.without_url();
         if err.is_timeout() {
             EtherscanError::Timeout(err.to_string())
-        } else if err.is_request() {
-            EtherscanError::Request(err.to_string())
         } else {
-            EtherscanError::Request(err.to_string())
+            EtherscanError::Request {
+                message: err.to_string(),
+                context: None,
+                source: Some(Arc::new(err)),
+            }
         }
     }
 }
 
 impl From<serde_json::Error> for EtherscanError {
     fn from(err: serde_json::Error) -> Self {
-        EtherscanError::Parse(err.to_string())
+        EtherscanError::Parse {
+            message: err.to_string(),
+            context: None,
+            source: Some(Arc::new(err)),
+        }
     }
 }
 
@@ -241,6 +496,13 @@ impl From<url::ParseError> for EtherscanError {
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for EtherscanError {
+    fn from(err: rusqlite::Error) -> Self {
+        EtherscanError::Internal(format!("SQLite error: {}", err))
+    }
+}
+
 /// Helper functions for validation
 pub mod validation {
     use super::EtherscanError;
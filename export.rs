@@ -0,0 +1,633 @@
+//! Export query results to CSV, newline-delimited JSON and Parquet
+//!
+//! Models implementing [`ExportRecord`] describe themselves as a flat row of
+//! typed columns. Amounts are exported twice: in base units (wei, gwei or raw
+//! token units, as exact integers) and as an exact decimal amount of ETH or
+//! tokens, so spreadsheets never lose precision.
+//!
+//! Writers implement [`RecordWriter`] and accept records one at a time, so
+//! they work the same on a collected `Vec` ([`RecordWriter::write_all`]) and
+//! on a paginated stream ([`write_stream`]) without holding the whole
+//! history in memory.
+//!
+//! - [`CsvWriter`]: one header row, then one row per record
+//! - [`NdjsonWriter`]: the serde representation of each record, one per line
+//! - `ParquetWriter` (`parquet` feature): typed Arrow columns, written in row
+//!   groups
+//!
+//! ```rust,no_run
+//! use keion_etherscan::export::{write_stream, CsvWriter};
+//! use keion_etherscan::EtherscanClient;
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let file = std::fs::File::create("transfers.csv")
+//!         .map_err(|e| keion_etherscan::EtherscanError::Internal(e.to_string()))?;
+//!
+//!     let mut writer = CsvWriter::new(file);
+//!     let transfers = client
+//!         .accounts()
+//!         .token_transfers("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!         .stream();
+//!     let rows = write_stream(transfers, &mut writer).await?;
+//!     writer.finish()?;
+//!     println!("exported {} transfers", rows);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    models::{
+        format_units, BeaconWithdrawal, BlockchainData, InternalTransaction, Timestamp,
+        TokenTransfer, Transaction, TransactionLog, ValidatedBlock,
+    },
+    EtherscanError, Result,
+};
+use chrono::SecondsFormat;
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::io::Write;
+
+#[cfg(feature = "parquet")]
+pub use parquet_writer::ParquetWriter;
+
+/// Decimals of ETH amounts in wei
+const ETH_DECIMALS: u32 = 18;
+
+/// Decimals of ETH amounts in gwei
+const GWEI_DECIMALS: u32 = 9;
+
+/// Type of an exported column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// UTF-8 text, also used for amounts that do not fit 64 bits
+    Text,
+    /// Unsigned 64-bit integer
+    UInt64,
+    /// Boolean
+    Bool,
+}
+
+/// Name and type of an exported column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    /// Column name
+    pub name: &'static str,
+
+    /// Column type
+    pub column_type: ColumnType,
+}
+
+impl Column {
+    const fn text(name: &'static str) -> Self {
+        Self {
+            name,
+            column_type: ColumnType::Text,
+        }
+    }
+
+    const fn uint(name: &'static str) -> Self {
+        Self {
+            name,
+            column_type: ColumnType::UInt64,
+        }
+    }
+
+    const fn bool(name: &'static str) -> Self {
+        Self {
+            name,
+            column_type: ColumnType::Bool,
+        }
+    }
+}
+
+/// Value of one exported cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    /// Missing value
+    Null,
+    /// Text value
+    Text(String),
+    /// Integer value
+    UInt64(u64),
+    /// Boolean value
+    Bool(bool),
+}
+
+impl Cell {
+    fn text<S: Into<String>>(value: S) -> Self {
+        Cell::Text(value.into())
+    }
+
+    fn optional<S: Into<String>>(value: Option<S>) -> Self {
+        value.map_or(Cell::Null, Cell::text)
+    }
+}
+
+/// A model that can be exported as a flat row
+pub trait ExportRecord: Serialize {
+    /// Columns of every row, in order
+    fn columns() -> &'static [Column];
+
+    /// Cells of this record, one per column
+    fn row(&self) -> Vec<Cell>;
+}
+
+/// Sink accepting records one at a time
+pub trait RecordWriter<T> {
+    /// Write one record
+    fn write_record(&mut self, record: &T) -> Result<()>;
+
+    /// Write every record of a slice
+    fn write_all(&mut self, records: &[T]) -> Result<()> {
+        records
+            .iter()
+            .try_for_each(|record| self.write_record(record))
+    }
+}
+
+/// Write every record of a stream, stopping at the first error
+///
+/// Returns the number of records written.
+pub async fn write_stream<T, S, W>(stream: S, writer: &mut W) -> Result<usize>
+where
+    S: Stream<Item = Result<T>>,
+    W: RecordWriter<T>,
+{
+    let mut stream = std::pin::pin!(stream);
+    let mut written = 0;
+    while let Some(record) = stream.next().await {
+        writer.write_record(&record?)?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// CSV writer with a header row and RFC 4180 quoting
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    header_written: bool,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Write CSV to `writer`
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            header_written: false,
+        }
+    }
+
+    /// Flush and return the underlying writer
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush().map_err(io_error)?;
+        Ok(self.writer)
+    }
+
+    fn write_line<I: IntoIterator<Item = String>>(&mut self, fields: I) -> Result<()> {
+        let line = fields
+            .into_iter()
+            .map(|field| escape_csv(&field))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(self.writer, "{}", line).map_err(io_error)
+    }
+}
+
+impl<W: Write, T: ExportRecord> RecordWriter<T> for CsvWriter<W> {
+    fn write_record(&mut self, record: &T) -> Result<()> {
+        if !self.header_written {
+            self.write_line(T::columns().iter().map(|column| column.name.to_string()))?;
+            self.header_written = true;
+        }
+
+        self.write_line(record.row().into_iter().map(|cell| match cell {
+            Cell::Null => String::new(),
+            Cell::Text(text) => text,
+            Cell::UInt64(value) => value.to_string(),
+            Cell::Bool(value) => value.to_string(),
+        }))
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Newline-delimited JSON writer, one serialized record per line
+#[derive(Debug)]
+pub struct NdjsonWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Write NDJSON to `writer`
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Flush and return the underlying writer
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush().map_err(io_error)?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write, T: Serialize> RecordWriter<T> for NdjsonWriter<W> {
+    fn write_record(&mut self, record: &T) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n").map_err(io_error)
+    }
+}
+
+fn io_error(error: std::io::Error) -> EtherscanError {
+    EtherscanError::Internal(format!("Failed to write export: {}", error))
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_writer {
+    use super::{Cell, ColumnType, ExportRecord, RecordWriter};
+    use crate::{EtherscanError, Result};
+    use arrow_array::builder::{BooleanBuilder, StringBuilder, UInt64Builder};
+    use arrow_array::{ArrayRef, RecordBatch};
+    use arrow_schema::{DataType, Field, Schema, SchemaRef};
+    use parquet::arrow::ArrowWriter;
+    use std::io::Write;
+    use std::marker::PhantomData;
+    use std::sync::Arc;
+
+    /// Rows buffered before a row group is written
+    const DEFAULT_BATCH_SIZE: usize = 8_192;
+
+    /// Parquet writer with one typed column per [`Column`](super::Column)
+    pub struct ParquetWriter<W: Write + Send, T> {
+        writer: ArrowWriter<W>,
+        schema: SchemaRef,
+        rows: Vec<Vec<Cell>>,
+        batch_size: usize,
+        record: PhantomData<fn(&T)>,
+    }
+
+    impl<W: Write + Send, T> std::fmt::Debug for ParquetWriter<W, T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ParquetWriter")
+                .field("schema", &self.schema)
+                .field("buffered_rows", &self.rows.len())
+                .finish()
+        }
+    }
+
+    impl<W: Write + Send, T: ExportRecord> ParquetWriter<W, T> {
+        /// Write Parquet to `writer`
+        pub fn new(writer: W) -> Result<Self> {
+            let fields: Vec<Field> = T::columns()
+                .iter()
+                .map(|column| {
+                    let data_type = match column.column_type {
+                        ColumnType::Text => DataType::Utf8,
+                        ColumnType::UInt64 => DataType::UInt64,
+                        ColumnType::Bool => DataType::Boolean,
+                    };
+                    Field::new(column.name, data_type, true)
+                })
+                .collect();
+            let schema = Arc::new(Schema::new(fields));
+            let writer =
+                ArrowWriter::try_new(writer, Arc::clone(&schema), None).map_err(parquet_error)?;
+
+            Ok(Self {
+                writer,
+                schema,
+                rows: Vec::new(),
+                batch_size: DEFAULT_BATCH_SIZE,
+                record: PhantomData,
+            })
+        }
+
+        /// Set the number of rows per row group (default: 8192)
+        pub fn batch_size(mut self, rows: usize) -> Self {
+            self.batch_size = rows.max(1);
+            self
+        }
+
+        /// Write buffered rows, close the file and return the underlying writer
+        pub fn finish(mut self) -> Result<W> {
+            self.flush_rows()?;
+            self.writer.into_inner().map_err(parquet_error)
+        }
+
+        fn flush_rows(&mut self) -> Result<()> {
+            if self.rows.is_empty() {
+                return Ok(());
+            }
+
+            let columns: Vec<ArrayRef> = T::columns()
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    let cells = self.rows.iter().map(|row| &row[index]);
+                    let array: ArrayRef = match column.column_type {
+                        ColumnType::Text => {
+                            let mut builder = StringBuilder::new();
+                            for cell in cells {
+                                match cell {
+                                    Cell::Text(text) => builder.append_value(text),
+                                    _ => builder.append_null(),
+                                }
+                            }
+                            Arc::new(builder.finish())
+                        }
+                        ColumnType::UInt64 => {
+                            let mut builder = UInt64Builder::new();
+                            for cell in cells {
+                                match cell {
+                                    Cell::UInt64(value) => builder.append_value(*value),
+                                    _ => builder.append_null(),
+                                }
+                            }
+                            Arc::new(builder.finish())
+                        }
+                        ColumnType::Bool => {
+                            let mut builder = BooleanBuilder::new();
+                            for cell in cells {
+                                match cell {
+                                    Cell::Bool(value) => builder.append_value(*value),
+                                    _ => builder.append_null(),
+                                }
+                            }
+                            Arc::new(builder.finish())
+                        }
+                    };
+                    array
+                })
+                .collect();
+
+            let batch = RecordBatch::try_new(Arc::clone(&self.schema), columns)
+                .map_err(|e| EtherscanError::Internal(format!("Invalid record batch: {}", e)))?;
+            self.writer.write(&batch).map_err(parquet_error)?;
+            self.writer.flush().map_err(parquet_error)?;
+            self.rows.clear();
+            Ok(())
+        }
+    }
+
+    impl<W: Write + Send, T: ExportRecord> RecordWriter<T> for ParquetWriter<W, T> {
+        fn write_record(&mut self, record: &T) -> Result<()> {
+            self.rows.push(record.row());
+            if self.rows.len() >= self.batch_size {
+                self.flush_rows()?;
+            }
+            Ok(())
+        }
+    }
+
+    fn parquet_error(error: parquet::errors::ParquetError) -> EtherscanError {
+        EtherscanError::Internal(format!("Failed to write Parquet: {}", error))
+    }
+}
+
+/// Exact decimal amount of `units` with `decimals` places, if the amount parses
+fn amount(units: Option<u128>, decimals: u32) -> Cell {
+    match units.and_then(|units| i128::try_from(units).ok()) {
+        Some(units) => Cell::Text(format_units(units, decimals)),
+        None => Cell::Null,
+    }
+}
+
+fn datetime(timestamp: &Timestamp) -> Cell {
+    Cell::Text(
+        timestamp
+            .datetime()
+            .to_rfc3339_opts(SecondsFormat::Secs, true),
+    )
+}
+
+impl ExportRecord for Transaction {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::text("hash"),
+            Column::uint("transaction_index"),
+            Column::text("from"),
+            Column::text("to"),
+            Column::text("value_wei"),
+            Column::text("value_eth"),
+            Column::uint("gas_used"),
+            Column::text("gas_price_wei"),
+            Column::text("fee_wei"),
+            Column::text("fee_eth"),
+            Column::bool("is_error"),
+            Column::text("method_id"),
+            Column::text("function_name"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        let fee = self
+            .gas_price
+            .as_u128()
+            .and_then(|price| price.checked_mul(u128::from(self.gas_used_amount())));
+
+        vec![
+            Cell::UInt64(self.block()),
+            Cell::UInt64(self.timestamp.value()),
+            datetime(&self.timestamp),
+            Cell::text(self.hash.as_str()),
+            Cell::UInt64(self.index()),
+            Cell::text(self.from.as_str()),
+            Cell::optional(self.to.as_ref().map(|to| to.as_str())),
+            Cell::text(self.value.as_str()),
+            amount(self.value.as_u128(), ETH_DECIMALS),
+            Cell::UInt64(self.gas_used_amount()),
+            Cell::text(self.gas_price.as_str()),
+            Cell::optional(fee.map(|fee| fee.to_string())),
+            amount(fee, ETH_DECIMALS),
+            Cell::Bool(self.has_error() || !self.is_successful()),
+            Cell::optional(self.method_id.clone()),
+            Cell::optional(self.function_name.clone()),
+        ]
+    }
+}
+
+impl ExportRecord for InternalTransaction {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::text("hash"),
+            Column::text("trace_id"),
+            Column::text("type"),
+            Column::text("from"),
+            Column::text("to"),
+            Column::text("contract_address"),
+            Column::text("value_wei"),
+            Column::text("value_eth"),
+            Column::bool("is_error"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        vec![
+            Cell::UInt64(self.block()),
+            Cell::UInt64(self.timestamp.value()),
+            datetime(&self.timestamp),
+            Cell::optional(self.hash.as_ref().map(|hash| hash.as_str())),
+            Cell::optional(self.trace_id.as_deref()),
+            Cell::text(self.transaction_type.as_str()),
+            Cell::text(self.from.as_str()),
+            Cell::optional(self.to.as_ref().map(|to| to.as_str())),
+            Cell::optional(self.contract_address.as_ref().map(|a| a.as_str())),
+            Cell::text(self.value.as_str()),
+            amount(self.value.as_u128(), ETH_DECIMALS),
+            Cell::Bool(self.has_error()),
+        ]
+    }
+}
+
+impl ExportRecord for TokenTransfer {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::text("hash"),
+            Column::uint("log_index"),
+            Column::text("contract_address"),
+            Column::text("token_name"),
+            Column::text("token_symbol"),
+            Column::uint("token_decimal"),
+            Column::text("token_id"),
+            Column::text("from"),
+            Column::text("to"),
+            Column::text("value"),
+            Column::text("amount"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        let decimals = u32::try_from(self.decimals()).unwrap_or(u32::MAX);
+
+        vec![
+            Cell::UInt64(self.block()),
+            Cell::UInt64(self.timestamp.value()),
+            datetime(&self.timestamp),
+            Cell::text(self.hash.as_str()),
+            Cell::UInt64(self.log_index.value()),
+            Cell::text(self.contract_address.as_str()),
+            Cell::text(self.token_name.as_str()),
+            Cell::text(self.token_symbol.as_str()),
+            Cell::UInt64(self.decimals()),
+            Cell::optional(self.nft_token_id()),
+            Cell::text(self.from.as_str()),
+            Cell::text(self.to.as_str()),
+            Cell::text(self.value.as_str()),
+            amount(self.value.as_u128(), decimals),
+        ]
+    }
+}
+
+impl ExportRecord for BeaconWithdrawal {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::uint("withdrawal_index"),
+            Column::uint("validator_index"),
+            Column::text("address"),
+            Column::text("amount_gwei"),
+            Column::text("amount_eth"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        vec![
+            Cell::UInt64(self.block()),
+            Cell::UInt64(self.timestamp.value()),
+            datetime(&self.timestamp),
+            Cell::UInt64(self.withdrawal_index.value()),
+            Cell::UInt64(self.validator_index.value()),
+            Cell::text(self.address.as_str()),
+            Cell::text(self.amount.as_str()),
+            amount(self.amount.as_u128(), GWEI_DECIMALS),
+        ]
+    }
+}
+
+impl ExportRecord for ValidatedBlock {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::text("block_reward_wei"),
+            Column::text("block_reward_eth"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        let reward = self.reward_wei();
+
+        vec![
+            Cell::UInt64(self.block()),
+            Cell::UInt64(self.timestamp.value()),
+            datetime(&self.timestamp),
+            Cell::text(reward.to_string()),
+            amount(Some(u128::from(reward)), ETH_DECIMALS),
+        ]
+    }
+}
+
+impl ExportRecord for TransactionLog {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            Column::uint("block_number"),
+            Column::uint("timestamp"),
+            Column::text("datetime"),
+            Column::text("transaction_hash"),
+            Column::uint("log_index"),
+            Column::text("address"),
+            Column::text("topic0"),
+            Column::text("topic1"),
+            Column::text("topic2"),
+            Column::text("topic3"),
+            Column::text("data"),
+        ];
+        COLUMNS
+    }
+
+    fn row(&self) -> Vec<Cell> {
+        let timestamp = BlockchainData::timestamp(self).map(Timestamp::from);
+        let topic = |index: usize| Cell::optional(self.topics.get(index).cloned());
+
+        vec![
+            Cell::UInt64(self.block()),
+            timestamp
+                .as_ref()
+                .map_or(Cell::Null, |t| Cell::UInt64(t.value())),
+            timestamp.as_ref().map_or(Cell::Null, datetime),
+            Cell::text(self.transaction_hash.as_str()),
+            Cell::UInt64(self.log_index.value()),
+            Cell::text(self.address.as_str()),
+            topic(0),
+            topic(1),
+            topic(2),
+            topic(3),
+            Cell::text(self.data.as_str()),
+        ]
+    }
+}
@@ -0,0 +1,487 @@
+//! Resumable incremental indexing of address histories
+//!
+//! An indexer walks a set of [`IndexTarget`]s (an address and a kind of
+//! activity) from their last checkpoint up to the confirmed chain head,
+//! fetching with the regular account builders and handing every completed
+//! block range to a sink. After the sink accepts a batch, the highest
+//! completed block of the target is saved in a [`CheckpointStore`], so a job
+//! that crashes or is stopped resumes exactly after the last stored batch.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{indexer::FileCheckpointStore, ActivityKind, EtherscanClient};
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let summaries = client
+//!         .indexer()
+//!         .targets(
+//!             "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6",
+//!             &[ActivityKind::Transaction, ActivityKind::Erc20Transfer],
+//!         )
+//!         .confirmations(12)
+//!         .checkpoint_store(FileCheckpointStore::new("checkpoints.json"))
+//!         .run(|batch| {
+//!             println!("{}: {} events", batch.target, batch.events.len());
+//!             Ok(())
+//!         })
+//!         .await?;
+//!
+//!     for summary in summaries {
+//!         println!("{} indexed up to {}", summary.target, summary.end_block);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    endpoints::{
+        accounts::{ActivityQueryBuilder, MAX_RESULT_WINDOW},
+        paging::split_page,
+    },
+    error::validation::normalize_address,
+    models::{ActivityEvent, ActivityKind},
+    utils::write_atomic,
+    EtherscanClient, EtherscanError, Pagination, Result,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteCheckpointStore;
+
+/// An address and the kind of activity indexed for it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct IndexTarget {
+    /// Indexed address, lowercase
+    pub address: String,
+
+    /// Indexed kind of activity
+    pub kind: ActivityKind,
+}
+
+impl IndexTarget {
+    /// Create a target
+    pub fn new<S: AsRef<str>>(address: S, kind: ActivityKind) -> Self {
+        Self {
+            address: address.as_ref().to_lowercase(),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for IndexTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.address, self.kind.as_str())
+    }
+}
+
+/// Events of one target over a completed block range
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexBatch {
+    /// Target the events belong to
+    pub target: IndexTarget,
+
+    /// First block of the range
+    pub start_block: u64,
+
+    /// Last block of the range; every event up to it is included
+    pub end_block: u64,
+
+    /// Events in ascending block order
+    pub events: Vec<ActivityEvent>,
+}
+
+/// Outcome of one run for a target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexSummary {
+    /// Indexed target
+    pub target: IndexTarget,
+
+    /// Block the run resumed from
+    pub start_block: u64,
+
+    /// Highest completed block after the run
+    pub end_block: u64,
+
+    /// Number of events handed to the sink
+    pub events: usize,
+}
+
+impl IndexSummary {
+    /// Check if the target was already up to date
+    pub fn is_up_to_date(&self) -> bool {
+        self.start_block > self.end_block
+    }
+}
+
+/// Persistence for the highest completed block of each target
+pub trait CheckpointStore: Send + Sync + fmt::Debug {
+    /// Load the checkpoint of a key
+    fn load(&self, key: &str) -> Result<Option<u64>>;
+
+    /// Save the checkpoint of a key, replacing any previous one
+    fn save(&self, key: &str, block: u64) -> Result<()>;
+}
+
+/// Checkpoint store kept in memory, shared by its clones
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+impl MemoryCheckpointStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self, key: &str) -> Result<Option<u64>> {
+        Ok(self
+            .checkpoints
+            .lock()
+            .expect("checkpoint store lock poisoned")
+            .get(key)
+            .copied())
+    }
+
+    fn save(&self, key: &str, block: u64) -> Result<()> {
+        self.checkpoints
+            .lock()
+            .expect("checkpoint store lock poisoned")
+            .insert(key.to_string(), block);
+        Ok(())
+    }
+}
+
+/// Checkpoint store keeping every checkpoint in one JSON file
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileCheckpointStore {
+    /// Store checkpoints in the file at `path`, created on first save
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, u64>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| {
+                EtherscanError::parse(format!(
+                    "Invalid checkpoints {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(EtherscanError::Internal(format!(
+                "Failed to read checkpoints {}: {}",
+                self.path.display(),
+                e
+            ))),
+        }
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, key: &str) -> Result<Option<u64>> {
+        let _guard = self.lock.lock().expect("checkpoint store lock poisoned");
+        Ok(self.read()?.get(key).copied())
+    }
+
+    fn save(&self, key: &str, block: u64) -> Result<()> {
+        let _guard = self.lock.lock().expect("checkpoint store lock poisoned");
+        let mut checkpoints = self.read()?;
+        checkpoints.insert(key.to_string(), block);
+        let text = serde_json::to_string_pretty(&checkpoints)?;
+
+        write_atomic(&self.path, text).map_err(|e| {
+            EtherscanError::Internal(format!(
+                "Failed to write checkpoints {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::CheckpointStore;
+    use crate::Result;
+    use rusqlite::{params, Connection, OptionalExtension};
+    use std::path::Path;
+    use std::sync::Mutex;
+
+    /// Checkpoint store in an SQLite database (`checkpoints` table)
+    #[derive(Debug)]
+    pub struct SqliteCheckpointStore {
+        connection: Mutex<Connection>,
+    }
+
+    impl SqliteCheckpointStore {
+        /// Open or create the database at `path`
+        pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+            Self::with_connection(Connection::open(path)?)
+        }
+
+        /// Create a store in a private in-memory database
+        pub fn in_memory() -> Result<Self> {
+            Self::with_connection(Connection::open_in_memory()?)
+        }
+
+        /// Use an existing connection, creating the table if needed
+        pub fn with_connection(connection: Connection) -> Result<Self> {
+            connection.execute(
+                "CREATE TABLE IF NOT EXISTS checkpoints (
+                    key TEXT PRIMARY KEY,
+                    block INTEGER NOT NULL
+                )",
+                [],
+            )?;
+
+            Ok(Self {
+                connection: Mutex::new(connection),
+            })
+        }
+    }
+
+    impl CheckpointStore for SqliteCheckpointStore {
+        fn load(&self, key: &str) -> Result<Option<u64>> {
+            let connection = self
+                .connection
+                .lock()
+                .expect("checkpoint store lock poisoned");
+            let block: Option<i64> = connection
+                .query_row(
+                    "SELECT block FROM checkpoints WHERE key = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            Ok(block.map(|block| block as u64))
+        }
+
+        fn save(&self, key: &str, block: u64) -> Result<()> {
+            let connection = self
+                .connection
+                .lock()
+                .expect("checkpoint store lock poisoned");
+            connection.execute(
+                "INSERT INTO checkpoints (key, block) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET block = excluded.block",
+                params![key, block as i64],
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Builder for an indexing run
+#[derive(Debug)]
+pub struct IndexerBuilder {
+    client: EtherscanClient,
+    targets: Vec<IndexTarget>,
+    start_block: u64,
+    end_block: Option<u64>,
+    confirmations: u64,
+    page_size: u32,
+    store: Option<Arc<dyn CheckpointStore>>,
+}
+
+impl IndexerBuilder {
+    pub(crate) fn new(client: &EtherscanClient) -> Self {
+        Self {
+            client: client.clone(),
+            targets: Vec::new(),
+            start_block: 0,
+            end_block: None,
+            confirmations: 0,
+            page_size: MAX_RESULT_WINDOW,
+            store: None,
+        }
+    }
+
+    /// Index one kind of activity of an address
+    pub fn target<S: AsRef<str>>(mut self, address: S, kind: ActivityKind) -> Self {
+        let target = IndexTarget::new(address, kind);
+        if !self.targets.contains(&target) {
+            self.targets.push(target);
+        }
+        self
+    }
+
+    /// Index several kinds of activity of an address
+    pub fn targets<S: AsRef<str>>(self, address: S, kinds: &[ActivityKind]) -> Self {
+        kinds.iter().fold(self, |builder, &kind| {
+            builder.target(address.as_ref(), kind)
+        })
+    }
+
+    /// Start targets without a checkpoint at this block (default: 0)
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.start_block = block;
+        self
+    }
+
+    /// Index up to this block instead of the confirmed head
+    pub fn end_block(mut self, block: u64) -> Self {
+        self.end_block = Some(block);
+        self
+    }
+
+    /// Stay this many blocks behind the head (default: 0)
+    pub fn confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Set the number of results fetched per request (default: 10 000)
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page_size = size.clamp(1, MAX_RESULT_WINDOW);
+        self
+    }
+
+    /// Persist checkpoints in `store`
+    ///
+    /// Without a store, checkpoints only live for the run.
+    pub fn checkpoint_store<S: CheckpointStore + 'static>(mut self, store: S) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    // Getter methods for testing
+
+    /// Indexed targets, in the order they are processed
+    pub fn get_targets(&self) -> &[IndexTarget] {
+        &self.targets
+    }
+
+    /// Number of results fetched per request
+    pub fn get_page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// Key the checkpoint of a target is stored under
+    pub fn checkpoint_key(&self, target: &IndexTarget) -> String {
+        format!("{}-{}", self.client.network(), target)
+    }
+
+    /// Index every target up to the end block, handing each batch to `sink`
+    ///
+    /// A batch is only checkpointed once `sink` returns `Ok`; an error from
+    /// the sink or a request stops the run, and the next run repeats the
+    /// failed batch. Batches without events are checkpointed without calling
+    /// the sink. Fails if a single block holds more events than a page.
+    pub async fn run<F>(self, mut sink: F) -> Result<Vec<IndexSummary>>
+    where
+        F: FnMut(IndexBatch) -> Result<()>,
+    {
+        let end_block = match self.end_block {
+            Some(block) => block,
+            None => {
+                let head = self.client.proxy().block_number().await?;
+                head.saturating_sub(self.confirmations)
+            }
+        };
+        let store: Arc<dyn CheckpointStore> = match &self.store {
+            Some(store) => Arc::clone(store),
+            None => Arc::new(MemoryCheckpointStore::new()),
+        };
+
+        let mut summaries = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            let key = self.checkpoint_key(target);
+            let address = normalize_address(&target.address)?;
+            let start_block = store
+                .load(&key)?
+                .map_or(self.start_block, |block| block + 1);
+
+            let mut summary = IndexSummary {
+                target: target.clone(),
+                start_block,
+                end_block: start_block.saturating_sub(1),
+                events: 0,
+            };
+
+            let mut from = start_block;
+            while from <= end_block {
+                let pagination = Pagination::new()
+                    .block_range(from, end_block)
+                    .offset(self.page_size);
+                let mut events = ActivityQueryBuilder::fetch_page(
+                    &self.client,
+                    target.kind,
+                    &address,
+                    &pagination,
+                )
+                .await?;
+
+                let completed = match split_page(&mut events, self.page_size, ActivityEvent::block)?
+                {
+                    Some(last_block) => last_block - 1,
+                    None => end_block,
+                };
+
+                summary.events += events.len();
+                if !events.is_empty() {
+                    sink(IndexBatch {
+                        target: target.clone(),
+                        start_block: from,
+                        end_block: completed,
+                        events,
+                    })?;
+                }
+                store.save(&key, completed)?;
+                summary.end_block = completed;
+                from = completed + 1;
+            }
+
+            summaries.push(summary);
+        }
+
+        Ok(summaries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(store: &dyn CheckpointStore) {
+        assert_eq!(store.load("mainnet-0xabc:transaction").unwrap(), None);
+        store.save("mainnet-0xabc:transaction", 10).unwrap();
+        store.save("mainnet-0xabc:transaction", 20).unwrap();
+        store.save("mainnet-0xabc:erc20_transfer", 5).unwrap();
+        assert_eq!(store.load("mainnet-0xabc:transaction").unwrap(), Some(20));
+        assert_eq!(store.load("mainnet-0xabc:erc20_transfer").unwrap(), Some(5));
+    }
+
+    #[test]
+    fn test_file_checkpoint_store() {
+        let path = std::env::temp_dir().join(format!("checkpoints-{}.json", std::process::id()));
+        round_trip(&FileCheckpointStore::new(&path));
+
+        // A new store over the same file resumes from it
+        let store = FileCheckpointStore::new(&path);
+        assert_eq!(store.load("mainnet-0xabc:transaction").unwrap(), Some(20));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_checkpoint_store() {
+        round_trip(&SqliteCheckpointStore::in_memory().unwrap());
+    }
+}
@@ -0,0 +1,544 @@
+//! API key pools: key selection, per-key rate limits, cooldowns and daily usage
+//!
+//! A client built with several keys spreads its requests over them:
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{EtherscanClient, KeySelection};
+//! use std::time::Duration;
+//!
+//! # fn main() -> keion_etherscan::Result<()> {
+//! let client = EtherscanClient::builder()
+//!     .api_keys(["team-a-key", "team-b-key", "team-c-key"])
+//!     .key_selection(KeySelection::LeastUsed)
+//!     .key_cooldown(Duration::from_secs(120))
+//!     .key_usage_file("key-usage.json")
+//!     .build()?;
+//!
+//! for usage in client.key_usage() {
+//!     println!("{}: {} calls on {}", usage.key, usage.calls, usage.date);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every key has its own rate limiter, so the configured requests per second
+//! apply to each key. A key answered with a rate-limit or invalid-key error
+//! is skipped until its cooldown ends, unless every key is cooling down, and
+//! a retry moves on to the next key right away.
+//!
+//! Calls are counted per UTC day, the period Etherscan quotas reset on. With a
+//! usage file the counters survive restarts; keys are stored as a preview and
+//! a fingerprint, never in full. New calls are added to the file in the
+//! background at most once per second and when the client is dropped.
+//! Writers lock a `.lock` file next to it while they update it, so several
+//! clients can share one file.
+
+use crate::client::RateLimiter;
+use crate::error::{EtherscanError, Result};
+use crate::utils::write_atomic;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long a key is skipped after a rate-limit or invalid-key error by default
+pub const DEFAULT_KEY_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// How long counted calls wait before being added to the usage file
+const USAGE_WRITE_DELAY: Duration = Duration::from_secs(1);
+
+/// How the client picks the key for each request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeySelection {
+    /// Use the keys in turn
+    #[default]
+    RoundRobin,
+    /// Use the key with the fewest calls today
+    LeastUsed,
+}
+
+/// Calls made with one key on one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// Key preview, such as `ABCD...WXYZ`
+    pub key: String,
+    /// UTC day the calls were made on
+    pub date: NaiveDate,
+    /// Requests sent with the key
+    pub calls: u64,
+    /// Whether the key is skipped after a rate-limit or invalid-key error
+    pub cooling_down: bool,
+}
+
+/// API key that only shows a preview when debug-printed
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct ApiKey(pub(crate) String);
+
+impl ApiKey {
+    pub(crate) fn preview(&self) -> String {
+        let key = &self.0;
+        if key.len() > 8 {
+            format!("{}...{}", &key[..4], &key[key.len() - 4..])
+        } else {
+            "****".to_string()
+        }
+    }
+
+    /// Identifies the key in logs and traces without revealing any of it
+    pub(crate) fn fingerprint(&self) -> String {
+        format!("{:016x}", fnv1a(self.0.as_bytes()))
+    }
+
+    /// Identifies the key in the usage file
+    fn usage_key(&self) -> String {
+        format!("{}:{}", self.preview(), self.fingerprint())
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.preview())
+    }
+}
+
+/// 64-bit FNV-1a, stable across Rust releases unlike the std hashers
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Contents of the usage file
+#[derive(Debug, Serialize, Deserialize)]
+struct UsageFile {
+    date: NaiveDate,
+    calls: BTreeMap<String, u64>,
+}
+
+#[derive(Debug)]
+struct PooledKey {
+    key: ApiKey,
+    rate_limiter: Option<RateLimiter>,
+}
+
+#[derive(Debug, Default)]
+struct KeyState {
+    calls: u64,
+    /// Calls not yet added to the usage file
+    unsaved: u64,
+    cooldown_until: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    date: NaiveDate,
+    cursor: usize,
+    keys: Vec<KeyState>,
+    /// Calls of earlier days not yet added to the usage file
+    earlier: Vec<(NaiveDate, Vec<u64>)>,
+}
+
+impl PoolState {
+    /// Reset the counters when the UTC day changes
+    ///
+    /// Unsaved calls of the day that ended are kept for the next write.
+    /// Returns whether there are any.
+    fn roll_over(&mut self) -> bool {
+        let today = Utc::now().date_naive();
+        if self.date == today {
+            return false;
+        }
+
+        let unsaved: Vec<u64> = self
+            .keys
+            .iter_mut()
+            .map(|key| {
+                key.calls = 0;
+                std::mem::take(&mut key.unsaved)
+            })
+            .collect();
+        let ended = std::mem::replace(&mut self.date, today);
+        if unsaved.iter().all(|&calls| calls == 0) {
+            return false;
+        }
+        self.earlier.push((ended, unsaved));
+        true
+    }
+
+    fn is_available(&self, index: usize, now: Instant) -> bool {
+        self.keys[index]
+            .cooldown_until
+            .is_none_or(|until| until <= now)
+    }
+}
+
+/// The keys of a client and their usage
+#[derive(Debug)]
+pub(crate) struct KeyPool {
+    keys: Vec<PooledKey>,
+    selection: KeySelection,
+    cooldown: Duration,
+    usage_writer: Option<Arc<UsageWriter>>,
+    state: Arc<Mutex<PoolState>>,
+}
+
+impl KeyPool {
+    /// Create a pool of distinct keys, loading today's counters from `usage_file`
+    pub(crate) fn new(
+        keys: Vec<ApiKey>,
+        rate_limit: Option<u32>,
+        selection: KeySelection,
+        cooldown: Duration,
+        usage_file: Option<PathBuf>,
+    ) -> Result<Self> {
+        let mut pooled: Vec<PooledKey> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if pooled.iter().all(|existing| existing.key != key) {
+                pooled.push(PooledKey {
+                    key,
+                    rate_limiter: rate_limit.map(RateLimiter::new),
+                });
+            }
+        }
+        if pooled.is_empty() {
+            return Err(EtherscanError::MissingApiKey);
+        }
+
+        let mut state = PoolState {
+            date: Utc::now().date_naive(),
+            cursor: 0,
+            keys: pooled.iter().map(|_| KeyState::default()).collect(),
+            earlier: Vec::new(),
+        };
+        if let Some(usage) = usage_file.as_ref().map(Self::read).transpose()?.flatten() {
+            if usage.date == state.date {
+                for (key, key_state) in pooled.iter().zip(&mut state.keys) {
+                    key_state.calls = usage.calls.get(&key.key.usage_key()).copied().unwrap_or(0);
+                }
+            }
+        }
+
+        let usage_writer = usage_file.map(|path| {
+            let mut lock_path = path.clone().into_os_string();
+            lock_path.push(".lock");
+            Arc::new(UsageWriter {
+                path,
+                lock_path: PathBuf::from(lock_path),
+                keys: pooled.iter().map(|key| key.key.usage_key()).collect(),
+                scheduled: AtomicBool::new(false),
+            })
+        });
+
+        Ok(Self {
+            keys: pooled,
+            selection,
+            cooldown,
+            usage_writer,
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    /// Key at `index`
+    pub(crate) fn key(&self, index: usize) -> &ApiKey {
+        &self.keys[index].key
+    }
+
+    /// Pick the key for the next request and count the call
+    ///
+    /// When every key is cooling down, the one whose cooldown ends first is used.
+    pub(crate) fn select(&self) -> usize {
+        let mut state = self.state.lock().expect("key pool lock poisoned");
+        state.roll_over();
+
+        let now = Instant::now();
+        let count = self.keys.len();
+        let selected = match self.selection {
+            KeySelection::RoundRobin => (0..count)
+                .map(|offset| (state.cursor + offset) % count)
+                .find(|&index| state.is_available(index, now)),
+            KeySelection::LeastUsed => (0..count)
+                .filter(|&index| state.is_available(index, now))
+                .min_by_key(|&index| state.keys[index].calls),
+        };
+        let index = selected.unwrap_or_else(|| {
+            (0..count)
+                .min_by_key(|&index| state.keys[index].cooldown_until)
+                .unwrap_or(0)
+        });
+
+        state.cursor = (index + 1) % count;
+        state.keys[index].calls += 1;
+        state.keys[index].unsaved += 1;
+        drop(state);
+
+        self.schedule_write();
+        index
+    }
+
+    /// Wait for a request slot of the key at `index`
+    pub(crate) async fn acquire(&self, index: usize) {
+        if let Some(rate_limiter) = &self.keys[index].rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+
+    /// Put the key at `index` on cooldown if `error` was caused by the key
+    ///
+    /// Returns whether another key can take over right away.
+    pub(crate) fn cool_down(&self, index: usize, error: &EtherscanError) -> bool {
+        let cooldown = match error {
+            EtherscanError::RateLimit {
+                retry_after: Some(seconds),
+                ..
+            } => Duration::from_secs(*seconds),
+            EtherscanError::RateLimit { .. } => self.cooldown,
+            error if error.is_invalid_api_key() => self.cooldown,
+            _ => return false,
+        };
+
+        let mut state = self.state.lock().expect("key pool lock poisoned");
+        let now = Instant::now();
+        let until = now + cooldown;
+        let key_state = &mut state.keys[index];
+        key_state.cooldown_until = key_state.cooldown_until.max(Some(until));
+
+        (0..self.keys.len()).any(|other| other != index && state.is_available(other, now))
+    }
+
+    /// Remove every key of the pool from an error
+    pub(crate) fn redact(&self, error: EtherscanError) -> EtherscanError {
+        self.keys
+            .iter()
+            .fold(error, |error, key| error.redact(&key.key.0))
+    }
+
+    /// Today's usage of every key
+    pub(crate) fn usage(&self) -> Vec<KeyUsage> {
+        let mut state = self.state.lock().expect("key pool lock poisoned");
+        let ended_unsaved = state.roll_over();
+
+        let now = Instant::now();
+        let usage = self
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| KeyUsage {
+                key: key.key.preview(),
+                date: state.date,
+                calls: state.keys[index].calls,
+                cooling_down: !state.is_available(index, now),
+            })
+            .collect();
+        drop(state);
+
+        if ended_unsaved {
+            self.schedule_write();
+        }
+        usage
+    }
+
+    fn read(path: &PathBuf) -> Result<Option<UsageFile>> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map(Some).map_err(|e| {
+                EtherscanError::parse(format!("Invalid key usage {}: {}", path.display(), e))
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(EtherscanError::Internal(format!(
+                "Failed to read key usage {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Add the new calls to the usage file from a background task
+    ///
+    /// Calls counted while a write is waiting go out with it.
+    fn schedule_write(&self) {
+        let Some(writer) = &self.usage_writer else {
+            return;
+        };
+        if writer.scheduled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let writer = Arc::clone(writer);
+        let state = Arc::clone(&self.state);
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    tokio::time::sleep(USAGE_WRITE_DELAY).await;
+                    writer.scheduled.store(false, Ordering::Release);
+                    let _ = tokio::task::spawn_blocking(move || writer.write(&state)).await;
+                });
+            }
+            // Without a runtime there is nothing to hand the write to
+            Err(_) => {
+                writer.scheduled.store(false, Ordering::Release);
+                writer.write(&state);
+            }
+        }
+    }
+}
+
+impl Drop for KeyPool {
+    fn drop(&mut self) {
+        // Calls counted since the last background write
+        if let Some(writer) = &self.usage_writer {
+            writer.write(&self.state);
+        }
+    }
+}
+
+/// Adds the calls of a pool to its usage file
+#[derive(Debug)]
+struct UsageWriter {
+    path: PathBuf,
+    /// Locked by every writer of the file, see [`UsageWriter::merge`]
+    lock_path: PathBuf,
+    /// Usage file entry of every key, in pool order
+    keys: Vec<String>,
+    /// Whether a background write is waiting
+    scheduled: AtomicBool,
+}
+
+impl UsageWriter {
+    /// Add the unsaved calls to the file, without holding the pool lock
+    ///
+    /// Calls left from a day that has ended go out first, under their own
+    /// date. The counts of this pool are added to what the file holds rather
+    /// than replacing it, so the entries of other clients sharing the file are
+    /// kept, and the pool then takes the file's totals, which include their
+    /// calls. Failures are logged rather than failing a request, and the
+    /// calls are kept for the next write.
+    fn write(&self, state: &Mutex<PoolState>) {
+        let (earlier, date, unsaved) = {
+            let mut state = state.lock().expect("key pool lock poisoned");
+            let unsaved: Vec<u64> = state
+                .keys
+                .iter_mut()
+                .map(|key| std::mem::take(&mut key.unsaved))
+                .collect();
+            (std::mem::take(&mut state.earlier), state.date, unsaved)
+        };
+
+        for (ended, calls) in earlier {
+            if let Err(error) = self.merge(ended, &calls) {
+                tracing::warn!(path = %self.path.display(), %error, "failed to write key usage");
+                let mut state = state.lock().expect("key pool lock poisoned");
+                state.earlier.push((ended, calls));
+            }
+        }
+        if unsaved.iter().all(|&calls| calls == 0) {
+            return;
+        }
+
+        let merged = self.merge(date, &unsaved);
+        let mut state = state.lock().expect("key pool lock poisoned");
+        match merged {
+            Ok(Some(totals)) if state.date == date => {
+                for (key, total) in state.keys.iter_mut().zip(totals) {
+                    key.calls = total + key.unsaved;
+                }
+            }
+            Ok(_) => {}
+            Err(error) => {
+                tracing::warn!(path = %self.path.display(), %error, "failed to write key usage");
+                if state.date == date {
+                    for (key, calls) in state.keys.iter_mut().zip(unsaved) {
+                        key.unsaved += calls;
+                    }
+                } else {
+                    state.earlier.push((date, unsaved));
+                }
+            }
+        }
+    }
+
+    /// Add `unsaved` to the counts of `date` in the file, returning the new totals
+    ///
+    /// The file only holds one day; if it has moved on to a later day the
+    /// counts are dropped and `None` is returned. The lock file is held from
+    /// the read to the rename, so writers sharing the file, whether other
+    /// clients or the background and final writes of this one, never lose
+    /// each other's calls.
+    fn merge(
+        &self,
+        date: NaiveDate,
+        unsaved: &[u64],
+    ) -> std::result::Result<Option<Vec<u64>>, String> {
+        let lock = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&self.lock_path)
+            .and_then(|file| file.lock().map(|_| file))
+            .map_err(|e| format!("failed to lock {}: {}", self.lock_path.display(), e))?;
+
+        let mut calls = match KeyPool::read(&self.path).map_err(|e| e.to_string())? {
+            Some(usage) if usage.date > date => return Ok(None),
+            Some(usage) if usage.date == date => usage.calls,
+            _ => BTreeMap::new(),
+        };
+        let totals = self
+            .keys
+            .iter()
+            .zip(unsaved)
+            .map(|(key, &added)| {
+                let total = calls.entry(key.clone()).or_insert(0);
+                *total += added;
+                *total
+            })
+            .collect();
+
+        let text =
+            serde_json::to_string_pretty(&UsageFile { date, calls }).map_err(|e| e.to_string())?;
+        write_atomic(&self.path, text).map_err(|e| e.to_string())?;
+        drop(lock);
+        Ok(Some(totals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_over_saves_the_day_that_ended() {
+        let path =
+            std::env::temp_dir().join(format!("key-usage-roll-over-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let pool = KeyPool::new(
+            vec![ApiKey("first-key".to_string())],
+            None,
+            KeySelection::RoundRobin,
+            DEFAULT_KEY_COOLDOWN,
+            Some(path.clone()),
+        )
+        .unwrap();
+
+        let yesterday = Utc::now().date_naive().pred_opt().unwrap();
+        {
+            let mut state = pool.state.lock().unwrap();
+            state.date = yesterday;
+            state.keys[0].calls = 3;
+            state.keys[0].unsaved = 3;
+        }
+
+        // Without a runtime the write happens inline
+        let usage = pool.usage();
+        assert_eq!(usage[0].calls, 0);
+        let file = KeyPool::read(&path).unwrap().unwrap();
+        assert_eq!(file.date, yesterday);
+        assert_eq!(file.calls.values().copied().collect::<Vec<_>>(), [3]);
+
+        drop(pool);
+        std::fs::remove_file(&path).unwrap();
+        let _ = std::fs::remove_file(path.with_extension("json.lock"));
+    }
+}
@@ -12,6 +12,9 @@
 //! - **Error handling**: Comprehensive error types with context
 //! - **Rate limiting**: Built-in respect for API rate limits
 //! - **Pagination**: Easy handling of paginated responses
+//! - **Middleware**: Hooks for logging, custom headers, metrics and fault injection
+//! - **Record/replay**: Capture real responses once and replay them in offline tests
+//! - **Mock server**: Local Etherscan emulator for integration tests (`mock-server` feature)
 //!
 //! ## Quick Start
 //!
@@ -61,6 +64,103 @@
 //! }
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! Endpoint groups can be enabled individually; all of them are on by default.
+//! Blocks and transaction status are always available.
+//!
+//! - `accounts`: balances, account lists, activity, ledgers and token endpoints
+//! - `contracts`: ABI, source code and creation lookups
+//! - `verification`: source code and proxy verification (implies `contracts`)
+//! - `proxy`: JSON-RPC proxy, chain head watcher and confirmation waits
+//! - `logs`: event logs
+//! - `stats`: gas oracle
+//!
+//! Transaction details and the `indexer` and `watch` modules need both
+//! `accounts` and `proxy`.
+//!
+//! HTTPS goes through `native-tls` (default) or `rustls-tls`, which needs no
+//! system OpenSSL and suits static musl builds:
+//!
+//! ```toml
+//! keion-etherscan = { version = "0.1", default-features = false, features = ["accounts", "rustls-tls"] }
+//! ```
+//!
+//! Only tokio's `sync` and `time` features are required; the `blocking`,
+//! `mock-server` and `cli` features add the runtime pieces they use.
+//!
+//! ## Telemetry
+//!
+//! Every request runs inside an `etherscan.request` [`tracing`] span carrying the
+//! network, module, action, page/offset and a fingerprint (hash) of the API key. When the request
+//! completes the span records `latency_ms`, `retries` and an `outcome`, which is
+//! either `ok` or the [`EtherscanError::category`] of the failure.
+//!
+//! With the `metrics` cargo feature the same data is exported through the
+//! [`metrics`](https://docs.rs/metrics) facade:
+//!
+//! - `etherscan_requests_total` (counter; `network`, `module`, `action`, `outcome`)
+//! - `etherscan_request_duration_seconds` (histogram; `network`, `module`, `action`)
+//! - `etherscan_request_retries_total` (counter; `network`, `module`, `action`)
+//!
+//! ## Blocking Client
+//!
+//! The `blocking` cargo feature adds `blocking::EtherscanClient`, which exposes
+//! the same endpoint groups and builders without `async`: `execute()` returns
+//! the result directly and paginated queries offer `iter()` instead of
+//! `stream()`. It drives the async client on a runtime of its own, so it must
+//! not be used from async code.
+//!
+//! ## Sharing the Client
+//!
+//! [`EtherscanClient`] is a cheap handle over shared internals: clones use the
+//! same connection pool, rate limiter, middleware and caches. Query builders
+//! hold such a handle instead of borrowing the client, so they can be moved
+//! into `tokio::spawn` or kept in a queue. Their `spec()` method returns a
+//! serializable `spec::QuerySpec` that can be persisted and executed later.
+//!
+//! ## Multiple Chains
+//!
+//! [`MultiChainClient`] holds one client per network and runs the same query
+//! (balances, token holdings, transaction history or any closure) on all of
+//! them concurrently. Results are tagged with their network, whose
+//! [`Network::native_currency`] names the balance unit, and a failing chain
+//! is reported next to the others instead of failing the whole query. See
+//! [`multichain`].
+//!
+//! ## Multiple API Keys
+//!
+//! [`EtherscanClientBuilder::api_keys`] spreads requests over a pool of keys,
+//! picked round-robin or least-used, each with its own rate limiter. Keys
+//! that hit a rate limit or are rejected cool down for a while, and daily
+//! call counters can be persisted to see which key is close to its quota.
+//! See [`keys`].
+//!
+//! ## Errors
+//!
+//! Failed requests carry an [`error::RequestContext`] with the network,
+//! module, action, URL, HTTP status and the start of the raw body, and
+//! transport and parse errors expose their cause through
+//! [`std::error::Error::source`]. The API key is replaced with `REDACTED`
+//! everywhere, so errors and the client can be logged with `{}` or `{:?}`.
+//!
+//! ## Unmodeled Endpoints
+//!
+//! Every request is an [`Endpoint`] naming a typed [`Module`] and [`Action`].
+//! [`EtherscanClient::raw`] calls any action and returns its result as JSON;
+//! [`Call`] does the same with a response type of your choice.
+//!
+//! ## Command Line
+//!
+//! The `cli` cargo feature builds the `keion-etherscan` binary, which wraps the
+//! endpoint builders for everyday queries (`balance`, `txs`, `tokens`,
+//! `internal`, `abi`, `source`, `creation`, `verify`, `logs`, `gas`,
+//! `block-by-time`) with table, JSON or CSV output:
+//!
+//! ```text
+//! ETHERSCAN_API_KEY=... keion-etherscan --network sepolia -o csv txs 0x742d...
+//! ```
+//!
 //! ## Supported Networks
 //!
 //! - Ethereum Mainnet
@@ -78,22 +178,36 @@
 //! - ETH balances (single and multi-address)
 //! - Transaction history (normal, internal, token transfers)
 //! - Token balances and transfers (ERC-20, ERC-721, ERC-1155)
+//! - Chronological activity feed merging all of the above
+//! - ETH balance reconstruction and reconciliation against chain state
+//! - Token ledger: per-token flows, held NFTs and ERC-1155 balances at any block
+//! - Address watcher streaming new activity with confirmations and reorg handling
+//! - Resumable incremental indexing with file or SQLite (`sqlite` feature) checkpoints
+//! - SQLite storage of fetched models in a normalized schema (`sqlite` feature)
+//! - Export to CSV, NDJSON and Parquet (`parquet` feature), from vectors or paginated streams
 //!
 //! ### Transactions
-//! - Transaction details and receipts
+//! - Full transaction details (receipt, internal transactions, token transfers,
+//!   status, timestamp and exact fee) in one call
 //! - Transaction status and confirmations
 //!
 //! ### Contracts
 //! - Contract source code
 //! - Contract ABI
-//! - Contract verification status
+//! - Contract verification (Solidity, Vyper, proxy) and its status
+//!
+//! ### Logs
+//! - Event logs by emitting address and topics, across pages
 //!
 //! ### Blocks
-//! - Block information
+//! - Block information (JSON-RPC proxy)
+//! - Chain head tracking and waiting for transaction confirmations
+//! - Block number by timestamp (time-based query ranges)
 //! - Block rewards
 //!
 //! ### Tokens
 //! - Token information
+//! - Holder snapshots at any block, exportable to CSV/JSON
 //! - Token supply
 //!
 //! ### Statistics
@@ -106,35 +220,68 @@
 // Re-exports for public API
 pub use client::{EtherscanClient, EtherscanClientBuilder};
 pub use error::{EtherscanError, Result};
-pub use types::{BlockType, Network, Pagination, Sort, Tag, TransactionType};
+pub use keys::{KeySelection, KeyUsage};
+pub use middleware::Middleware;
+pub use multichain::MultiChainClient;
+pub use types::{BlockType, Closest, Network, Pagination, Sort, Tag, TransactionType};
+
+// Re-exported so callers can build time ranges without a direct dependency
+pub use chrono;
 
 // Re-export key models that users will work with
 pub use models::{
     AccountInfo,
+    // Activity feed models
+    ActivityEvent,
+    ActivityGroup,
+    ActivityKind,
     // Common model types
     Address,
     // Account models
     Balance,
+    BalanceReplay,
     BeaconWithdrawal,
     BigNumber,
+    // Block models
+    Block,
     CodeFormat,
+    ConfirmationOutcome,
     // Contract models
     ContractAbi,
     ContractCreation,
     ContractSource,
+    DecodedTransfer,
+    Direction,
+    Discrepancy,
+    Erc1155Balance,
+    ExecutionStatus,
+    // Gas tracker models
+    GasOracle,
     HexNumber,
+    HexQuantity,
+    Holder,
+    HolderSnapshot,
     InternalTransaction,
     LibraryLink,
     MultiBalance,
+    NftHolding,
     OptimizationSettings,
     ProxyVerificationStatus,
+    ReconciliationReport,
+    RpcTransaction,
     StringNumber,
+    Timestamp,
     TokenBalance,
+    TokenFlow,
+    TokenLedger,
+    TokenStandard,
     TokenTransfer,
     // Transaction models
     Transaction,
+    TransactionDetails,
     TransactionLog,
     TransactionReceipt,
+    TransactionStatus,
     TxHash,
     ValidatedBlock,
     VerificationRequest,
@@ -142,17 +289,44 @@ pub use models::{
 };
 
 // Re-export key endpoint builders for convenience
+pub use endpoints::batch::{BatchResult, ChunkResult};
+#[cfg(feature = "verification")]
 pub use endpoints::contracts::{
     ProxyVerificationBuilder, SolidityVerificationBuilder, VyperVerificationBuilder,
 };
+pub use endpoints::{Action, Call, Endpoint, Module};
 
 // Module declarations
 mod client;
 pub mod error;
 mod types;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod endpoints;
+pub mod export;
+#[cfg(all(feature = "accounts", feature = "proxy"))]
+pub mod indexer;
+pub mod keys;
+pub mod middleware;
+#[cfg(feature = "mock-server")]
+pub mod mock;
 pub mod models;
+pub mod multichain;
+#[cfg(any(
+    feature = "accounts",
+    feature = "logs",
+    feature = "verification",
+    feature = "proxy"
+))]
+pub mod spec;
+#[cfg(feature = "sqlite")]
+pub mod storage;
+mod telemetry;
+mod utils;
+pub mod vcr;
+#[cfg(all(feature = "accounts", feature = "proxy"))]
+pub mod watch;
 
 // Feature-gated exports
 // #[cfg(feature = "rate-limiting")]
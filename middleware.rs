@@ -0,0 +1,167 @@
+//! Request middleware for the Etherscan client
+//!
+//! Middleware is installed with [`EtherscanClientBuilder::middleware`] and runs
+//! in installation order for every request made by the client. It can rewrite
+//! outgoing query parameters and headers, abort a request by returning an error,
+//! and observe responses and errors together with the request latency.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::middleware::{Middleware, Request, Response};
+//! use keion_etherscan::EtherscanClient;
+//!
+//! struct Logger;
+//!
+//! impl Middleware for Logger {
+//!     fn on_response(&self, request: &Request, response: &Response<'_>) {
+//!         println!(
+//!             "{}/{} -> {} in {:?}",
+//!             request.module, request.action, response.status, response.elapsed
+//!         );
+//!     }
+//! }
+//!
+//! # fn main() -> keion_etherscan::Result<()> {
+//! let client = EtherscanClient::builder()
+//!     .api_key("your-api-key-here")
+//!     .middleware(Logger)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`EtherscanClientBuilder::middleware`]: crate::EtherscanClientBuilder::middleware
+
+use crate::{EtherscanError, Network, Result};
+use reqwest::header::HeaderMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Outgoing request as seen by middleware
+///
+/// The API key is added after all middleware has run and is never part of
+/// `params`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Network the request is sent to
+    pub network: Network,
+    /// API module (e.g. `account`)
+    pub module: String,
+    /// API action (e.g. `balance`)
+    pub action: String,
+    /// Query parameters other than `module`, `action` and `apikey`
+    pub params: Vec<(String, String)>,
+    /// Extra HTTP headers sent with the request
+    pub headers: HeaderMap,
+}
+
+impl Request {
+    pub(crate) fn new(
+        network: Network,
+        module: &str,
+        action: &str,
+        params: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            network,
+            module: module.to_string(),
+            action: action.to_string(),
+            params,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Get the value of a query parameter
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set a query parameter, replacing any existing value
+    pub fn set_param<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        let key = key.into();
+        let value = value.into();
+        match self.params.iter_mut().find(|(k, _)| *k == key) {
+            Some(param) => param.1 = value,
+            None => self.params.push((key, value)),
+        }
+    }
+
+    /// Remove a query parameter
+    pub fn remove_param(&mut self, key: &str) {
+        self.params.retain(|(k, _)| k != key);
+    }
+}
+
+/// HTTP response as seen by middleware
+#[derive(Debug, Clone, Copy)]
+pub struct Response<'a> {
+    /// HTTP status code
+    pub status: u16,
+    /// Raw response body
+    pub body: &'a str,
+    /// Time elapsed since the request was sent
+    pub elapsed: Duration,
+}
+
+/// Hook into the request lifecycle of an [`EtherscanClient`](crate::EtherscanClient)
+///
+/// All methods have no-op defaults so implementations only override what they need.
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify a request before it is sent
+    ///
+    /// Returning an error aborts the request; the error is reported to
+    /// [`Middleware::on_error`] and returned to the caller.
+    fn on_request(&self, request: &mut Request) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Observe an HTTP response, before it is parsed
+    fn on_response(&self, request: &Request, response: &Response<'_>) {
+        let _ = (request, response);
+    }
+
+    /// Observe an error from sending, the HTTP status or response parsing
+    fn on_error(&self, request: &Request, error: &EtherscanError, elapsed: Duration) {
+        let _ = (request, error, elapsed);
+    }
+}
+
+/// Ordered set of middleware installed on a client
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack(Vec<Arc<dyn Middleware>>);
+
+impl MiddlewareStack {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn on_request(&self, request: &mut Request) -> Result<()> {
+        self.0
+            .iter()
+            .try_for_each(|middleware| middleware.on_request(request))
+    }
+
+    pub(crate) fn on_response(&self, request: &Request, response: &Response<'_>) {
+        for middleware in &self.0 {
+            middleware.on_response(request, response);
+        }
+    }
+
+    pub(crate) fn on_error(&self, request: &Request, error: &EtherscanError, elapsed: Duration) {
+        for middleware in &self.0 {
+            middleware.on_error(request, error, elapsed);
+        }
+    }
+}
+
+impl fmt::Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareStack")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,284 @@
+use crate::models::{
+    Address, BeaconWithdrawal, BigNumber, InternalTransaction, RpcTransaction, StringNumber,
+    Timestamp, TokenTransfer, Transaction, TransactionLog, TransactionReceipt, TxHash,
+    ValidatedBlock,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Timestamp of block 0 on the fake chain
+pub const GENESIS_TIMESTAMP: u64 = 1_600_000_000;
+
+/// Seconds between two blocks on the fake chain
+pub const BLOCK_TIME: u64 = 12;
+
+/// Gas price used for seeded transactions (1 gwei)
+pub const DEFAULT_GAS_PRICE: u64 = 1_000_000_000;
+
+/// Verified contract served by the `contract` module
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MockContract {
+    /// Contract address
+    pub address: String,
+    /// Contract name
+    pub name: String,
+    /// ABI JSON string
+    pub abi: String,
+    /// Source code
+    pub source_code: String,
+    /// Compiler version
+    pub compiler_version: String,
+    /// Address that deployed the contract
+    pub creator: String,
+    /// Hash of the deployment transaction
+    pub creation_tx: String,
+}
+
+impl MockContract {
+    /// Create a verified contract with an empty ABI and placeholder source
+    pub fn new<S: Into<String>>(address: S, name: S, creator: S, creation_tx: S) -> Self {
+        let name = name.into();
+        Self {
+            address: address.into().to_lowercase(),
+            source_code: format!("contract {} {{}}", name),
+            name,
+            abi: "[]".to_string(),
+            compiler_version: "v0.8.24+commit.e11b9ed9".to_string(),
+            creator: creator.into().to_lowercase(),
+            creation_tx: creation_tx.into().to_lowercase(),
+        }
+    }
+}
+
+/// In-memory fake chain served by [`MockServer`](super::MockServer)
+///
+/// The chain is plain data: tests seed it through the `add_*` helpers (or by
+/// pushing models directly into the public fields) and the server filters,
+/// sorts and paginates it the way Etherscan does. Block `n` has timestamp
+/// `GENESIS_TIMESTAMP + n * BLOCK_TIME`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MockChain {
+    /// Current head block number
+    pub block_number: u64,
+    /// ETH balances in wei, keyed by lowercase address
+    pub balances: HashMap<String, String>,
+    /// ETH balance changes per lowercase address, keyed by block (`balancehistory`)
+    pub balance_history: HashMap<String, BTreeMap<u64, String>>,
+    /// ERC-20 balance changes keyed by `"{contract}:{holder}"` and block
+    /// (`tokenbalance`, `tokenbalancehistory`)
+    pub token_balance_history: HashMap<String, BTreeMap<u64, String>>,
+    /// Normal transactions (`txlist`)
+    pub transactions: Vec<Transaction>,
+    /// Internal transactions (`txlistinternal`)
+    pub internal_transactions: Vec<InternalTransaction>,
+    /// ERC-20 transfers (`tokentx`)
+    pub token_transfers: Vec<TokenTransfer>,
+    /// ERC-721 transfers (`tokennfttx`)
+    pub nft_transfers: Vec<TokenTransfer>,
+    /// ERC-1155 transfers (`token1155tx`)
+    pub erc1155_transfers: Vec<TokenTransfer>,
+    /// Blocks validated per lowercase validator address (`getminedblocks`)
+    pub validated_blocks: HashMap<String, Vec<ValidatedBlock>>,
+    /// Beacon chain withdrawals (`beaconwithdrawal`)
+    pub beacon_withdrawals: Vec<BeaconWithdrawal>,
+    /// Verified contracts (`getabi`, `getsourcecode`, `getcontractcreation`)
+    pub contracts: Vec<MockContract>,
+    /// Event logs (`getLogs`)
+    pub logs: Vec<TransactionLog>,
+    /// Transaction receipts (`eth_getTransactionReceipt`)
+    pub receipts: Vec<TransactionReceipt>,
+    /// Transactions waiting to be mined (`eth_getTransactionByHash`)
+    pub pending_transactions: Vec<RpcTransaction>,
+    /// Total ETH supply in wei (`ethsupply`)
+    pub eth_supply: String,
+    /// ETH price in USD (`ethprice`)
+    pub eth_usd_price: String,
+}
+
+impl MockChain {
+    /// Create an empty chain
+    pub fn new() -> Self {
+        Self {
+            eth_supply: "120000000000000000000000000".to_string(),
+            eth_usd_price: "2000.00".to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Timestamp of a block on the fake chain
+    pub fn block_timestamp(block: u64) -> u64 {
+        GENESIS_TIMESTAMP + block * BLOCK_TIME
+    }
+
+    /// Set the current head block
+    pub fn set_block_number(&mut self, block: u64) -> &mut Self {
+        self.block_number = block;
+        self
+    }
+
+    /// Set the ETH balance of an address
+    pub fn add_balance<S: AsRef<str>>(&mut self, address: S, wei: u128) -> &mut Self {
+        self.balances
+            .insert(address.as_ref().to_lowercase(), wei.to_string());
+        self
+    }
+
+    /// Set the ETH balance of an address from `block` onwards
+    pub fn set_balance_at<S: AsRef<str>>(
+        &mut self,
+        address: S,
+        block: u64,
+        wei: u128,
+    ) -> &mut Self {
+        self.bump_head(block);
+        self.balance_history
+            .entry(address.as_ref().to_lowercase())
+            .or_default()
+            .insert(block, wei.to_string());
+        self
+    }
+
+    /// Set the token balance of a holder from `block` onwards
+    pub fn set_token_balance_at<S: AsRef<str>>(
+        &mut self,
+        contract: S,
+        holder: S,
+        block: u64,
+        amount: u128,
+    ) -> &mut Self {
+        self.bump_head(block);
+        self.token_balance_history
+            .entry(Self::token_balance_key(contract.as_ref(), holder.as_ref()))
+            .or_default()
+            .insert(block, amount.to_string());
+        self
+    }
+
+    /// Key of a holder's balance in [`token_balance_history`](Self::token_balance_history)
+    pub fn token_balance_key(contract: &str, holder: &str) -> String {
+        format!("{}:{}", contract.to_lowercase(), holder.to_lowercase())
+    }
+
+    /// Add a normal transaction
+    pub fn add_transaction(&mut self, transaction: Transaction) -> &mut Self {
+        self.bump_head(transaction.block());
+        self.transactions.push(transaction);
+        self
+    }
+
+    /// Add a successful ETH transfer and return its generated hash
+    ///
+    /// The transaction uses 21000 gas at [`DEFAULT_GAS_PRICE`].
+    pub fn transfer_eth<S: AsRef<str>>(&mut self, block: u64, from: S, to: S, wei: u128) -> TxHash {
+        let index = self
+            .transactions
+            .iter()
+            .filter(|tx| tx.block() == block)
+            .count() as u64;
+        let hash = TxHash::new(format!("0x{:056x}{:08x}", block, index));
+        let from = Address::new(from.as_ref());
+        let nonce = self
+            .transactions
+            .iter()
+            .filter(|tx| tx.from == from)
+            .count() as u64;
+
+        self.add_transaction(Transaction {
+            block_number: StringNumber::from(block),
+            block_hash: format!("0x{:064x}", block),
+            transaction_index: StringNumber::from(index),
+            hash: hash.clone(),
+            nonce: StringNumber::from(nonce),
+            from,
+            to: Some(Address::new(to.as_ref())),
+            value: BigNumber::from(wei.to_string()),
+            gas: StringNumber::from(21_000),
+            gas_price: BigNumber::from(DEFAULT_GAS_PRICE.to_string()),
+            gas_used: StringNumber::from(21_000),
+            cumulative_gas_used: StringNumber::from(21_000 * (index + 1)),
+            input: "0x".to_string(),
+            timestamp: Timestamp::from(Self::block_timestamp(block)),
+            method_id: Some("0x".to_string()),
+            function_name: Some(String::new()),
+            receipt_status: Some(StringNumber::from(1)),
+            confirmations: None,
+            is_error: Some(StringNumber::from(0)),
+        });
+
+        hash
+    }
+
+    /// Add an internal transaction
+    pub fn add_internal_transaction(&mut self, transaction: InternalTransaction) -> &mut Self {
+        self.bump_head(transaction.block());
+        self.internal_transactions.push(transaction);
+        self
+    }
+
+    /// Add an ERC-20 transfer
+    pub fn add_token_transfer(&mut self, transfer: TokenTransfer) -> &mut Self {
+        self.bump_head(transfer.block());
+        self.token_transfers.push(transfer);
+        self
+    }
+
+    /// Add an ERC-721 transfer
+    pub fn add_nft_transfer(&mut self, transfer: TokenTransfer) -> &mut Self {
+        self.bump_head(transfer.block());
+        self.nft_transfers.push(transfer);
+        self
+    }
+
+    /// Add an ERC-1155 transfer
+    pub fn add_erc1155_transfer(&mut self, transfer: TokenTransfer) -> &mut Self {
+        self.bump_head(transfer.block());
+        self.erc1155_transfers.push(transfer);
+        self
+    }
+
+    /// Add a block validated by an address
+    pub fn add_validated_block<S: AsRef<str>>(
+        &mut self,
+        validator: S,
+        block: ValidatedBlock,
+    ) -> &mut Self {
+        self.bump_head(block.block());
+        self.validated_blocks
+            .entry(validator.as_ref().to_lowercase())
+            .or_default()
+            .push(block);
+        self
+    }
+
+    /// Add a beacon chain withdrawal
+    pub fn add_beacon_withdrawal(&mut self, withdrawal: BeaconWithdrawal) -> &mut Self {
+        self.bump_head(withdrawal.block());
+        self.beacon_withdrawals.push(withdrawal);
+        self
+    }
+
+    /// Add a verified contract
+    pub fn add_contract(&mut self, contract: MockContract) -> &mut Self {
+        self.contracts.push(contract);
+        self
+    }
+
+    /// Add an event log
+    pub fn add_log(&mut self, log: TransactionLog) -> &mut Self {
+        self.bump_head(log.block());
+        self.logs.push(log);
+        self
+    }
+
+    /// Add a transaction receipt
+    pub fn add_receipt(&mut self, receipt: TransactionReceipt) -> &mut Self {
+        self.bump_head(receipt.block());
+        self.receipts.push(receipt);
+        self
+    }
+
+    fn bump_head(&mut self, block: u64) {
+        self.block_number = self.block_number.max(block);
+    }
+}
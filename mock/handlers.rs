@@ -0,0 +1,730 @@
+//! Etherscan query-string API emulation on top of a [`MockChain`]
+
+use super::chain::{MockChain, BLOCK_TIME, DEFAULT_GAS_PRICE, GENESIS_TIMESTAMP};
+use crate::models::{
+    Address, Block, BlockchainData, HexNumber, HexQuantity, InternalTransaction, RpcTransaction,
+    Transaction, TransactionLog, TransactionReceipt,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// Largest `page * offset` window Etherscan allows
+pub(crate) const MAX_RESULT_WINDOW: u64 = 10_000;
+
+/// Actions that require an API Pro plan
+pub(crate) const PRO_ACTIONS: &[&str] = &["balancehistory", "tokenbalancehistory"];
+
+/// Result Etherscan returns when a free-tier key calls an API Pro action
+pub(crate) const PRO_ENDPOINT_MESSAGE: &str =
+    "Sorry, it looks like you are trying to access an API Pro endpoint. Contact us to upgrade to API Pro.";
+
+/// Query parameters of a request
+pub(crate) type Params = HashMap<String, String>;
+
+/// Successful `status: "1"` payload
+pub(crate) fn ok<T: Serialize>(result: T) -> Value {
+    json!({ "status": "1", "message": "OK", "result": wire_numbers(json!(result), false) })
+}
+
+/// Successful `status: "1"` payload with hex numbers, as the `logs` module sends
+fn ok_hex<T: Serialize>(result: T) -> Value {
+    json!({ "status": "1", "message": "OK", "result": wire_numbers(json!(result), true) })
+}
+
+/// Turn the JSON numbers of serialized models into the strings Etherscan sends
+///
+/// Most modules send decimal strings; `logs` and `proxy` send hex quantities.
+fn wire_numbers(value: Value, hex: bool) -> Value {
+    match value {
+        Value::Number(number) => match number.as_u64() {
+            Some(number) if hex => Value::String(format!("0x{:x}", number)),
+            _ => Value::String(number.to_string()),
+        },
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| wire_numbers(item, hex))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, field)| (key, wire_numbers(field, hex)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Error `status: "0"` payload
+pub(crate) fn not_ok(result: &str) -> Value {
+    json!({ "status": "0", "message": "NOTOK", "result": result })
+}
+
+/// Empty list payload, which Etherscan reports with `status: "0"`
+fn no_records(message: &str) -> Value {
+    json!({ "status": "0", "message": message, "result": [] })
+}
+
+/// JSON-RPC payload used by the `proxy` module
+fn rpc<T: Serialize>(result: T) -> Value {
+    json!({ "jsonrpc": "2.0", "id": 1, "result": wire_numbers(json!(result), true) })
+}
+
+/// Dispatch a request to the handler for its module and action
+pub(crate) fn handle(chain: &MockChain, params: &Params) -> Value {
+    let module = params.get("module").map(String::as_str).unwrap_or_default();
+    let action = params.get("action").map(String::as_str).unwrap_or_default();
+
+    match (module, action) {
+        ("account", "balance") => balance(chain, params),
+        ("account", "balancemulti") => balance_multi(chain, params),
+        ("account", "balancehistory") => balance_history(chain, params),
+        ("account", "tokenbalance") | ("account", "tokenbalancehistory") => {
+            token_balance(chain, params)
+        }
+        ("account", "txlist") => address_list(params, &chain.transactions, |tx, address| {
+            tx.from.as_str() == address || tx.to.as_ref().is_some_and(|to| to.as_str() == address)
+        }),
+        ("account", "txlistinternal") => internal_transactions(chain, params),
+        ("account", "tokentx") => token_transfers(params, &chain.token_transfers),
+        ("account", "tokennfttx") => token_transfers(params, &chain.nft_transfers),
+        ("account", "token1155tx") => token_transfers(params, &chain.erc1155_transfers),
+        ("account", "getminedblocks") => mined_blocks(chain, params),
+        ("account", "beaconwithdrawal") => {
+            address_list(params, &chain.beacon_withdrawals, |withdrawal, address| {
+                withdrawal.address.as_str() == address
+            })
+        }
+        ("block", "getblocknobytime") => block_by_time(chain, params),
+        ("contract", "getabi") => contract_abi(chain, params),
+        ("contract", "getsourcecode") => contract_source(chain, params),
+        ("contract", "getcontractcreation") => contract_creation(chain, params),
+        ("contract", "verifysourcecode") => verify_source(chain, params),
+        ("contract", "checkverifystatus") => verification_status(params),
+        ("contract", "verifyproxycontract") => verify_proxy(chain, params),
+        ("contract", "checkproxyverification") => proxy_verification_status(params),
+        ("gastracker", "gasoracle") => gas_oracle(chain),
+        ("logs", "getLogs") => logs(chain, params),
+        ("proxy", "eth_blockNumber") => rpc(format!("0x{:x}", chain.block_number)),
+        ("proxy", "eth_getBlockByNumber") => block_by_number(chain, params),
+        ("proxy", "eth_getTransactionByHash") => transaction_by_hash(chain, params),
+        ("proxy", "eth_getTransactionCount") => transaction_count(chain, params),
+        ("proxy", "eth_getTransactionReceipt") => transaction_receipt(chain, params),
+        ("transaction", "getstatus") => transaction_status(chain, params),
+        ("stats", "ethsupply") => ok(&chain.eth_supply),
+        ("stats", "ethprice") => ok(json!({
+            "ethbtc": "0.05",
+            "ethbtc_timestamp": MockChain::block_timestamp(chain.block_number).to_string(),
+            "ethusd": chain.eth_usd_price,
+            "ethusd_timestamp": MockChain::block_timestamp(chain.block_number).to_string(),
+        })),
+        _ => not_ok("Error! Missing Or invalid Action name"),
+    }
+}
+
+fn address_param(params: &Params, key: &str) -> Result<String, Value> {
+    let address = params
+        .get(key)
+        .ok_or_else(|| not_ok("Error! Missing Or invalid Address"))?;
+    crate::error::validation::normalize_address(address)
+        .map_err(|_| not_ok("Error! Invalid address format"))
+}
+
+fn number_param(params: &Params, key: &str) -> Option<u64> {
+    params.get(key).and_then(|value| {
+        value
+            .strip_prefix("0x")
+            .map(|hex| u64::from_str_radix(hex, 16).ok())
+            .unwrap_or_else(|| value.parse().ok())
+    })
+}
+
+fn balance(chain: &MockChain, params: &Params) -> Value {
+    match address_param(params, "address") {
+        Ok(address) => ok(chain
+            .balances
+            .get(&address)
+            .cloned()
+            .unwrap_or_else(|| "0".to_string())),
+        Err(error) => error,
+    }
+}
+
+fn balance_multi(chain: &MockChain, params: &Params) -> Value {
+    let addresses: Vec<&str> = params
+        .get("address")
+        .map(|list| list.split(',').collect())
+        .unwrap_or_default();
+
+    if addresses.is_empty() || addresses.len() > 20 {
+        return not_ok("Error! Missing Or invalid Address");
+    }
+
+    let mut balances = Vec::new();
+    for address in addresses {
+        match crate::error::validation::normalize_address(address) {
+            Ok(address) => balances.push(json!({
+                "account": address,
+                "balance": chain.balances.get(&address).cloned().unwrap_or_else(|| "0".to_string()),
+            })),
+            Err(_) => return not_ok("Error! Invalid address format"),
+        }
+    }
+
+    ok(balances)
+}
+
+/// Value of a balance history at a block (or the latest value without one)
+fn balance_at(history: Option<&BTreeMap<u64, String>>, block: Option<u64>) -> String {
+    history
+        .and_then(|history| history.range(..=block.unwrap_or(u64::MAX)).next_back())
+        .map(|(_, balance)| balance.clone())
+        .unwrap_or_else(|| "0".to_string())
+}
+
+fn balance_history(chain: &MockChain, params: &Params) -> Value {
+    let address = match address_param(params, "address") {
+        Ok(address) => address,
+        Err(error) => return error,
+    };
+    match number_param(params, "blockno") {
+        Some(block) => ok(balance_at(chain.balance_history.get(&address), Some(block))),
+        None => not_ok("Error! Block number is required"),
+    }
+}
+
+fn token_balance(chain: &MockChain, params: &Params) -> Value {
+    let (address, contract) = match (
+        address_param(params, "address"),
+        address_param(params, "contractaddress"),
+    ) {
+        (Ok(address), Ok(contract)) => (address, contract),
+        (Err(error), _) | (_, Err(error)) => return error,
+    };
+    let history = chain
+        .token_balance_history
+        .get(&MockChain::token_balance_key(&contract, &address));
+    ok(balance_at(history, number_param(params, "blockno")))
+}
+
+fn block_by_time(chain: &MockChain, params: &Params) -> Value {
+    let Some(timestamp) = number_param(params, "timestamp") else {
+        return not_ok("Error! Invalid timestamp");
+    };
+    let head_timestamp = MockChain::block_timestamp(chain.block_number);
+    let elapsed = timestamp.saturating_sub(GENESIS_TIMESTAMP);
+
+    let block = match params.get("closest").map(String::as_str) {
+        Some("before") if timestamp >= GENESIS_TIMESTAMP => {
+            Some((elapsed / BLOCK_TIME).min(chain.block_number))
+        }
+        Some("after") if timestamp <= head_timestamp => Some(elapsed.div_ceil(BLOCK_TIME)),
+        Some("before") | Some("after") => None,
+        _ => return not_ok("Error! Invalid closest parameter"),
+    };
+
+    match block {
+        Some(block) => ok(block.to_string()),
+        None => not_ok("Error! No closest block found"),
+    }
+}
+
+/// Filter by block range, sort by block and paginate like the list endpoints do
+fn paginate<T: Serialize + BlockchainData>(params: &Params, mut items: Vec<&T>) -> Value {
+    let start_block = number_param(params, "startblock").unwrap_or(0);
+    let end_block = number_param(params, "endblock").unwrap_or(u64::MAX);
+    items.retain(|item| {
+        let block = item.block_number().unwrap_or(0);
+        block >= start_block && block <= end_block
+    });
+
+    // Stable sort keeps insertion order within a block
+    items.sort_by_key(|item| item.block_number().unwrap_or(0));
+    if params.get("sort").map(String::as_str) == Some("desc") {
+        items.reverse();
+    }
+
+    let page = number_param(params, "page").unwrap_or(1).max(1);
+    let offset = number_param(params, "offset").unwrap_or(MAX_RESULT_WINDOW);
+    if offset > 0 && page.saturating_mul(offset) > MAX_RESULT_WINDOW {
+        return not_ok(
+            "Result window is too large, PageNo x Offset size must be less than or equal to 10000",
+        );
+    }
+
+    let items: Vec<&T> = if offset == 0 {
+        items
+    } else {
+        items
+            .into_iter()
+            .skip(((page - 1) * offset) as usize)
+            .take(offset as usize)
+            .collect()
+    };
+
+    if items.is_empty() {
+        no_records("No transactions found")
+    } else {
+        ok(items)
+    }
+}
+
+fn address_list<T, F>(params: &Params, items: &[T], involves: F) -> Value
+where
+    T: Serialize + BlockchainData,
+    F: Fn(&T, &str) -> bool,
+{
+    match address_param(params, "address") {
+        Ok(address) => paginate(
+            params,
+            items
+                .iter()
+                .filter(|item| involves(item, &address))
+                .collect(),
+        ),
+        Err(error) => error,
+    }
+}
+
+fn internal_transactions(chain: &MockChain, params: &Params) -> Value {
+    if let Some(hash) = params.get("txhash") {
+        let hash = hash.to_lowercase();
+        // Etherscan leaves the hash and trace ID out of by-hash rows
+        let items: Vec<_> = chain
+            .internal_transactions
+            .iter()
+            .filter(|tx| {
+                tx.hash
+                    .as_ref()
+                    .is_some_and(|tx_hash| tx_hash.as_str() == hash)
+            })
+            .map(|tx| InternalTransaction {
+                hash: None,
+                trace_id: None,
+                ..tx.clone()
+            })
+            .collect();
+        return paginate(params, items.iter().collect());
+    }
+
+    if params.contains_key("address") {
+        return address_list(params, &chain.internal_transactions, |tx, address| {
+            tx.from.as_str() == address || tx.to.as_ref().is_some_and(|to| to.as_str() == address)
+        });
+    }
+
+    paginate(params, chain.internal_transactions.iter().collect())
+}
+
+fn token_transfers(params: &Params, transfers: &[crate::models::TokenTransfer]) -> Value {
+    let address = match params.get("address") {
+        Some(_) => match address_param(params, "address") {
+            Ok(address) => Some(address),
+            Err(error) => return error,
+        },
+        None => None,
+    };
+    let contract = match params.get("contractaddress") {
+        Some(_) => match address_param(params, "contractaddress") {
+            Ok(contract) => Some(contract),
+            Err(error) => return error,
+        },
+        None => None,
+    };
+
+    if address.is_none() && contract.is_none() {
+        return not_ok("Error! Missing Or invalid Address");
+    }
+
+    let items = transfers
+        .iter()
+        .filter(|transfer| {
+            address.as_deref().is_none_or(|address| {
+                transfer.from.as_str() == address || transfer.to.as_str() == address
+            }) && contract
+                .as_deref()
+                .is_none_or(|contract| transfer.contract_address.as_str() == contract)
+        })
+        .collect();
+
+    paginate(params, items)
+}
+
+/// Validated blocks, newest first like Etherscan serves them
+fn mined_blocks(chain: &MockChain, params: &Params) -> Value {
+    let mut params = params.clone();
+    params.insert("sort".to_string(), "desc".to_string());
+    match address_param(&params, "address") {
+        Ok(address) => paginate(
+            &params,
+            chain
+                .validated_blocks
+                .get(&address)
+                .map(|blocks| blocks.iter().collect())
+                .unwrap_or_default(),
+        ),
+        Err(error) => error,
+    }
+}
+
+fn find_contract<'a>(
+    chain: &'a MockChain,
+    params: &Params,
+) -> Result<Option<&'a super::chain::MockContract>, Value> {
+    let address = address_param(params, "address")?;
+    Ok(chain
+        .contracts
+        .iter()
+        .find(|contract| contract.address == address))
+}
+
+fn contract_abi(chain: &MockChain, params: &Params) -> Value {
+    match find_contract(chain, params) {
+        Ok(Some(contract)) => ok(&contract.abi),
+        Ok(None) => not_ok("Contract source code not verified"),
+        Err(error) => error,
+    }
+}
+
+/// Accept verification of any seeded contract and hand out a GUID naming it
+fn verify_source(chain: &MockChain, params: &Params) -> Value {
+    for key in ["sourceCode", "contractname", "compilerversion"] {
+        if params.get(key).is_none_or(String::is_empty) {
+            return not_ok(&format!("Error! Missing {}", key));
+        }
+    }
+
+    let address = match address_param(params, "contractaddress") {
+        Ok(address) => address,
+        Err(error) => return error,
+    };
+    if chain
+        .contracts
+        .iter()
+        .any(|contract| contract.address == address)
+    {
+        ok(format!("mock-{}", address.trim_start_matches("0x")))
+    } else {
+        not_ok(&format!("Unable to locate ContractCode at {}", address))
+    }
+}
+
+fn verification_status(params: &Params) -> Value {
+    match params.get("guid") {
+        Some(guid) if guid.starts_with("mock-") => ok("Pass - Verified"),
+        _ => not_ok("Unable to locate verification request"),
+    }
+}
+
+/// Accept proxy verification of any seeded contract, like [`verify_source`]
+fn verify_proxy(chain: &MockChain, params: &Params) -> Value {
+    let address = match address_param(params, "address") {
+        Ok(address) => address,
+        Err(error) => return error,
+    };
+    if chain
+        .contracts
+        .iter()
+        .any(|contract| contract.address == address)
+    {
+        ok(format!("mock-{}", address.trim_start_matches("0x")))
+    } else {
+        not_ok(&format!("Unable to locate ContractCode at {}", address))
+    }
+}
+
+fn proxy_verification_status(params: &Params) -> Value {
+    match params.get("guid") {
+        Some(guid) if guid.starts_with("mock-") => ok(format!(
+            "The proxy's (0x{}) implementation contract is found and is successfully updated.",
+            &guid["mock-".len()..]
+        )),
+        _ => not_ok("Unable to locate verification request"),
+    }
+}
+
+/// Gas price suggestions around the chain's default gas price
+fn gas_oracle(chain: &MockChain) -> Value {
+    let gwei = DEFAULT_GAS_PRICE / 1_000_000_000;
+    ok(json!({
+        "LastBlock": chain.block_number.to_string(),
+        "SafeGasPrice": gwei.to_string(),
+        "ProposeGasPrice": gwei.to_string(),
+        "FastGasPrice": (gwei * 2).to_string(),
+        "suggestBaseFee": format!("{}.5", gwei.saturating_sub(1)),
+        "gasUsedRatio": "0.5,0.5,0.5,0.5,0.5",
+    }))
+}
+
+fn contract_source(chain: &MockChain, params: &Params) -> Value {
+    let contract = match find_contract(chain, params) {
+        Ok(contract) => contract,
+        Err(error) => return error,
+    };
+
+    let entry = match contract {
+        Some(contract) => json!({
+            "SourceCode": contract.source_code,
+            "ABI": contract.abi,
+            "ContractName": contract.name,
+            "CompilerVersion": contract.compiler_version,
+            "OptimizationUsed": "0",
+            "Runs": "200",
+            "ConstructorArguments": "",
+            "EVMVersion": "Default",
+            "Library": "",
+            "LicenseType": "MIT",
+            "Proxy": "0",
+            "Implementation": "",
+            "SwarmSource": "",
+        }),
+        None => json!({
+            "SourceCode": "",
+            "ABI": "Contract source code not verified",
+            "ContractName": "",
+            "CompilerVersion": "",
+            "OptimizationUsed": "",
+            "Runs": "",
+            "ConstructorArguments": "",
+            "EVMVersion": "",
+            "Library": "",
+            "LicenseType": "",
+            "Proxy": "",
+            "Implementation": "",
+            "SwarmSource": "",
+        }),
+    };
+
+    ok(vec![entry])
+}
+
+fn contract_creation(chain: &MockChain, params: &Params) -> Value {
+    let addresses: Vec<&str> = params
+        .get("contractaddresses")
+        .map(|list| list.split(',').collect())
+        .unwrap_or_default();
+
+    if addresses.is_empty() || addresses.len() > 5 {
+        return not_ok("Error! Missing Or invalid contractaddresses");
+    }
+
+    let mut requested = Vec::new();
+    for address in addresses {
+        match crate::error::validation::normalize_address(address) {
+            Ok(address) => requested.push(address),
+            Err(_) => return not_ok("Error! Invalid address format"),
+        }
+    }
+
+    // Like Etherscan, answer in deployment order rather than request order
+    let creations: Vec<Value> = chain
+        .contracts
+        .iter()
+        .filter(|contract| requested.contains(&contract.address))
+        .map(|contract| {
+            json!({
+                "contractAddress": contract.address,
+                "contractCreator": contract.creator,
+                "txHash": contract.creation_tx,
+            })
+        })
+        .collect();
+
+    if creations.is_empty() {
+        no_records("No data found")
+    } else {
+        ok(creations)
+    }
+}
+
+fn logs(chain: &MockChain, params: &Params) -> Value {
+    let address = match params.get("address") {
+        Some(_) => match address_param(params, "address") {
+            Ok(address) => Some(address),
+            Err(error) => return error,
+        },
+        None => None,
+    };
+    let from_block = number_param(params, "fromBlock").unwrap_or(0);
+    let to_block = number_param(params, "toBlock").unwrap_or(u64::MAX);
+    let topics: Vec<(usize, String)> = (0..4)
+        .filter_map(|index| {
+            params
+                .get(&format!("topic{}", index))
+                .map(|topic| (index, topic.to_lowercase()))
+        })
+        .collect();
+
+    let logs: Vec<&TransactionLog> = chain
+        .logs
+        .iter()
+        .filter(|log| {
+            log.block() >= from_block
+                && log.block() <= to_block
+                && address
+                    .as_deref()
+                    .is_none_or(|address| log.address.as_str() == address)
+                && topics.iter().all(|(index, topic)| {
+                    log.topics
+                        .get(*index)
+                        .is_some_and(|value| value.to_lowercase() == *topic)
+                })
+        })
+        .collect();
+
+    let page = number_param(params, "page").unwrap_or(1).max(1) as usize;
+    let offset = number_param(params, "offset").unwrap_or(1000).max(1) as usize;
+    let logs: Vec<&TransactionLog> = logs
+        .into_iter()
+        .skip((page - 1) * offset)
+        .take(offset)
+        .collect();
+
+    if logs.is_empty() {
+        no_records("No records found")
+    } else {
+        ok_hex(logs)
+    }
+}
+
+fn find_transaction<'a>(chain: &'a MockChain, params: &Params) -> Option<&'a Transaction> {
+    let hash = params.get("txhash")?.to_lowercase();
+    chain
+        .transactions
+        .iter()
+        .find(|tx| tx.hash.as_str() == hash)
+}
+
+fn has_error(transaction: &Transaction) -> bool {
+    transaction.has_error() || !transaction.is_successful()
+}
+
+fn rpc_transaction(transaction: &Transaction) -> RpcTransaction {
+    RpcTransaction {
+        hash: transaction.hash.clone(),
+        block_hash: Some(transaction.block_hash.clone()),
+        block_number: Some(HexNumber::from(transaction.block())),
+        transaction_index: Some(HexNumber::from(transaction.index())),
+        from: transaction.from.clone(),
+        to: transaction.to.clone(),
+        value: HexQuantity::from(transaction.value.as_u128().unwrap_or_default()),
+        nonce: HexNumber::from(transaction.nonce_value()),
+        gas: HexNumber::from(transaction.gas_limit()),
+        gas_price: transaction.gas_price.as_u128().map(HexQuantity::from),
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        input: transaction.input.clone(),
+        transaction_type: Some(HexNumber::from(0)),
+    }
+}
+
+fn transaction_by_hash(chain: &MockChain, params: &Params) -> Value {
+    if let Some(transaction) = find_transaction(chain, params) {
+        return rpc(rpc_transaction(transaction));
+    }
+
+    let hash = params
+        .get("txhash")
+        .map(|hash| hash.to_lowercase())
+        .unwrap_or_default();
+    rpc(chain
+        .pending_transactions
+        .iter()
+        .find(|tx| tx.hash.as_str() == hash))
+}
+
+/// Number of mined transactions sent by an address up to `tag`
+fn transaction_count(chain: &MockChain, params: &Params) -> Value {
+    let address = match address_param(params, "address") {
+        Ok(address) => address,
+        Err(_) => return rpc(Value::Null),
+    };
+    let block = number_param(params, "tag").unwrap_or(chain.block_number);
+    let count = chain
+        .transactions
+        .iter()
+        .filter(|tx| tx.from.as_str() == address && tx.block() <= block)
+        .count();
+    rpc(format!("0x{:x}", count))
+}
+
+fn block_by_number(chain: &MockChain, params: &Params) -> Value {
+    let Some(number) = number_param(params, "tag") else {
+        return rpc(Value::Null);
+    };
+    if number > chain.block_number {
+        return rpc(Value::Null);
+    }
+
+    let transactions: Vec<&Transaction> = chain
+        .transactions
+        .iter()
+        .filter(|tx| tx.block() == number)
+        .collect();
+    rpc(Block {
+        number: HexNumber::from(number),
+        hash: format!("0x{:064x}", number),
+        parent_hash: format!("0x{:064x}", number.saturating_sub(1)),
+        timestamp: HexNumber::from(MockChain::block_timestamp(number)),
+        miner: Address::new("0x0000000000000000000000000000000000000000"),
+        gas_used: HexNumber::from(
+            transactions
+                .iter()
+                .map(|tx| tx.gas_used_amount())
+                .sum::<u64>(),
+        ),
+        gas_limit: HexNumber::from(30_000_000),
+        base_fee_per_gas: None,
+        transactions: transactions.iter().map(|tx| tx.hash.to_string()).collect(),
+    })
+}
+
+/// Seeded receipt, or one synthesized from the transaction and its logs
+fn transaction_receipt(chain: &MockChain, params: &Params) -> Value {
+    let hash = params
+        .get("txhash")
+        .map(|hash| hash.to_lowercase())
+        .unwrap_or_default();
+
+    if let Some(receipt) = chain
+        .receipts
+        .iter()
+        .find(|receipt| receipt.transaction_hash.as_str() == hash)
+    {
+        return rpc(receipt);
+    }
+
+    rpc(
+        find_transaction(chain, params).map(|transaction| TransactionReceipt {
+            block_hash: transaction.block_hash.clone(),
+            block_number: HexNumber::from(transaction.block()),
+            contract_address: None,
+            cumulative_gas_used: HexNumber::from(transaction.cumulative_gas_used.value()),
+            from: transaction.from.clone(),
+            gas_used: HexNumber::from(transaction.gas_used_amount()),
+            logs: chain
+                .logs
+                .iter()
+                .filter(|log| log.transaction_hash == transaction.hash)
+                .cloned()
+                .collect(),
+            logs_bloom: format!("0x{}", "0".repeat(512)),
+            status: HexNumber::from(u64::from(!has_error(transaction))),
+            to: transaction.to.clone(),
+            transaction_hash: transaction.hash.clone(),
+            transaction_index: HexNumber::from(transaction.index()),
+            effective_gas_price: transaction.gas_price.as_u128().map(HexQuantity::from),
+            transaction_type: Some(HexNumber::from(0)),
+        }),
+    )
+}
+
+fn transaction_status(chain: &MockChain, params: &Params) -> Value {
+    let failed = find_transaction(chain, params).is_some_and(has_error);
+    ok(json!({
+        "isError": if failed { "1" } else { "0" },
+        "errDescription": if failed { "Reverted" } else { "" },
+    }))
+}
@@ -0,0 +1,325 @@
+//! Mock Etherscan HTTP server for integration testing
+//!
+//! [`MockServer`] speaks the Etherscan query-string API (and form-encoded POST
+//! bodies) on localhost and serves `account`, `contract`, `gastracker`, `logs`,
+//! `proxy` and `stats` actions from an in-memory [`MockChain`]. It emulates the
+//! parts of the real API that matter for client behaviour: block-range
+//! filtering, sorting and `page`/`offset` pagination (including the 10 000
+//! result window), `status: "0"` error payloads, empty result lists, API key
+//! checks, rate-limit rejections, API Pro restrictions and injected HTTP
+//! failures.
+//!
+//! Available with the `mock-server` cargo feature, which also builds the
+//! `keion-etherscan-mock` binary.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::mock::{MockChain, MockServer};
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let mut chain = MockChain::new();
+//!     chain.add_balance("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6", 10u128.pow(18));
+//!
+//!     let server = MockServer::start(chain).await?;
+//!     let balance = server
+//!         .client()
+//!         .accounts()
+//!         .balance("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!         .await?;
+//!     assert_eq!(balance.eth(), Some(1.0));
+//!     Ok(())
+//! }
+//! ```
+
+mod chain;
+mod handlers;
+
+pub use chain::{MockChain, MockContract, BLOCK_TIME, DEFAULT_GAS_PRICE, GENESIS_TIMESTAMP};
+
+use crate::{EtherscanClient, EtherscanError, Result};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response, Server, StatusCode};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex, RwLock, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// API key accepted by a server started with the default configuration
+pub const MOCK_API_KEY: &str = "mock-api-key";
+
+/// Behaviour of a [`MockServer`]
+#[derive(Debug, Clone)]
+pub struct MockConfig {
+    /// API key every request must carry (`None` accepts any key)
+    pub api_key: Option<String>,
+    /// Further API keys accepted besides `api_key`
+    pub extra_api_keys: Vec<String>,
+    /// Requests accepted per second before answering with a rate-limit error
+    pub rate_limit: Option<u32>,
+    /// Number of initial requests answered with an HTTP 502
+    pub http_failures: usize,
+    /// Whether API Pro actions such as `balancehistory` are available
+    pub pro_plan: bool,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            api_key: Some(MOCK_API_KEY.to_string()),
+            extra_api_keys: Vec::new(),
+            rate_limit: None,
+            http_failures: 0,
+            pro_plan: true,
+        }
+    }
+}
+
+impl MockConfig {
+    /// Require a specific API key
+    pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Also accept `api_key`, for clients with several keys
+    pub fn accept_api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        self.extra_api_keys.push(api_key.into());
+        self
+    }
+
+    /// Reject requests above `requests_per_second`
+    pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit = Some(requests_per_second);
+        self
+    }
+
+    /// Answer the first `count` requests with an HTTP 502
+    pub fn http_failures(mut self, count: usize) -> Self {
+        self.http_failures = count;
+        self
+    }
+
+    /// Reject API Pro actions the way Etherscan does for free-tier keys
+    pub fn free_tier(mut self) -> Self {
+        self.pro_plan = false;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct Traffic {
+    requests: Vec<HashMap<String, String>>,
+    window_start: Option<Instant>,
+    window_count: u32,
+}
+
+#[derive(Debug)]
+struct ServerState {
+    chain: RwLock<MockChain>,
+    config: MockConfig,
+    traffic: Mutex<Traffic>,
+}
+
+impl ServerState {
+    /// Answer a request from its query string and form-encoded POST body
+    fn respond(&self, query: Option<&str>, body: &[u8]) -> (StatusCode, serde_json::Value) {
+        let mut params: HashMap<String, String> =
+            url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+                .chain(url::form_urlencoded::parse(body))
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+        let api_key = params.remove("apikey");
+
+        {
+            let mut traffic = self.traffic.lock().expect("traffic lock poisoned");
+            traffic.requests.push(params.clone());
+
+            if traffic.requests.len() <= self.config.http_failures {
+                return (
+                    StatusCode::BAD_GATEWAY,
+                    serde_json::Value::String("Bad Gateway".to_string()),
+                );
+            }
+
+            if let Some(limit) = self.config.rate_limit {
+                let now = Instant::now();
+                match traffic.window_start {
+                    Some(start) if now.duration_since(start) < Duration::from_secs(1) => {
+                        traffic.window_count += 1;
+                    }
+                    _ => {
+                        traffic.window_start = Some(now);
+                        traffic.window_count = 1;
+                    }
+                }
+                if traffic.window_count > limit {
+                    return (
+                        StatusCode::OK,
+                        handlers::not_ok(&format!(
+                            "Max calls per sec rate limit reached ({}/sec)",
+                            limit
+                        )),
+                    );
+                }
+            }
+        }
+
+        if let Some(expected) = &self.config.api_key {
+            let accepted = api_key.as_ref().is_some_and(|api_key| {
+                api_key == expected || self.config.extra_api_keys.contains(api_key)
+            });
+            if !accepted {
+                return (StatusCode::OK, handlers::not_ok("Invalid API Key"));
+            }
+        }
+
+        let action = params.get("action").map(String::as_str).unwrap_or_default();
+        if !self.config.pro_plan && handlers::PRO_ACTIONS.contains(&action) {
+            return (
+                StatusCode::OK,
+                handlers::not_ok(handlers::PRO_ENDPOINT_MESSAGE),
+            );
+        }
+
+        let chain = self.chain.read().expect("chain lock poisoned");
+        (StatusCode::OK, handlers::handle(&chain, &params))
+    }
+}
+
+/// Etherscan-compatible HTTP server backed by a [`MockChain`]
+///
+/// The server runs on the current tokio runtime and shuts down when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<ServerState>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Start a server on a random local port with the default configuration
+    pub async fn start(chain: MockChain) -> Result<Self> {
+        Self::start_with(chain, MockConfig::default()).await
+    }
+
+    /// Start a server on a random local port
+    pub async fn start_with(chain: MockChain, config: MockConfig) -> Result<Self> {
+        Self::bind(([127, 0, 0, 1], 0).into(), chain, config).await
+    }
+
+    /// Start a server on a specific address
+    pub async fn bind(address: SocketAddr, chain: MockChain, config: MockConfig) -> Result<Self> {
+        let io_error = |e: std::io::Error| {
+            EtherscanError::Internal(format!("Failed to bind mock server: {}", e))
+        };
+        let listener = TcpListener::bind(address).map_err(io_error)?;
+        listener.set_nonblocking(true).map_err(io_error)?;
+        let address = listener.local_addr().map_err(io_error)?;
+
+        let state = Arc::new(ServerState {
+            chain: RwLock::new(chain),
+            config,
+            traffic: Mutex::new(Traffic::default()),
+        });
+
+        let service_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: hyper::Request<Body>| {
+                    let state = state.clone();
+                    async move {
+                        let query = request.uri().query().map(str::to_string);
+                        let body = hyper::body::to_bytes(request.into_body())
+                            .await
+                            .unwrap_or_default();
+                        let (status, payload) = state.respond(query.as_deref(), &body);
+                        Ok::<_, Infallible>(
+                            Response::builder()
+                                .status(status)
+                                .header("content-type", "application/json")
+                                .body(Body::from(payload.to_string()))
+                                .expect("valid response"),
+                        )
+                    }
+                }))
+            }
+        });
+
+        let server = Server::from_tcp(listener)
+            .map_err(|e| EtherscanError::Internal(format!("Failed to start mock server: {}", e)))?
+            .serve(make_service);
+
+        let (shutdown, signal) = oneshot::channel::<()>();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            signal.await.ok();
+        }));
+
+        Ok(Self {
+            address,
+            state,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Socket address the server listens on
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Base URL to pass to [`EtherscanClientBuilder::base_url`](crate::EtherscanClientBuilder::base_url)
+    pub fn url(&self) -> String {
+        format!("http://{}/api", self.address)
+    }
+
+    /// Create a client for this server with rate limiting disabled
+    pub fn client(&self) -> EtherscanClient {
+        EtherscanClient::builder()
+            .api_key(
+                self.state
+                    .config
+                    .api_key
+                    .clone()
+                    .unwrap_or_else(|| MOCK_API_KEY.to_string()),
+            )
+            .base_url(self.url())
+            .rate_limit(0)
+            .build()
+            .expect("mock client configuration is valid")
+    }
+
+    /// Access the fake chain, e.g. to seed more data while the server runs
+    pub fn chain(&self) -> RwLockWriteGuard<'_, MockChain> {
+        self.state.chain.write().expect("chain lock poisoned")
+    }
+
+    /// Query parameters (without the API key) of every request received so far
+    pub fn requests(&self) -> Vec<HashMap<String, String>> {
+        self.state
+            .traffic
+            .lock()
+            .expect("traffic lock poisoned")
+            .requests
+            .clone()
+    }
+
+    /// Number of requests received so far
+    pub fn request_count(&self) -> usize {
+        self.state
+            .traffic
+            .lock()
+            .expect("traffic lock poisoned")
+            .requests
+            .len()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
@@ -2,7 +2,11 @@ use crate::models::{Address, BigNumber, BlockchainData, StringNumber};
 use serde::{Deserialize, Serialize};
 
 /// ETH balance information for an account
+///
+/// The `balance` action returns a bare wei string while `balancemulti` returns
+/// objects with the account; both deserialize into this type.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "BalanceRepr")]
 pub struct Balance {
     /// The account address
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -12,6 +16,29 @@ pub struct Balance {
     pub balance: BigNumber,
 }
 
+/// Wire formats accepted for [`Balance`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BalanceRepr {
+    Wei(BigNumber),
+    Account {
+        account: Option<Address>,
+        balance: BigNumber,
+    },
+}
+
+impl From<BalanceRepr> for Balance {
+    fn from(repr: BalanceRepr) -> Self {
+        match repr {
+            BalanceRepr::Wei(balance) => Balance {
+                account: None,
+                balance,
+            },
+            BalanceRepr::Account { account, balance } => Balance { account, balance },
+        }
+    }
+}
+
 impl Balance {
     /// Get balance in wei as a string
     pub fn wei(&self) -> &str {
@@ -41,6 +68,41 @@ impl BlockchainData for Balance {
     }
 }
 
+/// Balance of an account at a specific block
+///
+/// For token queries the balance is in the token's smallest unit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceSnapshot {
+    /// Block the balance was sampled at
+    pub block_number: u64,
+
+    /// Balance in wei (or token base units)
+    pub balance: BigNumber,
+}
+
+impl BalanceSnapshot {
+    /// Get the raw balance as a string
+    pub fn wei(&self) -> &str {
+        self.balance.as_str()
+    }
+
+    /// Get the balance in ETH (convenience method)
+    /// Returns None if the balance is too large or invalid
+    pub fn eth(&self) -> Option<f64> {
+        self.balance.as_u128().map(|wei| wei as f64 / 1e18)
+    }
+}
+
+impl BlockchainData for BalanceSnapshot {
+    fn block_number(&self) -> Option<u64> {
+        Some(self.block_number)
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        None
+    }
+}
+
 /// ERC-20 token balance information
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenBalance {
@@ -230,6 +292,25 @@ mod tests {
         assert_eq!(balance.balance.as_str(), "123456789");
     }
 
+    #[test]
+    fn test_balance_deserialization_from_wei_string() {
+        let balance: Balance = serde_json::from_str(r#""40891626854930000000000""#).unwrap();
+        assert_eq!(balance.account, None);
+        assert_eq!(balance.wei(), "40891626854930000000000");
+    }
+
+    #[test]
+    fn test_balance_serialization_round_trip() {
+        // Balances always serialize as objects, with or without the account
+        for json in [
+            r#"{"balance":"1000"}"#,
+            r#"{"account":"0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6","balance":"1000"}"#,
+        ] {
+            let balance: Balance = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&balance).unwrap(), json);
+        }
+    }
+
     #[test]
     fn test_multi_balance_conversion() {
         let multi = MultiBalance {
@@ -0,0 +1,426 @@
+use crate::models::{
+    Address, BeaconWithdrawal, BlockchainData, InternalTransaction, Timestamp, TokenTransfer,
+    Transaction, TxHash,
+};
+use crate::types::Sort;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Direction of an event relative to the queried address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// Value or tokens received by the address
+    In,
+    /// Value or tokens sent by the address
+    Out,
+    /// The address is both sender and recipient
+    #[serde(rename = "self")]
+    SelfTransfer,
+    /// The address is neither sender nor recipient, e.g. an internal
+    /// transaction between two contracts it called
+    Unrelated,
+}
+
+impl Direction {
+    /// Determine the direction of a transfer between `from` and `to`
+    pub fn of(address: &str, from: &Address, to: Option<&Address>) -> Self {
+        let sent = from.as_str() == address;
+        let received = to.is_some_and(|to| to.as_str() == address);
+
+        match (sent, received) {
+            (true, true) => Direction::SelfTransfer,
+            (true, false) => Direction::Out,
+            (false, true) => Direction::In,
+            (false, false) => Direction::Unrelated,
+        }
+    }
+}
+
+/// Kind of an [`ActivityEvent`], also used to select what an activity query fetches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    /// Normal transaction (`txlist`)
+    Transaction,
+    /// Internal transaction (`txlistinternal`)
+    InternalTransaction,
+    /// ERC-20 transfer (`tokentx`)
+    Erc20Transfer,
+    /// ERC-721 transfer (`tokennfttx`)
+    Erc721Transfer,
+    /// ERC-1155 transfer (`token1155tx`)
+    Erc1155Transfer,
+    /// Beacon chain withdrawal (`beaconwithdrawal`)
+    BeaconWithdrawal,
+}
+
+impl ActivityKind {
+    /// All kinds, in the order events of one transaction are listed
+    pub const ALL: [ActivityKind; 6] = [
+        ActivityKind::Transaction,
+        ActivityKind::InternalTransaction,
+        ActivityKind::Erc20Transfer,
+        ActivityKind::Erc721Transfer,
+        ActivityKind::Erc1155Transfer,
+        ActivityKind::BeaconWithdrawal,
+    ];
+
+    /// Name of the kind, as used in serialized events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityKind::Transaction => "transaction",
+            ActivityKind::InternalTransaction => "internal_transaction",
+            ActivityKind::Erc20Transfer => "erc20_transfer",
+            ActivityKind::Erc721Transfer => "erc721_transfer",
+            ActivityKind::Erc1155Transfer => "erc1155_transfer",
+            ActivityKind::BeaconWithdrawal => "beacon_withdrawal",
+        }
+    }
+}
+
+/// A single entry of an address's activity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ActivityEvent {
+    /// Normal transaction sent or received by the address
+    Transaction {
+        /// Direction relative to the address
+        direction: Direction,
+        /// The transaction
+        transaction: Transaction,
+    },
+    /// Internal transaction involving the address
+    InternalTransaction {
+        /// Direction relative to the address
+        direction: Direction,
+        /// The internal transaction
+        transaction: InternalTransaction,
+    },
+    /// ERC-20 transfer involving the address
+    Erc20Transfer {
+        /// Direction relative to the address
+        direction: Direction,
+        /// The transfer
+        transfer: TokenTransfer,
+    },
+    /// ERC-721 transfer involving the address
+    Erc721Transfer {
+        /// Direction relative to the address
+        direction: Direction,
+        /// The transfer
+        transfer: TokenTransfer,
+    },
+    /// ERC-1155 transfer involving the address
+    Erc1155Transfer {
+        /// Direction relative to the address
+        direction: Direction,
+        /// The transfer
+        transfer: TokenTransfer,
+    },
+    /// Beacon chain withdrawal credited to the address
+    BeaconWithdrawal {
+        /// The withdrawal
+        withdrawal: BeaconWithdrawal,
+    },
+}
+
+impl ActivityEvent {
+    /// Get the kind of the event
+    pub fn kind(&self) -> ActivityKind {
+        match self {
+            ActivityEvent::Transaction { .. } => ActivityKind::Transaction,
+            ActivityEvent::InternalTransaction { .. } => ActivityKind::InternalTransaction,
+            ActivityEvent::Erc20Transfer { .. } => ActivityKind::Erc20Transfer,
+            ActivityEvent::Erc721Transfer { .. } => ActivityKind::Erc721Transfer,
+            ActivityEvent::Erc1155Transfer { .. } => ActivityKind::Erc1155Transfer,
+            ActivityEvent::BeaconWithdrawal { .. } => ActivityKind::BeaconWithdrawal,
+        }
+    }
+
+    /// Get the direction relative to the queried address
+    ///
+    /// Beacon withdrawals are always incoming.
+    pub fn direction(&self) -> Direction {
+        match self {
+            ActivityEvent::Transaction { direction, .. }
+            | ActivityEvent::InternalTransaction { direction, .. }
+            | ActivityEvent::Erc20Transfer { direction, .. }
+            | ActivityEvent::Erc721Transfer { direction, .. }
+            | ActivityEvent::Erc1155Transfer { direction, .. } => *direction,
+            ActivityEvent::BeaconWithdrawal { .. } => Direction::In,
+        }
+    }
+
+    /// Get the hash of the transaction the event belongs to
+    ///
+    /// Beacon withdrawals are not part of a transaction.
+    pub fn tx_hash(&self) -> Option<&TxHash> {
+        match self {
+            ActivityEvent::Transaction { transaction, .. } => Some(&transaction.hash),
+            ActivityEvent::InternalTransaction { transaction, .. } => transaction.hash.as_ref(),
+            ActivityEvent::Erc20Transfer { transfer, .. }
+            | ActivityEvent::Erc721Transfer { transfer, .. }
+            | ActivityEvent::Erc1155Transfer { transfer, .. } => Some(&transfer.hash),
+            ActivityEvent::BeaconWithdrawal { .. } => None,
+        }
+    }
+
+    /// Get a key identifying the event
+    ///
+    /// The key includes the block number, so an event that moves to another
+    /// block in a reorg gets a new key.
+    pub fn id(&self) -> String {
+        let details = match self {
+            ActivityEvent::Transaction { transaction, .. } => transaction.hash.to_string(),
+            ActivityEvent::InternalTransaction { transaction, .. } => format!(
+                "{}:{}:{}:{}:{}",
+                transaction
+                    .hash
+                    .as_ref()
+                    .map(TxHash::as_str)
+                    .unwrap_or_default(),
+                transaction.trace_id.as_deref().unwrap_or_default(),
+                transaction.from,
+                transaction
+                    .to
+                    .as_ref()
+                    .map(Address::as_str)
+                    .unwrap_or_default(),
+                transaction.value
+            ),
+            ActivityEvent::Erc20Transfer { transfer, .. }
+            | ActivityEvent::Erc721Transfer { transfer, .. }
+            | ActivityEvent::Erc1155Transfer { transfer, .. } => format!(
+                "{}:{}:{}:{}:{}:{}:{}",
+                transfer.hash,
+                transfer.log_index.value(),
+                transfer.contract_address,
+                transfer.nft_token_id().unwrap_or_default(),
+                transfer.from,
+                transfer.to,
+                transfer.value
+            ),
+            ActivityEvent::BeaconWithdrawal { withdrawal } => withdrawal.index().to_string(),
+        };
+        format!("{}:{}:{}", self.kind().as_str(), self.block(), details)
+    }
+
+    /// Get block number as u64
+    pub fn block(&self) -> u64 {
+        match self {
+            ActivityEvent::Transaction { transaction, .. } => transaction.block(),
+            ActivityEvent::InternalTransaction { transaction, .. } => transaction.block(),
+            ActivityEvent::Erc20Transfer { transfer, .. }
+            | ActivityEvent::Erc721Transfer { transfer, .. }
+            | ActivityEvent::Erc1155Transfer { transfer, .. } => transfer.block(),
+            ActivityEvent::BeaconWithdrawal { withdrawal } => withdrawal.block(),
+        }
+    }
+
+    /// Get timestamp as u64
+    pub fn timestamp_value(&self) -> u64 {
+        match self {
+            ActivityEvent::Transaction { transaction, .. } => transaction.timestamp.value(),
+            ActivityEvent::InternalTransaction { transaction, .. } => transaction.timestamp.value(),
+            ActivityEvent::Erc20Transfer { transfer, .. }
+            | ActivityEvent::Erc721Transfer { transfer, .. }
+            | ActivityEvent::Erc1155Transfer { transfer, .. } => transfer.timestamp.value(),
+            ActivityEvent::BeaconWithdrawal { withdrawal } => withdrawal.timestamp_value(),
+        }
+    }
+}
+
+impl BlockchainData for ActivityEvent {
+    fn block_number(&self) -> Option<u64> {
+        Some(self.block())
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        Some(self.timestamp_value())
+    }
+}
+
+/// Events of an address that belong to the same transaction
+///
+/// Beacon withdrawals have no transaction and form a group of their own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityGroup {
+    /// Hash of the transaction, `None` for beacon withdrawals
+    pub tx_hash: Option<TxHash>,
+    /// Block the transaction was mined in
+    pub block_number: u64,
+    /// Timestamp of the block
+    pub timestamp: Timestamp,
+    /// Events ordered by kind (transaction first)
+    pub events: Vec<ActivityEvent>,
+}
+
+impl ActivityGroup {
+    /// Get the direction of the group
+    ///
+    /// This is the direction of the normal transaction when the address sent
+    /// or received one, otherwise that of the first event.
+    pub fn direction(&self) -> Direction {
+        self.events
+            .iter()
+            .find(|event| event.kind() == ActivityKind::Transaction)
+            .or_else(|| self.events.first())
+            .map(ActivityEvent::direction)
+            .unwrap_or(Direction::In)
+    }
+
+    /// Check whether the group contains an event of the given kind
+    pub fn contains(&self, kind: ActivityKind) -> bool {
+        self.events.iter().any(|event| event.kind() == kind)
+    }
+}
+
+impl BlockchainData for ActivityGroup {
+    fn block_number(&self) -> Option<u64> {
+        Some(self.block_number)
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        Some(self.timestamp.value())
+    }
+}
+
+/// Group events by transaction hash and order the groups chronologically
+#[cfg_attr(not(feature = "accounts"), allow(dead_code))]
+pub(crate) fn timeline(events: Vec<ActivityEvent>, sort: Sort) -> Vec<ActivityGroup> {
+    let mut groups: Vec<ActivityGroup> = Vec::new();
+    let mut by_hash: HashMap<TxHash, usize> = HashMap::new();
+
+    for event in events {
+        let existing = event.tx_hash().and_then(|hash| by_hash.get(hash).copied());
+        match existing {
+            Some(index) => groups[index].events.push(event),
+            None => {
+                if let Some(hash) = event.tx_hash() {
+                    by_hash.insert(hash.clone(), groups.len());
+                }
+                groups.push(ActivityGroup {
+                    tx_hash: event.tx_hash().cloned(),
+                    block_number: event.block(),
+                    timestamp: Timestamp::from(event.timestamp_value()),
+                    events: vec![event],
+                });
+            }
+        }
+    }
+
+    for group in &mut groups {
+        group.events.sort_by_key(ActivityEvent::kind);
+    }
+
+    // Stable sorts keep the order the API returned within a block
+    groups.sort_by_key(|group| group.block_number);
+    if sort == Sort::Descending {
+        groups.reverse();
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BigNumber, StringNumber, Timestamp};
+
+    const ADDRESS: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+    const OTHER: &str = "0x1234567890123456789012345678901234567890";
+
+    fn withdrawal(block: u64) -> BeaconWithdrawal {
+        BeaconWithdrawal {
+            withdrawal_index: StringNumber::from(block),
+            validator_index: StringNumber::from(1),
+            address: Address::new(ADDRESS),
+            amount: BigNumber::from("32000000000".to_string()),
+            block_number: StringNumber::from(block),
+            timestamp: Timestamp::from(1_700_000_000 + block),
+        }
+    }
+
+    fn internal(hash: &str, block: u64, from: &str, to: &str) -> InternalTransaction {
+        serde_json::from_value(serde_json::json!({
+            "blockNumber": block.to_string(),
+            "timeStamp": (1_700_000_000 + block).to_string(),
+            "hash": hash,
+            "from": from,
+            "to": to,
+            "value": "1",
+            "contractAddress": "",
+            "input": "",
+            "type": "call",
+            "gas": "0",
+            "gasUsed": "0",
+            "traceId": "0",
+            "isError": "0",
+            "errCode": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_direction() {
+        let address = Address::new(ADDRESS);
+        let other = Address::new(OTHER);
+
+        assert_eq!(
+            Direction::of(ADDRESS, &address, Some(&other)),
+            Direction::Out
+        );
+        assert_eq!(
+            Direction::of(ADDRESS, &other, Some(&address)),
+            Direction::In
+        );
+        assert_eq!(
+            Direction::of(ADDRESS, &address, Some(&address)),
+            Direction::SelfTransfer
+        );
+        assert_eq!(Direction::of(ADDRESS, &address, None), Direction::Out);
+        assert_eq!(
+            Direction::of(ADDRESS, &other, Some(&other)),
+            Direction::Unrelated
+        );
+        assert_eq!(Direction::of(ADDRESS, &other, None), Direction::Unrelated);
+    }
+
+    #[test]
+    fn test_timeline_groups_by_hash() {
+        let hash_a = format!("0x{:064x}", 0xa);
+        let hash_b = format!("0x{:064x}", 0xb);
+
+        let events = vec![
+            ActivityEvent::InternalTransaction {
+                direction: Direction::In,
+                transaction: internal(&hash_b, 20, OTHER, ADDRESS),
+            },
+            ActivityEvent::BeaconWithdrawal {
+                withdrawal: withdrawal(15),
+            },
+            ActivityEvent::InternalTransaction {
+                direction: Direction::In,
+                transaction: internal(&hash_a, 10, OTHER, ADDRESS),
+            },
+            ActivityEvent::InternalTransaction {
+                direction: Direction::Out,
+                transaction: internal(&hash_b, 20, ADDRESS, OTHER),
+            },
+        ];
+
+        let groups = timeline(events.clone(), Sort::Ascending);
+        assert_eq!(
+            groups.iter().map(|g| g.block_number).collect::<Vec<_>>(),
+            vec![10, 15, 20]
+        );
+        assert_eq!(groups[1].tx_hash, None);
+        assert_eq!(groups[2].events.len(), 2);
+        assert_eq!(groups[2].direction(), Direction::In);
+        assert!(groups[2].contains(ActivityKind::InternalTransaction));
+
+        let descending = timeline(events, Sort::Descending);
+        assert_eq!(descending[0].block_number, 20);
+    }
+}
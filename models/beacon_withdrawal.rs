@@ -1,4 +1,4 @@
-use crate::models::{Address, BigNumber, BlockchainData, StringNumber};
+use crate::models::{Address, BigNumber, BlockchainData, StringNumber, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// Beacon chain withdrawal event for a validator
@@ -24,7 +24,7 @@ pub struct BeaconWithdrawal {
 
     /// Timestamp of the withdrawal
     #[serde(rename = "timestamp")]
-    pub timestamp: StringNumber,
+    pub timestamp: Timestamp,
 }
 
 impl BeaconWithdrawal {
@@ -59,10 +59,12 @@ impl BeaconWithdrawal {
         self.amount.as_u128().map(|gwei| gwei as f64 / 1e9)
     }
 
-    /// Get withdrawal amount in Wei
+    /// Get withdrawal amount in Wei, `None` if it does not parse or overflows
     pub fn amount_wei(&self) -> Option<u128> {
         // Convert from Gwei to Wei (1 Gwei = 1e9 Wei)
-        self.amount.as_u128().map(|gwei| gwei * 1_000_000_000)
+        self.amount
+            .as_u128()
+            .and_then(|gwei| gwei.checked_mul(1_000_000_000))
     }
 }
 
@@ -108,7 +110,7 @@ mod tests {
             address: Address::new("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6"),
             amount: BigNumber::from("32000000000".to_string()), // 32 ETH in Gwei
             block_number: StringNumber::from(17000000),
-            timestamp: StringNumber::from(1681228800),
+            timestamp: Timestamp::from(1681228800),
         };
 
         // Test Gwei amount
@@ -130,7 +132,7 @@ mod tests {
             address: Address::new("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6"),
             amount: BigNumber::from("1234567890".to_string()), // ~1.23 ETH in Gwei
             block_number: StringNumber::from(17500000),
-            timestamp: StringNumber::from(1690000000),
+            timestamp: Timestamp::from(1690000000),
         };
 
         // Should be approximately 1.23456789 ETH
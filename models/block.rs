@@ -1,19 +1,65 @@
-use crate::models::{BlockchainData, StringNumber};
+use crate::models::{Address, BlockchainData, HexNumber, HexQuantity};
 use serde::{Deserialize, Serialize};
 
-/// Placeholder for block-related models
+/// Block header as returned by `eth_getBlockByNumber`
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Block {
-    /// TODO: Implement block model
-    pub number: StringNumber,
+    /// Block number
+    pub number: HexNumber,
+
+    /// Block hash
+    pub hash: String,
+
+    /// Parent block hash
+    #[serde(rename = "parentHash")]
+    pub parent_hash: String,
+
+    /// Block timestamp (Unix seconds)
+    pub timestamp: HexNumber,
+
+    /// Fee recipient of the block
+    pub miner: Address,
+
+    /// Total gas used by all transactions in the block
+    #[serde(rename = "gasUsed")]
+    pub gas_used: HexNumber,
+
+    /// Block gas limit
+    #[serde(rename = "gasLimit")]
+    pub gas_limit: HexNumber,
+
+    /// EIP-1559 base fee per gas (absent before London)
+    #[serde(rename = "baseFeePerGas", default)]
+    pub base_fee_per_gas: Option<HexQuantity>,
+
+    /// Hashes of the transactions in the block
+    #[serde(default)]
+    pub transactions: Vec<String>,
+}
+
+impl Block {
+    /// Get block number as u64
+    pub fn block(&self) -> u64 {
+        self.number.value()
+    }
+
+    /// Get timestamp as u64
+    pub fn timestamp_value(&self) -> u64 {
+        self.timestamp.value()
+    }
+
+    /// Get base fee per gas in wei
+    pub fn base_fee(&self) -> Option<u128> {
+        self.base_fee_per_gas.map(|fee| fee.value())
+    }
 }
 
 impl BlockchainData for Block {
     fn block_number(&self) -> Option<u64> {
-        Some(self.number.value())
+        Some(self.block())
     }
 
     fn timestamp(&self) -> Option<u64> {
-        None
+        Some(self.timestamp_value())
     }
 }
@@ -2,13 +2,36 @@ use crate::models::{Address, StringNumber, TxHash};
 use serde::{Deserialize, Serialize};
 
 /// Contract ABI representation
+///
+/// The `getabi` action returns the ABI as a bare JSON string; the object form
+/// `{"ABI": "..."}` is accepted as well.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ContractAbiRepr")]
 pub struct ContractAbi {
     /// JSON string of the ABI
     #[serde(rename = "ABI")]
     pub abi: String,
 }
 
+/// Wire formats accepted for [`ContractAbi`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ContractAbiRepr {
+    Bare(String),
+    Object {
+        #[serde(rename = "ABI")]
+        abi: String,
+    },
+}
+
+impl From<ContractAbiRepr> for ContractAbi {
+    fn from(repr: ContractAbiRepr) -> Self {
+        match repr {
+            ContractAbiRepr::Bare(abi) | ContractAbiRepr::Object { abi } => ContractAbi { abi },
+        }
+    }
+}
+
 impl ContractAbi {
     /// Parse the ABI JSON string into a JSON value
     pub fn parse_abi(&self) -> serde_json::Result<serde_json::Value> {
@@ -22,7 +45,7 @@ impl ContractAbi {
 }
 
 /// Contract source code information
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractSource {
     /// The source code of the contract
     #[serde(rename = "SourceCode")]
@@ -105,7 +128,7 @@ impl ContractSource {
 }
 
 /// Contract creation information
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractCreation {
     /// The contract address
     #[serde(rename = "contractAddress")]
@@ -121,12 +144,34 @@ pub struct ContractCreation {
 }
 
 /// Verification status response
-#[derive(Debug, Clone, Deserialize)]
+///
+/// `checkverifystatus` returns the status as a bare string; the object form
+/// `{"status": "..."}` is accepted as well.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "VerificationStatusRepr")]
 pub struct VerificationStatus {
     /// Status message from Etherscan
     pub status: String,
 }
 
+/// Wire formats accepted for [`VerificationStatus`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VerificationStatusRepr {
+    Bare(String),
+    Object { status: String },
+}
+
+impl From<VerificationStatusRepr> for VerificationStatus {
+    fn from(repr: VerificationStatusRepr) -> Self {
+        match repr {
+            VerificationStatusRepr::Bare(status) | VerificationStatusRepr::Object { status } => {
+                VerificationStatus { status }
+            }
+        }
+    }
+}
+
 impl VerificationStatus {
     /// Check if verification was successful
     pub fn is_verified(&self) -> bool {
@@ -145,12 +190,33 @@ impl VerificationStatus {
 }
 
 /// Proxy verification status response
-#[derive(Debug, Clone, Deserialize)]
+///
+/// `checkproxyverification` returns the message as a bare string; the object
+/// form `{"result": "..."}` is accepted as well.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ProxyVerificationStatusRepr")]
 pub struct ProxyVerificationStatus {
     /// Result message from Etherscan
     pub result: String,
 }
 
+/// Wire formats accepted for [`ProxyVerificationStatus`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProxyVerificationStatusRepr {
+    Bare(String),
+    Object { result: String },
+}
+
+impl From<ProxyVerificationStatusRepr> for ProxyVerificationStatus {
+    fn from(repr: ProxyVerificationStatusRepr) -> Self {
+        match repr {
+            ProxyVerificationStatusRepr::Bare(result)
+            | ProxyVerificationStatusRepr::Object { result } => ProxyVerificationStatus { result },
+        }
+    }
+}
+
 impl ProxyVerificationStatus {
     /// Check if proxy verification was successful
     pub fn is_verified(&self) -> bool {
@@ -159,14 +225,39 @@ impl ProxyVerificationStatus {
 }
 
 /// Verification request response containing GUID for tracking
-#[derive(Debug, Clone, Deserialize)]
+///
+/// The verification actions return the GUID as a bare string; the object
+/// form `{"guid": "..."}` is accepted as well.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "VerificationRequestRepr")]
 pub struct VerificationRequest {
     /// GUID for tracking verification status
     pub guid: String,
 }
 
+/// Wire formats accepted for [`VerificationRequest`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VerificationRequestRepr {
+    Bare(String),
+    Object { guid: String },
+}
+
+impl From<VerificationRequestRepr> for VerificationRequest {
+    fn from(repr: VerificationRequestRepr) -> Self {
+        match repr {
+            VerificationRequestRepr::Bare(guid) | VerificationRequestRepr::Object { guid } => {
+                VerificationRequest { guid }
+            }
+        }
+    }
+}
+
 /// Code format types for verification
-#[derive(Debug, Clone, Copy)]
+///
+/// Serialized with the same names as [`as_str`](CodeFormat::as_str).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum CodeFormat {
     /// Single Solidity file
     SoliditySingleFile,
@@ -188,7 +279,7 @@ impl CodeFormat {
 }
 
 /// Optimization settings for contract verification
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OptimizationSettings {
     /// Whether optimization is enabled
     pub enabled: bool,
@@ -215,7 +306,7 @@ impl OptimizationSettings {
 }
 
 /// Library linking information for contract verification
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LibraryLink {
     /// Library name
     pub name: String,
@@ -0,0 +1,290 @@
+use crate::models::{
+    hex_to_decimal, Address, BigNumber, BlockchainData, InternalTransaction, RpcTransaction,
+    Timestamp, TransactionLog, TransactionReceipt, TxHash,
+};
+use serde::{Deserialize, Serialize};
+
+/// `Transfer(address,address,uint256)`, shared by ERC-20 and ERC-721
+pub const TRANSFER_TOPIC: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+/// ERC-1155 `TransferSingle(address,address,address,uint256,uint256)`
+pub const TRANSFER_SINGLE_TOPIC: &str =
+    "0xc3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62";
+
+/// ERC-1155 `TransferBatch(address,address,address,uint256[],uint256[])`
+pub const TRANSFER_BATCH_TOPIC: &str =
+    "0x4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb";
+
+/// Token standard of a decoded transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStandard {
+    /// Fungible token
+    Erc20,
+    /// Non-fungible token
+    Erc721,
+    /// Multi-token
+    Erc1155,
+}
+
+impl TokenStandard {
+    /// Lowercase name of the standard, as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenStandard::Erc20 => "erc20",
+            TokenStandard::Erc721 => "erc721",
+            TokenStandard::Erc1155 => "erc1155",
+        }
+    }
+}
+
+/// Token transfer decoded from a receipt log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedTransfer {
+    /// Token standard inferred from the event
+    pub standard: TokenStandard,
+
+    /// Token contract that emitted the event
+    pub contract_address: Address,
+
+    /// Sender
+    pub from: Address,
+
+    /// Recipient
+    pub to: Address,
+
+    /// Token ID (ERC-721 and ERC-1155)
+    pub token_id: Option<BigNumber>,
+
+    /// Raw amount transferred (always 1 for ERC-721)
+    pub value: BigNumber,
+
+    /// Index of the log in the block
+    pub log_index: u64,
+}
+
+impl DecodedTransfer {
+    /// Decode the token transfers carried by a log, if it is a transfer event
+    ///
+    /// An ERC-1155 `TransferBatch` yields one transfer per token ID.
+    pub fn from_log(log: &TransactionLog) -> Vec<Self> {
+        Self::decode(log).unwrap_or_default()
+    }
+
+    fn decode(log: &TransactionLog) -> Option<Vec<Self>> {
+        let topic = log.event_signature()?.to_lowercase();
+        let words = data_words(&log.data)?;
+        let transfer = |standard, from: &str, to: &str, token_id: Option<&str>, value: &str| {
+            Some(DecodedTransfer {
+                standard,
+                contract_address: log.address.clone(),
+                from: topic_address(from)?,
+                to: topic_address(to)?,
+                token_id: match token_id {
+                    Some(id) => Some(BigNumber::from(hex_to_decimal(id)?)),
+                    None => None,
+                },
+                value: BigNumber::from(hex_to_decimal(value)?),
+                log_index: log.log_index.value(),
+            })
+        };
+
+        match (topic.as_str(), log.topics.as_slice()) {
+            // ERC-20 keeps the amount in data, ERC-721 indexes the token ID
+            (TRANSFER_TOPIC, [_, from, to]) => Some(vec![transfer(
+                TokenStandard::Erc20,
+                from,
+                to,
+                None,
+                words.first()?,
+            )?]),
+            (TRANSFER_TOPIC, [_, from, to, id]) => Some(vec![transfer(
+                TokenStandard::Erc721,
+                from,
+                to,
+                Some(id),
+                "0x1",
+            )?]),
+            (TRANSFER_SINGLE_TOPIC, [_, _, from, to]) => Some(vec![transfer(
+                TokenStandard::Erc1155,
+                from,
+                to,
+                Some(words.first()?),
+                words.get(1)?,
+            )?]),
+            (TRANSFER_BATCH_TOPIC, [_, _, from, to]) => {
+                let ids = abi_array(&words, words.first()?)?;
+                let values = abi_array(&words, words.get(1)?)?;
+                if ids.len() != values.len() {
+                    return None;
+                }
+                ids.iter()
+                    .zip(values)
+                    .map(|(id, value)| transfer(TokenStandard::Erc1155, from, to, Some(id), value))
+                    .collect()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Split ABI-encoded log data into 32-byte hex words
+fn data_words(data: &str) -> Option<Vec<&str>> {
+    let data = data.strip_prefix("0x").unwrap_or(data);
+    if !data.len().is_multiple_of(64) || !data.is_ascii() {
+        return None;
+    }
+    Some(
+        (0..data.len())
+            .step_by(64)
+            .map(|start| &data[start..start + 64])
+            .collect(),
+    )
+}
+
+/// Read a dynamic `uint256[]` whose byte offset is stored in `offset`
+fn abi_array<'a>(words: &'a [&'a str], offset: &str) -> Option<&'a [&'a str]> {
+    let start = word_to_usize(offset)? / 32;
+    let len = word_to_usize(words.get(start)?)?;
+    words.get(start + 1..start.checked_add(len)?.checked_add(1)?)
+}
+
+fn word_to_usize(word: &str) -> Option<usize> {
+    match word.trim_start_matches('0') {
+        "" => Some(0),
+        digits => usize::from_str_radix(digits, 16).ok(),
+    }
+}
+
+/// Extract the address from an indexed topic (the low 20 bytes)
+fn topic_address(topic: &str) -> Option<Address> {
+    let topic = topic.strip_prefix("0x").unwrap_or(topic);
+    let address = topic.get(topic.len().checked_sub(40)?..)?;
+    Some(Address::new(format!("0x{}", address)))
+}
+
+/// Execution outcome of a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum ExecutionStatus {
+    /// Not yet mined
+    Pending,
+    /// Mined and executed successfully
+    Success,
+    /// Mined but reverted
+    Failed {
+        /// Revert reason, when Etherscan reports one
+        reason: Option<String>,
+    },
+}
+
+/// Everything known about a single transaction, assembled from several calls
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionDetails {
+    /// The transaction itself
+    pub transaction: RpcTransaction,
+
+    /// Receipt with logs (absent while pending)
+    pub receipt: Option<TransactionReceipt>,
+
+    /// Internal transactions (traces) triggered by the transaction
+    pub internal_transactions: Vec<InternalTransaction>,
+
+    /// Token transfers decoded from the receipt logs
+    pub token_transfers: Vec<DecodedTransfer>,
+
+    /// Execution status
+    pub status: ExecutionStatus,
+
+    /// Timestamp of the containing block (absent while pending)
+    pub timestamp: Option<Timestamp>,
+
+    /// Price per gas actually paid, in wei
+    pub effective_gas_price: Option<u128>,
+
+    /// Total fee paid (gas used × effective gas price), in wei
+    pub fee: Option<u128>,
+}
+
+impl TransactionDetails {
+    /// Assemble the details from the individual responses
+    pub fn new(
+        transaction: RpcTransaction,
+        receipt: Option<TransactionReceipt>,
+        internal_transactions: Vec<InternalTransaction>,
+        timestamp: Option<Timestamp>,
+        revert_reason: Option<String>,
+    ) -> Self {
+        let status = match &receipt {
+            None => ExecutionStatus::Pending,
+            Some(receipt) if receipt.is_successful() => ExecutionStatus::Success,
+            Some(_) => ExecutionStatus::Failed {
+                reason: revert_reason,
+            },
+        };
+        let token_transfers = receipt
+            .iter()
+            .flat_map(|receipt| receipt.logs.iter().flat_map(DecodedTransfer::from_log))
+            .collect();
+        // Receipts from before London may lack effectiveGasPrice; gasPrice is
+        // what legacy transactions paid
+        let effective_gas_price = receipt.as_ref().and_then(|receipt| {
+            receipt
+                .effective_gas_price_wei()
+                .or(transaction.gas_price.map(|price| price.value()))
+        });
+        let fee = receipt
+            .as_ref()
+            .zip(effective_gas_price)
+            .and_then(|(receipt, price)| u128::from(receipt.gas_used_amount()).checked_mul(price));
+
+        Self {
+            transaction,
+            receipt,
+            internal_transactions,
+            token_transfers,
+            status,
+            timestamp,
+            effective_gas_price,
+            fee,
+        }
+    }
+
+    /// Get the transaction hash
+    pub fn hash(&self) -> &TxHash {
+        &self.transaction.hash
+    }
+
+    /// Check if the transaction is still waiting to be mined
+    pub fn is_pending(&self) -> bool {
+        self.status == ExecutionStatus::Pending
+    }
+
+    /// Check if the transaction executed successfully
+    pub fn is_successful(&self) -> bool {
+        self.status == ExecutionStatus::Success
+    }
+
+    /// Get the gas used, once mined
+    pub fn gas_used(&self) -> Option<u64> {
+        self.receipt
+            .as_ref()
+            .map(TransactionReceipt::gas_used_amount)
+    }
+
+    /// Get the total fee in ETH
+    pub fn fee_eth(&self) -> Option<f64> {
+        self.fee.map(|wei| wei as f64 / 1e18)
+    }
+}
+
+impl BlockchainData for TransactionDetails {
+    fn block_number(&self) -> Option<u64> {
+        self.transaction.block()
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        self.timestamp.as_ref().map(Timestamp::value)
+    }
+}
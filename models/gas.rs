@@ -1,8 +1,75 @@
+use crate::models::StringNumber;
 use serde::{Deserialize, Serialize};
 
-/// Placeholder for gas-related models
+/// Gas price suggestions from the gas tracker
+///
+/// Prices are decimal amounts of gwei, e.g. `"0.482"`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct GasPrice {
-    /// TODO: Implement gas price model
-    pub price: String,
+pub struct GasOracle {
+    /// Block the suggestions are based on
+    #[serde(rename = "LastBlock")]
+    pub last_block: StringNumber,
+
+    /// Price for a slow transaction
+    #[serde(rename = "SafeGasPrice")]
+    pub safe_gas_price: String,
+
+    /// Price for a standard transaction
+    #[serde(rename = "ProposeGasPrice")]
+    pub propose_gas_price: String,
+
+    /// Price for a fast transaction
+    #[serde(rename = "FastGasPrice")]
+    pub fast_gas_price: String,
+
+    /// Base fee of the next block
+    #[serde(rename = "suggestBaseFee", default)]
+    pub suggest_base_fee: Option<String>,
+
+    /// Gas used ratios of the last five blocks, comma separated
+    #[serde(rename = "gasUsedRatio", default)]
+    pub gas_used_ratio: Option<String>,
+}
+
+impl GasOracle {
+    /// Get the block the suggestions are based on
+    pub fn block(&self) -> u64 {
+        self.last_block.value()
+    }
+
+    /// Slow price in wei
+    pub fn safe_wei(&self) -> Option<u128> {
+        gwei_to_wei(&self.safe_gas_price)
+    }
+
+    /// Standard price in wei
+    pub fn propose_wei(&self) -> Option<u128> {
+        gwei_to_wei(&self.propose_gas_price)
+    }
+
+    /// Fast price in wei
+    pub fn fast_wei(&self) -> Option<u128> {
+        gwei_to_wei(&self.fast_gas_price)
+    }
+
+    /// Next block's base fee in wei
+    pub fn base_fee_wei(&self) -> Option<u128> {
+        self.suggest_base_fee.as_deref().and_then(gwei_to_wei)
+    }
+}
+
+/// Convert a decimal amount of gwei to wei without rounding
+fn gwei_to_wei(gwei: &str) -> Option<u128> {
+    let (whole, fraction) = gwei.trim().split_once('.').unwrap_or((gwei.trim(), ""));
+    if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let whole: u128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: u128 = format!("{:0<9}", fraction).parse().ok()?;
+    whole.checked_mul(1_000_000_000)?.checked_add(fraction)
 }
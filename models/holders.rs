@@ -0,0 +1,161 @@
+use super::ledger::format_magnitude;
+use crate::models::{Address, TokenTransfer};
+use crate::{EtherscanError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// The zero address, source of mints
+pub const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// The conventional burn address
+pub const DEAD_ADDRESS: &str = "0x000000000000000000000000000000000000dead";
+
+/// A token holder and their balance in a [`HolderSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Holder {
+    /// Holder address
+    pub address: Address,
+
+    /// Balance in base units, serialized as a decimal string
+    #[serde(with = "amount_string")]
+    pub balance: u128,
+}
+
+/// Every holder of a token and their balance at a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HolderSnapshot {
+    /// Token contract address
+    pub contract_address: Address,
+
+    /// Block the snapshot was taken at (inclusive)
+    pub block_number: u64,
+
+    /// Token decimals
+    pub decimals: u32,
+
+    /// Holders with a positive balance, largest first
+    pub holders: Vec<Holder>,
+}
+
+impl HolderSnapshot {
+    /// Replay `transfers` of a token up to `block_number` into a holder map
+    ///
+    /// Transfers after the block are ignored, as are addresses in `excluded`.
+    /// Fails with [`EtherscanError::Parse`] if an amount or balance overflows
+    /// an `i128`.
+    pub fn new<S: AsRef<str>>(
+        contract_address: S,
+        block_number: u64,
+        transfers: &[TokenTransfer],
+        excluded: &[String],
+    ) -> Result<Self> {
+        let contract_address = Address::new(contract_address.as_ref());
+        let excluded: HashSet<String> = excluded.iter().map(|a| a.to_lowercase()).collect();
+        let transfers: Vec<&TokenTransfer> = transfers
+            .iter()
+            .filter(|t| t.contract_address == contract_address && t.block() <= block_number)
+            .collect();
+
+        let mut balances: HashMap<&str, i128> = HashMap::new();
+        for transfer in &transfers {
+            let overflow = || {
+                EtherscanError::parse(format!(
+                    "Amount {} of transfer {} overflows a balance",
+                    transfer.value, transfer.hash
+                ))
+            };
+            let amount = transfer
+                .value
+                .as_u128()
+                .and_then(|amount| i128::try_from(amount).ok())
+                .ok_or_else(overflow)?;
+            let from = balances.entry(transfer.from.as_str()).or_default();
+            *from = from.checked_sub(amount).ok_or_else(overflow)?;
+            let to = balances.entry(transfer.to.as_str()).or_default();
+            *to = to.checked_add(amount).ok_or_else(overflow)?;
+        }
+
+        let mut holders: Vec<Holder> = balances
+            .into_iter()
+            .filter(|(address, balance)| *balance > 0 && !excluded.contains(*address))
+            .map(|(address, balance)| Holder {
+                address: Address::new(address),
+                balance: balance.unsigned_abs(),
+            })
+            .collect();
+        holders.sort_by(|a, b| {
+            b.balance
+                .cmp(&a.balance)
+                .then(a.address.0.cmp(&b.address.0))
+        });
+
+        Ok(Self {
+            contract_address,
+            block_number,
+            decimals: transfers
+                .first()
+                .map_or(0, |t| u32::try_from(t.decimals()).unwrap_or(u32::MAX)),
+            holders,
+        })
+    }
+
+    /// Get the number of holders
+    pub fn len(&self) -> usize {
+        self.holders.len()
+    }
+
+    /// Check if nobody holds the token
+    pub fn is_empty(&self) -> bool {
+        self.holders.is_empty()
+    }
+
+    /// Get the balance of an address (zero if it holds nothing or is excluded)
+    pub fn balance_of<S: AsRef<str>>(&self, address: S) -> u128 {
+        let address = address.as_ref().to_lowercase();
+        self.holders
+            .iter()
+            .find(|holder| holder.address.as_str() == address)
+            .map_or(0, |holder| holder.balance)
+    }
+
+    /// Get the sum of all holder balances, in base units
+    pub fn total(&self) -> u128 {
+        self.holders
+            .iter()
+            .fold(0u128, |total, holder| total.saturating_add(holder.balance))
+    }
+
+    /// Export as CSV with `address,balance,amount` rows
+    ///
+    /// `balance` is in base units and `amount` is the exact decimal amount.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("address,balance,amount\n");
+        for holder in &self.holders {
+            let amount = format_magnitude(false, holder.balance, self.decimals);
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                holder.address, holder.balance, amount
+            ));
+        }
+        csv
+    }
+
+    /// Export as pretty-printed JSON, with balances as decimal strings
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+mod amount_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
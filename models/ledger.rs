@@ -0,0 +1,338 @@
+use crate::models::{Address, TokenTransfer};
+use crate::{EtherscanError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Format a raw token amount with `decimals` places, exactly
+///
+/// Trailing zeros of the fraction are dropped, so `format_units(1_500, 3)` is
+/// `"1.5"` and `format_units(-2_000, 3)` is `"-2"`.
+pub fn format_units(amount: i128, decimals: u32) -> String {
+    format_magnitude(amount < 0, amount.unsigned_abs(), decimals)
+}
+
+pub(crate) fn format_magnitude(negative: bool, amount: u128, decimals: u32) -> String {
+    let digits = amount.to_string();
+    let decimals = decimals as usize;
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    let sign = if negative && amount != 0 { "-" } else { "" };
+
+    if fraction.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+/// Inflow and outflow of one ERC-20 token for an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenFlow {
+    /// Token contract address
+    pub contract_address: Address,
+
+    /// Token name
+    pub token_name: String,
+
+    /// Token symbol
+    pub token_symbol: String,
+
+    /// Token decimals
+    pub decimals: u32,
+
+    /// Total received, in base units
+    pub inflow: u128,
+
+    /// Total sent, in base units
+    pub outflow: u128,
+
+    /// Number of transfers counted
+    pub transfers: usize,
+}
+
+impl TokenFlow {
+    /// Get inflow minus outflow, in base units
+    ///
+    /// Returns `None` if the difference does not fit in an `i128`.
+    pub fn net(&self) -> Option<i128> {
+        i128::try_from(self.inflow)
+            .ok()?
+            .checked_sub(i128::try_from(self.outflow).ok()?)
+    }
+
+    /// Get the inflow as an exact decimal string
+    pub fn inflow_units(&self) -> String {
+        format_magnitude(false, self.inflow, self.decimals)
+    }
+
+    /// Get the outflow as an exact decimal string
+    pub fn outflow_units(&self) -> String {
+        format_magnitude(false, self.outflow, self.decimals)
+    }
+
+    /// Get the net flow as an exact decimal string
+    pub fn net_units(&self) -> String {
+        format_magnitude(
+            self.outflow > self.inflow,
+            self.inflow.abs_diff(self.outflow),
+            self.decimals,
+        )
+    }
+}
+
+/// ERC-721 token IDs of one collection currently held by an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NftHolding {
+    /// Collection contract address
+    pub contract_address: Address,
+
+    /// Collection name
+    pub token_name: String,
+
+    /// Collection symbol
+    pub token_symbol: String,
+
+    /// Held token IDs
+    pub token_ids: BTreeSet<String>,
+}
+
+/// ERC-1155 balance of one token ID held by an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Erc1155Balance {
+    /// Token contract address
+    pub contract_address: Address,
+
+    /// Token ID
+    pub token_id: String,
+
+    /// Token name
+    pub token_name: String,
+
+    /// Balance (negative if the transfer history is incomplete)
+    pub balance: i128,
+}
+
+/// Token holdings of an address, reconstructed from its transfer history
+///
+/// The ledger keeps the transfers in chain order, so [`at_block`](Self::at_block)
+/// can answer the same questions for any historical block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenLedger {
+    /// Address the ledger is kept for
+    pub address: Address,
+
+    /// ERC-20 transfers (`tokentx`)
+    pub erc20_transfers: Vec<TokenTransfer>,
+
+    /// ERC-721 transfers (`tokennfttx`)
+    pub nft_transfers: Vec<TokenTransfer>,
+
+    /// ERC-1155 transfers (`token1155tx`)
+    pub erc1155_transfers: Vec<TokenTransfer>,
+}
+
+impl TokenLedger {
+    /// Build a ledger from the transfer lists of an address
+    pub fn new<S: AsRef<str>>(
+        address: S,
+        mut erc20_transfers: Vec<TokenTransfer>,
+        mut nft_transfers: Vec<TokenTransfer>,
+        mut erc1155_transfers: Vec<TokenTransfer>,
+    ) -> Self {
+        for transfers in [
+            &mut erc20_transfers,
+            &mut nft_transfers,
+            &mut erc1155_transfers,
+        ] {
+            transfers.sort_by_key(|transfer| (transfer.block(), transfer.log_index.value()));
+        }
+
+        Self {
+            address: Address::new(address.as_ref()),
+            erc20_transfers,
+            nft_transfers,
+            erc1155_transfers,
+        }
+    }
+
+    /// Get the ledger as it stood after `block`
+    pub fn at_block(&self, block: u64) -> TokenLedger {
+        let until = |transfers: &[TokenTransfer]| {
+            transfers
+                .iter()
+                .take_while(|transfer| transfer.block() <= block)
+                .cloned()
+                .collect()
+        };
+
+        TokenLedger {
+            address: self.address.clone(),
+            erc20_transfers: until(&self.erc20_transfers),
+            nft_transfers: until(&self.nft_transfers),
+            erc1155_transfers: until(&self.erc1155_transfers),
+        }
+    }
+
+    /// Get the last block with a transfer
+    pub fn last_block(&self) -> Option<u64> {
+        [
+            &self.erc20_transfers,
+            &self.nft_transfers,
+            &self.erc1155_transfers,
+        ]
+        .iter()
+        .filter_map(|transfers| transfers.last().map(TokenTransfer::block))
+        .max()
+    }
+
+    /// Get the ERC-20 flows per token, ordered by contract address
+    ///
+    /// Fails with [`EtherscanError::Parse`] if an amount is not a `u128` or a
+    /// total overflows.
+    pub fn flows(&self) -> Result<Vec<TokenFlow>> {
+        let mut flows: BTreeMap<&str, TokenFlow> = BTreeMap::new();
+        for transfer in &self.erc20_transfers {
+            let flow = flows
+                .entry(transfer.contract_address.as_str())
+                .or_insert_with(|| TokenFlow {
+                    contract_address: transfer.contract_address.clone(),
+                    token_name: transfer.token_name.clone(),
+                    token_symbol: transfer.token_symbol.clone(),
+                    decimals: u32::try_from(transfer.decimals()).unwrap_or(u32::MAX),
+                    inflow: 0,
+                    outflow: 0,
+                    transfers: 0,
+                });
+            let amount = amount(transfer)?;
+            if transfer.to == self.address {
+                flow.inflow = flow
+                    .inflow
+                    .checked_add(amount)
+                    .ok_or_else(|| overflow(transfer))?;
+            }
+            if transfer.from == self.address {
+                flow.outflow = flow
+                    .outflow
+                    .checked_add(amount)
+                    .ok_or_else(|| overflow(transfer))?;
+            }
+            flow.transfers += 1;
+        }
+
+        Ok(flows.into_values().collect())
+    }
+
+    /// Get the ERC-20 flow of one token
+    pub fn flow<S: AsRef<str>>(&self, contract_address: S) -> Result<Option<TokenFlow>> {
+        let contract_address = contract_address.as_ref().to_lowercase();
+        Ok(self
+            .flows()?
+            .into_iter()
+            .find(|flow| flow.contract_address.as_str() == contract_address))
+    }
+
+    /// Get the ERC-721 token IDs held, per collection
+    ///
+    /// Collections the address no longer holds anything of are omitted.
+    pub fn nft_holdings(&self) -> Vec<NftHolding> {
+        let mut holdings: BTreeMap<&str, NftHolding> = BTreeMap::new();
+        for transfer in &self.nft_transfers {
+            let Some(token_id) = transfer.nft_token_id() else {
+                continue;
+            };
+            let holding = holdings
+                .entry(transfer.contract_address.as_str())
+                .or_insert_with(|| NftHolding {
+                    contract_address: transfer.contract_address.clone(),
+                    token_name: transfer.token_name.clone(),
+                    token_symbol: transfer.token_symbol.clone(),
+                    token_ids: BTreeSet::new(),
+                });
+            if transfer.from == self.address {
+                holding.token_ids.remove(token_id);
+            }
+            if transfer.to == self.address {
+                holding.token_ids.insert(token_id.to_string());
+            }
+        }
+
+        holdings
+            .into_values()
+            .filter(|holding| !holding.token_ids.is_empty())
+            .collect()
+    }
+
+    /// Get the ERC-1155 balance per token ID, ordered by contract and ID
+    ///
+    /// Token IDs with a zero balance are omitted. Fails with
+    /// [`EtherscanError::Parse`] if an amount or balance overflows an `i128`.
+    pub fn erc1155_balances(&self) -> Result<Vec<Erc1155Balance>> {
+        let mut balances: BTreeMap<(&str, &str), Erc1155Balance> = BTreeMap::new();
+        for transfer in &self.erc1155_transfers {
+            let Some(token_id) = transfer.nft_token_id() else {
+                continue;
+            };
+            let entry = balances
+                .entry((transfer.contract_address.as_str(), token_id))
+                .or_insert_with(|| Erc1155Balance {
+                    contract_address: transfer.contract_address.clone(),
+                    token_id: token_id.to_string(),
+                    token_name: transfer.token_name.clone(),
+                    balance: 0,
+                });
+            let amount = i128::try_from(amount(transfer)?).map_err(|_| overflow(transfer))?;
+            if transfer.to == self.address {
+                entry.balance = entry
+                    .balance
+                    .checked_add(amount)
+                    .ok_or_else(|| overflow(transfer))?;
+            }
+            if transfer.from == self.address {
+                entry.balance = entry
+                    .balance
+                    .checked_sub(amount)
+                    .ok_or_else(|| overflow(transfer))?;
+            }
+        }
+
+        Ok(balances
+            .into_values()
+            .filter(|balance| balance.balance != 0)
+            .collect())
+    }
+}
+
+fn amount(transfer: &TokenTransfer) -> Result<u128> {
+    transfer.value.as_u128().ok_or_else(|| {
+        EtherscanError::parse(format!(
+            "Invalid amount {} in transfer {}",
+            transfer.value, transfer.hash
+        ))
+    })
+}
+
+fn overflow(transfer: &TokenTransfer) -> EtherscanError {
+    EtherscanError::parse(format!(
+        "Amount overflow at transfer {} of {}",
+        transfer.hash, transfer.contract_address
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(1_500_000, 6), "1.5");
+        assert_eq!(format_units(-2_000, 3), "-2");
+        assert_eq!(format_units(1, 18), "0.000000000000000001");
+        assert_eq!(format_units(42, 0), "42");
+        assert_eq!(format_units(0, 6), "0");
+        assert_eq!(
+            format_units(i128::MAX, 18),
+            "170141183460469231731.687303715884105727"
+        );
+    }
+}
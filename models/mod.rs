@@ -4,23 +4,34 @@
 //! by the Etherscan API endpoints.
 
 mod account;
+mod activity;
 mod beacon_withdrawal;
 mod block;
 mod contract;
+mod details;
 mod gas;
+mod holders;
+mod ledger;
+mod reconcile;
 mod token;
 mod transaction;
 mod validated_block;
 
 pub use account::*;
+pub use activity::*;
 pub use beacon_withdrawal::*;
 pub use block::*;
 pub use contract::*;
+pub use details::*;
 pub use gas::*;
+pub use holders::*;
+pub use ledger::*;
+pub use reconcile::*;
 pub use token::*;
 pub use transaction::*;
 pub use validated_block::*;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -31,10 +42,16 @@ pub trait BlockchainData {
 
     /// Get the timestamp associated with this data, if applicable
     fn timestamp(&self) -> Option<u64>;
+
+    /// Get the timestamp as a UTC date and time, if applicable
+    fn datetime(&self) -> Option<DateTime<Utc>> {
+        self.timestamp()
+            .map(|seconds| Timestamp::from(seconds).datetime())
+    }
 }
 
 /// Helper type for handling string numbers from API
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct StringNumber(#[serde(deserialize_with = "deserialize_string_number")] pub u64);
 
@@ -56,6 +73,50 @@ impl fmt::Display for StringNumber {
     }
 }
 
+/// Unix timestamp sent by the API as a string of seconds
+///
+/// Serializes back to the same string form so models round-trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    pub DateTime<Utc>,
+);
+
+impl Timestamp {
+    /// Seconds since the Unix epoch
+    pub fn value(&self) -> u64 {
+        self.0.timestamp().max(0) as u64
+    }
+
+    /// UTC date and time
+    pub fn datetime(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl From<u64> for Timestamp {
+    fn from(seconds: u64) -> Self {
+        let seconds = i64::try_from(seconds).unwrap_or(i64::MAX);
+        Timestamp(DateTime::from_timestamp(seconds, 0).unwrap_or(DateTime::<Utc>::MAX_UTC))
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Timestamp(datetime)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_rfc3339())
+    }
+}
+
 /// Helper type for handling hex string numbers from API
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -79,6 +140,37 @@ impl fmt::Display for HexNumber {
     }
 }
 
+/// Helper type for hex-encoded quantities too large for `u64` (like wei amounts
+/// returned by the `proxy` module)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HexQuantity(
+    #[serde(
+        serialize_with = "serialize_hex_quantity",
+        deserialize_with = "deserialize_hex_quantity"
+    )]
+    pub u128,
+);
+
+impl HexQuantity {
+    /// Get the quantity as u128
+    pub fn value(&self) -> u128 {
+        self.0
+    }
+}
+
+impl From<u128> for HexQuantity {
+    fn from(value: u128) -> Self {
+        HexQuantity(value)
+    }
+}
+
+impl fmt::Display for HexQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:x}", self.0)
+    }
+}
+
 /// Helper type for handling large number strings (like wei amounts)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -113,7 +205,7 @@ impl fmt::Display for BigNumber {
 }
 
 /// Helper type for Ethereum addresses
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Address(pub String);
 
@@ -151,7 +243,7 @@ impl fmt::Display for Address {
 }
 
 /// Helper type for transaction hashes
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct TxHash(pub String);
 
@@ -183,22 +275,91 @@ impl fmt::Display for TxHash {
     }
 }
 
+/// A number as Etherscan sends it (a string) or as the models serialize it
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberRepr {
+    Number(u64),
+    String(String),
+}
+
 // Custom deserializers for handling Etherscan's string formats
 fn deserialize_string_number<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    s.parse().map_err(serde::de::Error::custom)
+    match NumberRepr::deserialize(deserializer)? {
+        NumberRepr::Number(value) => Ok(value),
+        NumberRepr::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+fn serialize_timestamp<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(&value.timestamp())
+}
+
+fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let seconds = deserialize_string_number(deserializer)?;
+    Ok(Timestamp::from(seconds).datetime())
 }
 
 fn deserialize_hex_number<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match NumberRepr::deserialize(deserializer)? {
+        NumberRepr::Number(value) => Ok(value),
+        NumberRepr::String(s) => {
+            let s = s.strip_prefix("0x").unwrap_or(&s);
+            u64::from_str_radix(s, 16).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+fn serialize_hex_quantity<S>(value: &u128, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&format!("0x{:x}", value))
+}
+
+fn deserialize_hex_quantity<'de, D>(deserializer: D) -> Result<u128, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
     let s = s.strip_prefix("0x").unwrap_or(&s);
-    u64::from_str_radix(s, 16).map_err(serde::de::Error::custom)
+    u128::from_str_radix(s, 16).map_err(serde::de::Error::custom)
+}
+
+/// Convert a hex string of any width (such as a 256-bit log word) to decimal
+pub(crate) fn hex_to_decimal(hex: &str) -> Option<String> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    // Little-endian base 10^9 limbs
+    let mut limbs: Vec<u64> = vec![0];
+    for c in hex.chars() {
+        let mut carry = u64::from(c.to_digit(16)?);
+        for limb in limbs.iter_mut() {
+            let value = *limb * 16 + carry;
+            *limb = value % 1_000_000_000;
+            carry = value / 1_000_000_000;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+    }
+
+    let mut decimal = limbs.last().copied().unwrap_or_default().to_string();
+    for limb in limbs.iter().rev().skip(1) {
+        decimal.push_str(&format!("{:09}", limb));
+    }
+    Some(decimal)
 }
 
 fn deserialize_big_number<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -0,0 +1,374 @@
+use crate::error::{EtherscanError, Result};
+use crate::models::{
+    BeaconWithdrawal, BigNumber, InternalTransaction, Transaction, ValidatedBlock,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Running ETH balance of an address after a block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalancePoint {
+    /// Block whose balance changes are included
+    pub block_number: u64,
+
+    /// Balance in wei (negative if the replayed history is incomplete)
+    pub balance: i128,
+}
+
+/// ETH balance of an address at every block, replayed from its history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceReplay {
+    /// Balance before the first replayed block
+    pub opening_balance: i128,
+
+    /// Balance after every block that changed it, in ascending block order
+    pub points: Vec<BalancePoint>,
+}
+
+impl BalanceReplay {
+    /// Replay the balance changes of `address`
+    ///
+    /// - normal transactions move their value unless they reverted, and the
+    ///   sender always pays `gasUsed × gasPrice`
+    /// - internal transactions move their value unless they reverted
+    /// - beacon withdrawals (in gwei) and validated-block rewards are credited
+    ///
+    /// Fails on an amount that does not parse and on a balance that does not
+    /// fit in an `i128`, instead of replaying corrupted numbers.
+    pub fn new(
+        address: &str,
+        opening_balance: i128,
+        transactions: &[Transaction],
+        internal_transactions: &[InternalTransaction],
+        withdrawals: &[BeaconWithdrawal],
+        validated_blocks: &[ValidatedBlock],
+    ) -> Result<Self> {
+        let address = address.to_lowercase();
+        let mut deltas: BTreeMap<u64, i128> = BTreeMap::new();
+        let mut apply = |block: u64, delta: i128| -> Result<()> {
+            let entry = deltas.entry(block).or_default();
+            *entry = entry.checked_add(delta).ok_or_else(|| overflow(block))?;
+            Ok(())
+        };
+
+        for tx in transactions {
+            let value = wei(&tx.value, tx.block())?;
+            let failed = tx.has_error() || !tx.is_successful();
+            if tx.from.as_str() == address {
+                let fee = wei(&tx.gas_price, tx.block())?
+                    .checked_mul(i128::from(tx.gas_used_amount()))
+                    .ok_or_else(|| overflow(tx.block()))?;
+                apply(tx.block(), -fee)?;
+                if !failed {
+                    apply(tx.block(), -value)?;
+                }
+            }
+            if !failed && tx.to.as_ref().is_some_and(|to| to.as_str() == address) {
+                apply(tx.block(), value)?;
+            }
+        }
+
+        for tx in internal_transactions.iter().filter(|tx| !tx.has_error()) {
+            let value = wei(&tx.value, tx.block())?;
+            // Contract creations have no `to` and credit the created contract
+            let recipient = tx.to.as_ref().or(tx.contract_address.as_ref());
+            if tx.from.as_str() == address {
+                apply(tx.block(), -value)?;
+            }
+            if recipient.is_some_and(|to| to.as_str() == address) {
+                apply(tx.block(), value)?;
+            }
+        }
+
+        for withdrawal in withdrawals {
+            if withdrawal.address.as_str() == address {
+                let amount = withdrawal
+                    .amount_wei()
+                    .and_then(|amount| i128::try_from(amount).ok())
+                    .ok_or_else(|| {
+                        EtherscanError::parse(format!(
+                            "Invalid withdrawal amount {} gwei in block {}",
+                            withdrawal.amount,
+                            withdrawal.block()
+                        ))
+                    })?;
+                apply(withdrawal.block(), amount)?;
+            }
+        }
+
+        for block in validated_blocks {
+            apply(block.block(), i128::from(block.reward_wei()))?;
+        }
+
+        let mut balance = opening_balance;
+        let mut points = Vec::new();
+        for (block_number, delta) in deltas.into_iter().filter(|(_, delta)| *delta != 0) {
+            balance = balance
+                .checked_add(delta)
+                .ok_or_else(|| overflow(block_number))?;
+            points.push(BalancePoint {
+                block_number,
+                balance,
+            });
+        }
+
+        Ok(Self {
+            opening_balance,
+            points,
+        })
+    }
+
+    /// Get the replayed balance after `block`
+    pub fn balance_at(&self, block: u64) -> i128 {
+        let index = self
+            .points
+            .partition_point(|point| point.block_number <= block);
+        match index {
+            0 => self.opening_balance,
+            index => self.points[index - 1].balance,
+        }
+    }
+
+    /// Get the balance after the last replayed block
+    pub fn closing_balance(&self) -> i128 {
+        self.points
+            .last()
+            .map_or(self.opening_balance, |point| point.balance)
+    }
+
+    /// Get the last block that changed the balance
+    pub fn last_block(&self) -> Option<u64> {
+        self.points.last().map(|point| point.block_number)
+    }
+}
+
+fn wei(amount: &BigNumber, block: u64) -> Result<i128> {
+    amount
+        .as_u128()
+        .and_then(|amount| i128::try_from(amount).ok())
+        .ok_or_else(|| {
+            EtherscanError::parse(format!("Invalid amount {} in block {}", amount, block))
+        })
+}
+
+fn overflow(block: u64) -> EtherscanError {
+    EtherscanError::parse(format!("Balance overflow at block {}", block))
+}
+
+/// Replayed and on-chain balance at a checkpoint block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Checkpoint block
+    pub block_number: u64,
+
+    /// Balance computed from the replayed history, in wei
+    pub expected: i128,
+
+    /// Balance reported by the chain, in wei
+    pub actual: i128,
+}
+
+impl Checkpoint {
+    /// Get the on-chain balance minus the replayed balance
+    pub fn difference(&self) -> i128 {
+        self.actual.saturating_sub(self.expected)
+    }
+
+    /// Check if the replayed balance matches the chain
+    pub fn matches(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// A mismatch that appeared between two consecutive checkpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Discrepancy {
+    /// First block of the range the mismatch appeared in
+    pub start_block: u64,
+
+    /// Last block of the range (the checkpoint that revealed it)
+    pub end_block: u64,
+
+    /// Replayed balance at `end_block`, in wei
+    pub expected: i128,
+
+    /// On-chain balance at `end_block`, in wei
+    pub actual: i128,
+
+    /// Unexplained wei introduced within the range (on-chain minus replayed)
+    pub introduced: i128,
+}
+
+/// Outcome of reconciling an address's history against chain state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    /// Reconciled address
+    pub address: String,
+
+    /// Replayed running balance
+    pub replay: BalanceReplay,
+
+    /// Balances compared at each checkpoint, in ascending block order
+    pub checkpoints: Vec<Checkpoint>,
+
+    /// Mismatches and the block range each first appeared in
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl ReconciliationReport {
+    /// Compare the replay with on-chain balances at the checkpoints
+    ///
+    /// `first_block` is the first replayed block, where the opening balance
+    /// is known to be correct.
+    pub fn new(
+        address: &str,
+        replay: BalanceReplay,
+        first_block: u64,
+        mut checkpoints: Vec<Checkpoint>,
+    ) -> Self {
+        checkpoints.sort_by_key(|checkpoint| checkpoint.block_number);
+        checkpoints.dedup_by_key(|checkpoint| checkpoint.block_number);
+
+        let mut discrepancies = Vec::new();
+        let mut start_block = first_block;
+        let mut previous = 0i128;
+        for checkpoint in &checkpoints {
+            let difference = checkpoint.difference();
+            if difference != previous {
+                discrepancies.push(Discrepancy {
+                    start_block,
+                    end_block: checkpoint.block_number,
+                    expected: checkpoint.expected,
+                    actual: checkpoint.actual,
+                    introduced: difference.saturating_sub(previous),
+                });
+            }
+            previous = difference;
+            start_block = checkpoint.block_number.saturating_add(1);
+        }
+
+        Self {
+            address: address.to_lowercase(),
+            replay,
+            checkpoints,
+            discrepancies,
+        }
+    }
+
+    /// Check if every checkpoint matched
+    pub fn is_reconciled(&self) -> bool {
+        self.checkpoints.iter().all(Checkpoint::matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Address, BigNumber, StringNumber, Timestamp, TxHash};
+
+    const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+    const BOB: &str = "0x1234567890123456789012345678901234567890";
+
+    fn transaction(block: u64, from: &str, to: &str, value: u128, failed: bool) -> Transaction {
+        Transaction {
+            block_number: StringNumber::from(block),
+            block_hash: String::new(),
+            transaction_index: StringNumber::from(0),
+            hash: TxHash::new(format!("0x{:064x}", block)),
+            nonce: StringNumber::from(0),
+            from: Address::new(from),
+            to: Some(Address::new(to)),
+            value: BigNumber::from(value.to_string()),
+            gas: StringNumber::from(21_000),
+            gas_price: BigNumber::from("10".to_string()),
+            gas_used: StringNumber::from(21_000),
+            cumulative_gas_used: StringNumber::from(21_000),
+            input: "0x".to_string(),
+            timestamp: Timestamp::from(0),
+            method_id: None,
+            function_name: None,
+            receipt_status: Some(StringNumber::from(u64::from(!failed))),
+            confirmations: None,
+            is_error: Some(StringNumber::from(u64::from(failed))),
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let transactions = [
+            transaction(1, BOB, ALICE, 1_000_000, false),
+            transaction(2, ALICE, BOB, 100_000, false),
+            // Reverted: only the fee is paid
+            transaction(3, ALICE, BOB, 100_000, true),
+        ];
+        let withdrawals = [BeaconWithdrawal {
+            withdrawal_index: StringNumber::from(1),
+            validator_index: StringNumber::from(1),
+            address: Address::new(ALICE),
+            amount: BigNumber::from("2".to_string()),
+            block_number: StringNumber::from(5),
+            timestamp: Timestamp::from(0),
+        }];
+
+        let replay = BalanceReplay::new(ALICE, 7, &transactions, &[], &withdrawals, &[]).unwrap();
+        let balances: Vec<(u64, i128)> = replay
+            .points
+            .iter()
+            .map(|point| (point.block_number, point.balance))
+            .collect();
+        assert_eq!(
+            balances,
+            vec![
+                (1, 1_000_007),
+                (2, 1_000_007 - 100_000 - 210_000),
+                (3, 1_000_007 - 100_000 - 420_000),
+                (5, 1_000_007 - 100_000 - 420_000 + 2_000_000_000),
+            ]
+        );
+        assert_eq!(replay.balance_at(0), 7);
+        assert_eq!(replay.balance_at(4), 1_000_007 - 100_000 - 420_000);
+        assert_eq!(replay.last_block(), Some(5));
+    }
+
+    #[test]
+    fn test_replay_fails_on_overflow() {
+        let transactions = [transaction(1, BOB, ALICE, i128::MAX as u128, false)];
+        assert!(BalanceReplay::new(ALICE, 1, &transactions, &[], &[], &[]).is_err());
+
+        let mut invalid = transaction(1, BOB, ALICE, 0, false);
+        invalid.value = BigNumber::from("lots".to_string());
+        assert!(BalanceReplay::new(ALICE, 0, &[invalid], &[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_report_discrepancy_ranges() {
+        let checkpoint = |block_number, expected, actual| Checkpoint {
+            block_number,
+            expected,
+            actual,
+        };
+        let report = ReconciliationReport::new(
+            ALICE,
+            BalanceReplay {
+                opening_balance: 0,
+                points: Vec::new(),
+            },
+            1,
+            vec![
+                checkpoint(30, 10, 15),
+                checkpoint(10, 10, 10),
+                checkpoint(20, 10, 15),
+                checkpoint(40, 10, 10),
+            ],
+        );
+
+        assert!(!report.is_reconciled());
+        let ranges: Vec<(u64, u64, i128)> = report
+            .discrepancies
+            .iter()
+            .map(|d| (d.start_block, d.end_block, d.introduced))
+            .collect();
+        // The surplus appeared in 11..=20 and was gone again by 40
+        assert_eq!(ranges, vec![(11, 20, 5), (31, 40, -5)]);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::{Address, BigNumber, BlockchainData, HexNumber, StringNumber, TxHash};
+use crate::models::{
+    Address, BigNumber, BlockchainData, HexNumber, HexQuantity, StringNumber, Timestamp, TxHash,
+};
 use serde::{Deserialize, Serialize};
 
 /// Standard Ethereum transaction
@@ -51,7 +53,7 @@ pub struct Transaction {
 
     /// Transaction timestamp
     #[serde(rename = "timeStamp")]
-    pub timestamp: StringNumber,
+    pub timestamp: Timestamp,
 
     /// Method ID (first 4 bytes of input)
     #[serde(rename = "methodId")]
@@ -171,7 +173,12 @@ pub struct InternalTransaction {
     pub block_number: StringNumber,
 
     /// Transaction hash of the parent transaction
-    pub hash: TxHash,
+    ///
+    /// Etherscan leaves it out of by-hash queries; [`by_hash`] fills it in.
+    ///
+    /// [`by_hash`]: crate::endpoints::accounts::InternalTxByHashBuilder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<TxHash>,
 
     /// From address
     pub from: Address,
@@ -200,9 +207,9 @@ pub struct InternalTransaction {
     #[serde(rename = "gasUsed")]
     pub gas_used: StringNumber,
 
-    /// Trace ID
-    #[serde(rename = "traceId")]
-    pub trace_id: String,
+    /// Trace ID, left out of by-hash queries
+    #[serde(rename = "traceId", default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
 
     /// Is error flag
     #[serde(rename = "isError")]
@@ -214,7 +221,7 @@ pub struct InternalTransaction {
 
     /// Timestamp
     #[serde(rename = "timeStamp")]
-    pub timestamp: StringNumber,
+    pub timestamp: Timestamp,
 }
 
 impl InternalTransaction {
@@ -278,6 +285,10 @@ pub struct TokenTransfer {
     pub contract_address: Address,
 
     /// Token value/amount transferred
+    ///
+    /// `token1155tx` calls this `tokenValue` and `tokennfttx` omits it, in
+    /// which case it is 1.
+    #[serde(alias = "tokenValue", default = "one_token")]
     pub value: BigNumber,
 
     /// Token name
@@ -288,8 +299,8 @@ pub struct TokenTransfer {
     #[serde(rename = "tokenSymbol")]
     pub token_symbol: String,
 
-    /// Token decimals
-    #[serde(rename = "tokenDecimal")]
+    /// Token decimals (absent for ERC-1155, where it is 0)
+    #[serde(rename = "tokenDecimal", default)]
     pub token_decimal: StringNumber,
 
     /// Gas price
@@ -302,10 +313,10 @@ pub struct TokenTransfer {
 
     /// Timestamp
     #[serde(rename = "timeStamp")]
-    pub timestamp: StringNumber,
+    pub timestamp: Timestamp,
 
-    /// Log index
-    #[serde(rename = "logIndex")]
+    /// Log index (absent for ERC-1155)
+    #[serde(rename = "logIndex", default)]
     pub log_index: StringNumber,
 
     /// Token ID (for NFTs)
@@ -316,6 +327,10 @@ pub struct TokenTransfer {
     pub confirmations: Option<StringNumber>,
 }
 
+fn one_token() -> BigNumber {
+    BigNumber::from("1".to_string())
+}
+
 impl TokenTransfer {
     /// Get block number as u64
     pub fn block(&self) -> u64 {
@@ -406,6 +421,14 @@ pub struct TransactionReceipt {
     /// Transaction index
     #[serde(rename = "transactionIndex")]
     pub transaction_index: HexNumber,
+
+    /// Price per gas actually paid (base fee plus priority fee)
+    #[serde(rename = "effectiveGasPrice", default)]
+    pub effective_gas_price: Option<HexQuantity>,
+
+    /// Transaction envelope type (0 = legacy, 2 = EIP-1559)
+    #[serde(rename = "type", default)]
+    pub transaction_type: Option<HexNumber>,
 }
 
 impl TransactionReceipt {
@@ -423,6 +446,159 @@ impl TransactionReceipt {
     pub fn gas_used_amount(&self) -> u64 {
         self.gas_used.value()
     }
+
+    /// Get the effective gas price in wei, if reported
+    pub fn effective_gas_price_wei(&self) -> Option<u128> {
+        self.effective_gas_price.map(|price| price.value())
+    }
+}
+
+/// Transaction as returned by `eth_getTransactionByHash`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcTransaction {
+    /// Transaction hash
+    pub hash: TxHash,
+
+    /// Block hash (null while pending)
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<String>,
+
+    /// Block number (null while pending)
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<HexNumber>,
+
+    /// Transaction index in the block (null while pending)
+    #[serde(rename = "transactionIndex")]
+    pub transaction_index: Option<HexNumber>,
+
+    /// From address
+    pub from: Address,
+
+    /// To address (null for contract creation)
+    pub to: Option<Address>,
+
+    /// Value transferred in wei
+    pub value: HexQuantity,
+
+    /// Nonce of the transaction
+    pub nonce: HexNumber,
+
+    /// Gas limit
+    pub gas: HexNumber,
+
+    /// Gas price in wei (the fee cap for EIP-1559 transactions)
+    #[serde(rename = "gasPrice", default)]
+    pub gas_price: Option<HexQuantity>,
+
+    /// EIP-1559 maximum fee per gas
+    #[serde(rename = "maxFeePerGas", default)]
+    pub max_fee_per_gas: Option<HexQuantity>,
+
+    /// EIP-1559 maximum priority fee per gas
+    #[serde(rename = "maxPriorityFeePerGas", default)]
+    pub max_priority_fee_per_gas: Option<HexQuantity>,
+
+    /// Input data
+    pub input: String,
+
+    /// Transaction envelope type (0 = legacy, 2 = EIP-1559)
+    #[serde(rename = "type", default)]
+    pub transaction_type: Option<HexNumber>,
+}
+
+impl RpcTransaction {
+    /// Get block number as u64, if the transaction has been mined
+    pub fn block(&self) -> Option<u64> {
+        self.block_number.as_ref().map(HexNumber::value)
+    }
+
+    /// Check if the transaction is still waiting to be mined
+    pub fn is_pending(&self) -> bool {
+        self.block_number.is_none()
+    }
+
+    /// Get value in wei
+    pub fn value_wei(&self) -> u128 {
+        self.value.value()
+    }
+
+    /// Get gas limit as u64
+    pub fn gas_limit(&self) -> u64 {
+        self.gas.value()
+    }
+
+    /// Check if this is a contract creation transaction
+    pub fn is_contract_creation(&self) -> bool {
+        self.to.is_none()
+    }
+
+    /// Get method signature from input data
+    pub fn method_signature(&self) -> Option<&str> {
+        if self.input.len() >= 10 && self.input.starts_with("0x") {
+            Some(&self.input[0..10])
+        } else {
+            None
+        }
+    }
+}
+
+/// Execution status reported by `transaction/getstatus`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionStatus {
+    /// Is error flag (1 = reverted)
+    #[serde(rename = "isError")]
+    pub is_error: StringNumber,
+
+    /// Revert reason, empty on success
+    #[serde(rename = "errDescription")]
+    pub error_description: String,
+}
+
+impl TransactionStatus {
+    /// Check if the transaction reverted
+    pub fn has_error(&self) -> bool {
+        self.is_error.value() == 1
+    }
+
+    /// Get the revert reason, if any
+    pub fn reason(&self) -> Option<&str> {
+        Some(self.error_description.as_str()).filter(|reason| !reason.is_empty())
+    }
+}
+
+/// Outcome of waiting for a transaction to be confirmed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ConfirmationOutcome {
+    /// The transaction is mined and deep enough
+    Confirmed {
+        /// Receipt of the mined transaction
+        receipt: Box<TransactionReceipt>,
+        /// Blocks on top of the receipt, counting its own block
+        confirmations: u64,
+    },
+    /// The transaction left the mempool without its nonce being used
+    Dropped,
+    /// Another transaction from the same sender used its nonce
+    Replaced {
+        /// Nonce of the replaced transaction
+        nonce: u64,
+    },
+}
+
+impl ConfirmationOutcome {
+    /// Check if the transaction was confirmed
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, ConfirmationOutcome::Confirmed { .. })
+    }
+
+    /// Get the receipt, if the transaction was confirmed
+    pub fn receipt(&self) -> Option<&TransactionReceipt> {
+        match self {
+            ConfirmationOutcome::Confirmed { receipt, .. } => Some(receipt),
+            _ => None,
+        }
+    }
 }
 
 /// Transaction log entry
@@ -457,7 +633,12 @@ pub struct TransactionLog {
     #[serde(rename = "logIndex")]
     pub log_index: HexNumber,
 
-    /// Removed flag
+    /// Block timestamp, present on logs from the `logs` module
+    #[serde(rename = "timeStamp", default, skip_serializing_if = "Option::is_none")]
+    pub time_stamp: Option<HexNumber>,
+
+    /// Removed flag, absent on logs from the `logs` module
+    #[serde(default)]
     pub removed: bool,
 }
 
@@ -472,3 +653,13 @@ impl TransactionLog {
         self.block_number.value()
     }
 }
+
+impl BlockchainData for TransactionLog {
+    fn block_number(&self) -> Option<u64> {
+        Some(self.block())
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        self.time_stamp.as_ref().map(HexNumber::value)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::{BlockchainData, StringNumber};
+use crate::models::{BlockchainData, StringNumber, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// Block validated by a validator address
@@ -10,7 +10,7 @@ pub struct ValidatedBlock {
 
     /// Timestamp when the block was validated
     #[serde(rename = "timeStamp")]
-    pub timestamp: StringNumber,
+    pub timestamp: Timestamp,
 
     /// Block reward received by the validator (in wei)
     #[serde(rename = "blockReward")]
@@ -73,7 +73,7 @@ mod tests {
     fn test_validated_block_helpers() {
         let block = ValidatedBlock {
             block_number: StringNumber::from(15000000),
-            timestamp: StringNumber::from(1659312000),
+            timestamp: Timestamp::from(1659312000),
             block_reward: StringNumber::from(2500000000000000000u64), // 2.5 ETH
         };
 
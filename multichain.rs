@@ -0,0 +1,356 @@
+//! Run the same query on several networks at once
+//!
+//! A [`MultiChainClient`] holds one [`EtherscanClient`] per [`Network`] and
+//! fans a query out to all of them concurrently. Every chain gets its own
+//! rate limiter, and a chain that fails does not hold back the others:
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{multichain::MultiChainClient, Network};
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = MultiChainClient::builder()
+//!         .api_key("YOUR_ETHERSCAN_KEY")
+//!         .networks([Network::Mainnet, Network::Arbitrum, Network::Optimism])
+//!         .network_with_key(Network::Polygon, "YOUR_POLYGONSCAN_KEY")
+//!         .network_with_key(Network::BinanceSmartChain, "YOUR_BSCSCAN_KEY")
+//!         .build()?;
+//!
+//!     let balances = client
+//!         .balances("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!         .await;
+//!     for (network, balance) in balances.successes() {
+//!         println!(
+//!             "{}: {} {}",
+//!             network,
+//!             balance.eth().unwrap_or(0.0),
+//!             network.native_currency()
+//!         );
+//!     }
+//!     for failure in balances.failures() {
+//!         eprintln!("{} failed: {:?}", failure.network, failure.result.as_ref().err());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(feature = "accounts")]
+use crate::{
+    endpoints::accounts::TransactionQueryBuilder, Balance, Pagination, TokenBalance, Transaction,
+    TransactionType,
+};
+use crate::{EtherscanClient, EtherscanClientBuilder, EtherscanError, Network, Result};
+use futures::future::join_all;
+use std::fmt;
+use std::future::Future;
+
+/// Customizes the builder of every chain's client
+type Configure = Box<dyn Fn(EtherscanClientBuilder) -> EtherscanClientBuilder + Send + Sync>;
+
+/// Outcome of a query on one network
+#[derive(Debug)]
+pub struct ChainResult<T> {
+    /// Network the query ran on
+    pub network: Network,
+    /// Query result, or the error that made it fail
+    pub result: Result<T>,
+}
+
+impl<T> ChainResult<T> {
+    /// Check if the query succeeded on this network
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Symbol of the network's native currency, such as `ETH` or `BNB`
+    pub fn native_currency(&self) -> &'static str {
+        self.network.native_currency()
+    }
+}
+
+/// Results of a query on several networks, in the order the networks were added
+#[derive(Debug)]
+pub struct MultiChainResult<T> {
+    /// Per-network outcomes
+    pub chains: Vec<ChainResult<T>>,
+}
+
+impl<T> MultiChainResult<T> {
+    /// Check if the query succeeded on every network
+    pub fn is_complete(&self) -> bool {
+        self.chains.iter().all(ChainResult::is_ok)
+    }
+
+    /// Outcome on `network`, if it is part of the client
+    pub fn get(&self, network: Network) -> Option<&ChainResult<T>> {
+        self.chains.iter().find(|chain| chain.network == network)
+    }
+
+    /// Iterate over the successful results with their network
+    pub fn successes(&self) -> impl Iterator<Item = (Network, &T)> {
+        self.chains
+            .iter()
+            .filter_map(|chain| Some((chain.network, chain.result.as_ref().ok()?)))
+    }
+
+    /// Iterate over the networks the query failed on
+    pub fn failures(&self) -> impl Iterator<Item = &ChainResult<T>> {
+        self.chains.iter().filter(|chain| !chain.is_ok())
+    }
+
+    /// Networks the query failed on
+    pub fn failed_networks(&self) -> Vec<Network> {
+        self.failures().map(|chain| chain.network).collect()
+    }
+
+    /// Consume the results, returning every network's result or the first error
+    pub fn into_result(self) -> Result<Vec<(Network, T)>> {
+        self.chains
+            .into_iter()
+            .map(|chain| chain.result.map(|result| (chain.network, result)))
+            .collect()
+    }
+}
+
+/// One [`EtherscanClient`] per network, queried concurrently
+#[derive(Debug, Clone)]
+pub struct MultiChainClient {
+    clients: Vec<EtherscanClient>,
+}
+
+impl MultiChainClient {
+    /// Create a new multi-chain client builder
+    pub fn builder() -> MultiChainClientBuilder {
+        MultiChainClientBuilder::new()
+    }
+
+    /// Networks of the client, in the order they were added
+    pub fn networks(&self) -> Vec<Network> {
+        self.clients.iter().map(EtherscanClient::network).collect()
+    }
+
+    /// Client for one network
+    pub fn client(&self, network: Network) -> Option<&EtherscanClient> {
+        self.clients
+            .iter()
+            .find(|client| client.network() == network)
+    }
+
+    /// Run `query` with every network's client concurrently
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: keion_etherscan::multichain::MultiChainClient) {
+    /// let heads = client
+    ///     .query(|client| async move { client.proxy().block_number().await })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn query<T, F, Fut>(&self, query: F) -> MultiChainResult<T>
+    where
+        F: Fn(EtherscanClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let results = join_all(self.clients.iter().cloned().map(&query)).await;
+        MultiChainResult {
+            chains: self
+                .clients
+                .iter()
+                .zip(results)
+                .map(|(client, result)| ChainResult {
+                    network: client.network(),
+                    result,
+                })
+                .collect(),
+        }
+    }
+
+    /// Native currency balance of an address on every network
+    #[cfg(feature = "accounts")]
+    pub async fn balances<S: AsRef<str>>(&self, address: S) -> MultiChainResult<Balance> {
+        let address = address.as_ref();
+        self.query(|client| async move { client.accounts().balance(address).await })
+            .await
+    }
+
+    /// ERC-20 tokens held by an address on every network
+    #[cfg(feature = "accounts")]
+    pub async fn token_balances<S: AsRef<str>>(
+        &self,
+        address: S,
+    ) -> MultiChainResult<Vec<TokenBalance>> {
+        let address = address.as_ref();
+        self.query(|client| async move { client.accounts().token_balances(address).await })
+            .await
+    }
+
+    /// Normal transactions of an address on every network
+    ///
+    /// Block ranges mean different things on each chain; use a time range
+    /// (`since`/`until`) to cover the same period everywhere.
+    #[cfg(feature = "accounts")]
+    pub async fn transactions<S: AsRef<str>>(
+        &self,
+        address: S,
+        pagination: &Pagination,
+    ) -> MultiChainResult<Vec<Transaction>> {
+        let address = address.as_ref();
+        self.query(|client| async move {
+            TransactionQueryBuilder::from_parts(
+                &client,
+                address.to_string(),
+                TransactionType::Normal,
+                pagination.clone(),
+            )
+            .execute()
+            .await
+        })
+        .await
+    }
+}
+
+/// A network to build a client for, or a client built elsewhere
+enum Chain {
+    Network(Network, Option<String>),
+    Client(EtherscanClient),
+}
+
+impl Chain {
+    fn network(&self) -> Network {
+        match self {
+            Chain::Network(network, _) => *network,
+            Chain::Client(client) => client.network(),
+        }
+    }
+}
+
+/// Builder for [`MultiChainClient`]
+pub struct MultiChainClientBuilder {
+    api_key: Option<String>,
+    chains: Vec<Chain>,
+    configure: Option<Configure>,
+}
+
+impl MultiChainClientBuilder {
+    /// Create a builder without networks
+    pub fn new() -> Self {
+        Self {
+            api_key: None,
+            chains: Vec::new(),
+            configure: None,
+        }
+    }
+
+    /// Set the API key of networks added without their own key
+    pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Add a network using the key set with [`api_key`](Self::api_key)
+    pub fn network(mut self, network: Network) -> Self {
+        self.chains.push(Chain::Network(network, None));
+        self
+    }
+
+    /// Add several networks using the key set with [`api_key`](Self::api_key)
+    pub fn networks<I: IntoIterator<Item = Network>>(mut self, networks: I) -> Self {
+        self.chains.extend(
+            networks
+                .into_iter()
+                .map(|network| Chain::Network(network, None)),
+        );
+        self
+    }
+
+    /// Add a network with its own API key, e.g. for explorers that do not
+    /// share Etherscan keys
+    pub fn network_with_key<S: Into<String>>(mut self, network: Network, api_key: S) -> Self {
+        self.chains
+            .push(Chain::Network(network, Some(api_key.into())));
+        self
+    }
+
+    /// Add a client configured elsewhere, for its network
+    pub fn client(mut self, client: EtherscanClient) -> Self {
+        self.chains.push(Chain::Client(client));
+        self
+    }
+
+    /// Customize the builder of every client created for a network
+    ///
+    /// ```rust,no_run
+    /// # use keion_etherscan::{multichain::MultiChainClient, Network};
+    /// let client = MultiChainClient::builder()
+    ///     .api_key("YOUR_API_KEY")
+    ///     .networks([Network::Mainnet, Network::Optimism])
+    ///     .configure(|builder| builder.rate_limit(2).max_retries(3))
+    ///     .build();
+    /// ```
+    pub fn configure<F>(mut self, configure: F) -> Self
+    where
+        F: Fn(EtherscanClientBuilder) -> EtherscanClientBuilder + Send + Sync + 'static,
+    {
+        self.configure = Some(Box::new(configure));
+        self
+    }
+
+    /// Build a client per network
+    ///
+    /// Fails when no network was added, a network was added twice or a
+    /// network has no API key.
+    pub fn build(self) -> Result<MultiChainClient> {
+        if self.chains.is_empty() {
+            return Err(EtherscanError::InvalidParams(
+                "No networks configured".to_string(),
+            ));
+        }
+        for (index, chain) in self.chains.iter().enumerate() {
+            let network = chain.network();
+            if self.chains[..index]
+                .iter()
+                .any(|earlier| earlier.network() == network)
+            {
+                return Err(EtherscanError::InvalidParams(format!(
+                    "Network added twice: {}",
+                    network
+                )));
+            }
+        }
+
+        let clients = self
+            .chains
+            .into_iter()
+            .map(|chain| match chain {
+                Chain::Client(client) => Ok(client),
+                Chain::Network(network, api_key) => {
+                    let mut builder = EtherscanClient::builder().network(network);
+                    if let Some(api_key) = api_key.or_else(|| self.api_key.clone()) {
+                        builder = builder.api_key(api_key);
+                    }
+                    if let Some(configure) = &self.configure {
+                        builder = configure(builder);
+                    }
+                    builder.build()
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MultiChainClient { clients })
+    }
+}
+
+impl Default for MultiChainClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MultiChainClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let networks: Vec<Network> = self.chains.iter().map(Chain::network).collect();
+        f.debug_struct("MultiChainClientBuilder")
+            .field("networks", &networks)
+            .field("configure", &self.configure.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,620 @@
+//! Serializable query specifications
+//!
+//! Every query builder can be turned into a [`QuerySpec`] with its `spec()`
+//! method. A spec holds everything the builder was configured with except the
+//! client, so it can be stored in a database, put on a job queue or sent to
+//! another service, and executed later against any [`EtherscanClient`].
+//!
+//! This covers the contract verification builders and
+//! [`ConfirmationWaitBuilder`] too: executing their specs submits the
+//! verification or waits for the transaction, like their own methods do.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{spec::QuerySpec, EtherscanClient, Sort};
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let spec = client
+//!         .accounts()
+//!         .transactions("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!         .sort(Sort::Descending)
+//!         .offset(100)
+//!         .spec();
+//!
+//!     let json = serde_json::to_string(&spec)?;
+//!
+//!     // Later, possibly in another process
+//!     let spec: QuerySpec = serde_json::from_str(&json)?;
+//!     let output = spec.execute(&client).await?;
+//!     println!("{}", serde_json::to_string_pretty(&output)?);
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(any(feature = "accounts", feature = "logs"))]
+use crate::Pagination;
+#[cfg(feature = "verification")]
+use crate::{
+    endpoints::contracts::{
+        ProxyVerificationBuilder, SolidityVerificationBuilder, VyperVerificationBuilder,
+    },
+    models::{CodeFormat, LibraryLink, OptimizationSettings, VerificationRequest},
+};
+#[cfg(feature = "logs")]
+use crate::{
+    endpoints::logs::{LogQueryBuilder, MAX_TOPICS},
+    models::TransactionLog,
+};
+#[cfg(feature = "proxy")]
+use crate::{endpoints::proxy::ConfirmationWaitBuilder, models::ConfirmationOutcome};
+#[cfg(feature = "accounts")]
+use crate::{
+    endpoints::{
+        accounts::{
+            ActivityQueryBuilder, BeaconWithdrawalsQueryBuilder, HistoricalBalanceQueryBuilder,
+            InternalTxByAddressBuilder, InternalTxByBlockRangeBuilder, InternalTxByHashBuilder,
+            ReconcileQueryBuilder, TokenLedgerQueryBuilder, TokenTransferQueryBuilder,
+            TransactionQueryBuilder, ValidatedBlocksQueryBuilder,
+        },
+        tokens::HolderSnapshotBuilder,
+    },
+    models::{
+        ActivityGroup, ActivityKind, Balance, BalanceSnapshot, BeaconWithdrawal, HolderSnapshot,
+        InternalTransaction, ReconciliationReport, TokenLedger, TokenTransfer, Transaction,
+        ValidatedBlock,
+    },
+    TransactionType,
+};
+use crate::{EtherscanClient, Result};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "proxy")]
+use std::time::Duration;
+
+/// A query builder's configuration, without the client
+///
+/// The spec can be stored or sent elsewhere and run later with
+/// [`QuerySpec::execute`].
+///
+/// Serialized with a `query` tag naming the kind of query, e.g.
+/// `{"query":"transactions","address":"0x…","tx_type":"normal","pagination":{"offset":100}}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "query", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum QuerySpec {
+    /// See [`TransactionQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    Transactions {
+        /// Queried address
+        address: String,
+        /// Kind of transactions
+        tx_type: TransactionType,
+        /// Block range, paging and sort order
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`TokenTransferQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    TokenTransfers {
+        /// Queried address, empty when querying a token contract
+        address: String,
+        /// Token standard of the transfers
+        tx_type: TransactionType,
+        /// Token contract filter
+        #[serde(default)]
+        contract_address: Option<String>,
+        /// Block range, paging and sort order
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`InternalTxByAddressBuilder`]
+    #[cfg(feature = "accounts")]
+    InternalTransactionsByAddress {
+        /// Queried address
+        address: String,
+        /// Block range, paging and sort order
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`InternalTxByHashBuilder`]
+    #[cfg(feature = "accounts")]
+    InternalTransactionsByHash {
+        /// Hash of the parent transaction
+        tx_hash: String,
+    },
+
+    /// See [`InternalTxByBlockRangeBuilder`]
+    #[cfg(feature = "accounts")]
+    InternalTransactionsByBlockRange {
+        /// First block of the range
+        start_block: u64,
+        /// Last block of the range
+        end_block: u64,
+        /// Paging and sort order
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`ValidatedBlocksQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    ValidatedBlocks {
+        /// Validator address
+        address: String,
+        /// Paging
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`BeaconWithdrawalsQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    BeaconWithdrawals {
+        /// Withdrawal address
+        address: String,
+        /// First block of the range
+        #[serde(default)]
+        start_block: Option<u64>,
+        /// Last block of the range
+        #[serde(default)]
+        end_block: Option<u64>,
+        /// Paging, sort order and time range
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`HistoricalBalanceQueryBuilder`]
+    ///
+    /// Runs as a series when `range` is set, as a single balance otherwise.
+    #[cfg(feature = "accounts")]
+    HistoricalBalance {
+        /// Queried address
+        address: String,
+        /// Token contract, for a token balance
+        #[serde(default)]
+        contract_address: Option<String>,
+        /// Block of a single balance
+        #[serde(default)]
+        block_number: Option<u64>,
+        /// Start, end and step of a balance series
+        #[serde(default)]
+        range: Option<(u64, u64, u64)>,
+    },
+
+    /// See [`ActivityQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    Activity {
+        /// Queried address
+        address: String,
+        /// Kinds of activity fetched
+        kinds: Vec<ActivityKind>,
+        /// Block range and sort order
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`ReconcileQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    Reconcile {
+        /// Reconciled address
+        address: String,
+        /// Block range
+        #[serde(default)]
+        pagination: Pagination,
+        /// Explicit checkpoint blocks
+        #[serde(default)]
+        checkpoints: Vec<u64>,
+        /// Distance between generated checkpoints
+        #[serde(default)]
+        checkpoint_step: Option<u64>,
+    },
+
+    /// See [`TokenLedgerQueryBuilder`]
+    #[cfg(feature = "accounts")]
+    TokenLedger {
+        /// Queried address
+        address: String,
+        /// Token contract filter
+        #[serde(default)]
+        contract_address: Option<String>,
+        /// Block range
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`HolderSnapshotBuilder`]
+    #[cfg(feature = "accounts")]
+    HolderSnapshot {
+        /// Token contract
+        contract_address: String,
+        /// Snapshot block
+        block_number: u64,
+        /// Addresses left out of the snapshot
+        excluded: Vec<String>,
+        /// Transfers fetched per request
+        #[serde(default)]
+        page_size: Option<u32>,
+    },
+
+    /// See [`LogQueryBuilder`]
+    #[cfg(feature = "logs")]
+    Logs {
+        /// Emitting contract filter
+        #[serde(default)]
+        address: Option<String>,
+        /// Topic filters by index
+        #[serde(default)]
+        topics: [Option<String>; MAX_TOPICS],
+        /// Block range and paging
+        #[serde(default)]
+        pagination: Pagination,
+    },
+
+    /// See [`SolidityVerificationBuilder`]
+    #[cfg(feature = "verification")]
+    VerifySolidity {
+        /// Contract address
+        address: String,
+        /// Source code
+        #[serde(default)]
+        source_code: Option<String>,
+        /// Contract name
+        #[serde(default)]
+        contract_name: Option<String>,
+        /// Compiler version
+        #[serde(default)]
+        compiler_version: Option<String>,
+        /// Optimizer settings
+        optimization_settings: OptimizationSettings,
+        /// ABI-encoded constructor arguments
+        #[serde(default)]
+        constructor_arguments: Option<String>,
+        /// Format of the source code
+        code_format: CodeFormat,
+        /// Linked libraries
+        #[serde(default)]
+        libraries: Vec<LibraryLink>,
+        /// License type
+        #[serde(default)]
+        license_type: Option<String>,
+        /// EVM version
+        #[serde(default)]
+        evm_version: Option<String>,
+    },
+
+    /// See [`VyperVerificationBuilder`]
+    #[cfg(feature = "verification")]
+    VerifyVyper {
+        /// Contract address
+        address: String,
+        /// Source code
+        #[serde(default)]
+        source_code: Option<String>,
+        /// Contract name
+        #[serde(default)]
+        contract_name: Option<String>,
+        /// Compiler version
+        #[serde(default)]
+        compiler_version: Option<String>,
+        /// ABI-encoded constructor arguments
+        #[serde(default)]
+        constructor_arguments: Option<String>,
+        /// Optimizer settings
+        optimization_settings: OptimizationSettings,
+    },
+
+    /// See [`ProxyVerificationBuilder`]
+    #[cfg(feature = "verification")]
+    VerifyProxy {
+        /// Proxy contract address
+        address: String,
+        /// Implementation the proxy is expected to point to
+        #[serde(default)]
+        expected_implementation: Option<String>,
+    },
+
+    /// See [`ConfirmationWaitBuilder`]
+    #[cfg(feature = "proxy")]
+    WaitForConfirmations {
+        /// Transaction hash waited for
+        tx_hash: String,
+        /// Required confirmations
+        confirmations: u64,
+        /// Maximum time to wait
+        timeout: Duration,
+        /// Time between two checks
+        poll_interval: Duration,
+    },
+}
+
+/// Result of executing a [`QuerySpec`]
+///
+/// Holds what the matching builder's `execute` returns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "output", content = "data", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum QueryOutput {
+    /// Normal transactions
+    #[cfg(feature = "accounts")]
+    Transactions(Vec<Transaction>),
+    /// Token transfers
+    #[cfg(feature = "accounts")]
+    TokenTransfers(Vec<TokenTransfer>),
+    /// Internal transactions
+    #[cfg(feature = "accounts")]
+    InternalTransactions(Vec<InternalTransaction>),
+    /// Validated blocks
+    #[cfg(feature = "accounts")]
+    ValidatedBlocks(Vec<ValidatedBlock>),
+    /// Beacon chain withdrawals
+    #[cfg(feature = "accounts")]
+    BeaconWithdrawals(Vec<BeaconWithdrawal>),
+    /// A single historical balance
+    #[cfg(feature = "accounts")]
+    Balance(Balance),
+    /// A historical balance series
+    #[cfg(feature = "accounts")]
+    BalanceSeries(Vec<BalanceSnapshot>),
+    /// Activity grouped by transaction
+    #[cfg(feature = "accounts")]
+    Activity(Vec<ActivityGroup>),
+    /// Balance reconciliation report
+    #[cfg(feature = "accounts")]
+    Reconciliation(ReconciliationReport),
+    /// Token ledger
+    #[cfg(feature = "accounts")]
+    TokenLedger(TokenLedger),
+    /// Token holder snapshot
+    #[cfg(feature = "accounts")]
+    HolderSnapshot(HolderSnapshot),
+    /// Event logs
+    #[cfg(feature = "logs")]
+    Logs(Vec<TransactionLog>),
+    /// Submitted verification request
+    #[cfg(feature = "verification")]
+    Verification(VerificationRequest),
+    /// Outcome of a confirmation wait
+    #[cfg(feature = "proxy")]
+    Confirmation(ConfirmationOutcome),
+}
+
+impl QuerySpec {
+    /// Execute the query with `client`
+    pub async fn execute(self, client: &EtherscanClient) -> Result<QueryOutput> {
+        match self {
+            #[cfg(feature = "accounts")]
+            QuerySpec::Transactions {
+                address,
+                tx_type,
+                pagination,
+            } => TransactionQueryBuilder::from_parts(client, address, tx_type, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::Transactions),
+            #[cfg(feature = "accounts")]
+            QuerySpec::TokenTransfers {
+                address,
+                tx_type,
+                contract_address,
+                pagination,
+            } => TokenTransferQueryBuilder::from_parts(
+                client,
+                address,
+                tx_type,
+                contract_address,
+                pagination,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::TokenTransfers),
+            #[cfg(feature = "accounts")]
+            QuerySpec::InternalTransactionsByAddress {
+                address,
+                pagination,
+            } => InternalTxByAddressBuilder::from_parts(client, address, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::InternalTransactions),
+            #[cfg(feature = "accounts")]
+            QuerySpec::InternalTransactionsByHash { tx_hash } => {
+                InternalTxByHashBuilder::from_parts(client, tx_hash)
+                    .execute()
+                    .await
+                    .map(QueryOutput::InternalTransactions)
+            }
+            #[cfg(feature = "accounts")]
+            QuerySpec::InternalTransactionsByBlockRange {
+                start_block,
+                end_block,
+                pagination,
+            } => InternalTxByBlockRangeBuilder::from_parts(
+                client,
+                start_block,
+                end_block,
+                pagination,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::InternalTransactions),
+            #[cfg(feature = "accounts")]
+            QuerySpec::ValidatedBlocks {
+                address,
+                pagination,
+            } => ValidatedBlocksQueryBuilder::from_parts(client, address, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::ValidatedBlocks),
+            #[cfg(feature = "accounts")]
+            QuerySpec::BeaconWithdrawals {
+                address,
+                start_block,
+                end_block,
+                pagination,
+            } => BeaconWithdrawalsQueryBuilder::from_parts(
+                client,
+                address,
+                start_block,
+                end_block,
+                pagination,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::BeaconWithdrawals),
+            #[cfg(feature = "accounts")]
+            QuerySpec::HistoricalBalance {
+                address,
+                contract_address,
+                block_number,
+                range,
+            } => {
+                let builder = HistoricalBalanceQueryBuilder::from_parts(
+                    client,
+                    address,
+                    contract_address,
+                    block_number,
+                    range,
+                );
+                if range.is_some() {
+                    builder
+                        .execute_series()
+                        .await
+                        .map(QueryOutput::BalanceSeries)
+                } else {
+                    builder.execute().await.map(QueryOutput::Balance)
+                }
+            }
+            #[cfg(feature = "accounts")]
+            QuerySpec::Activity {
+                address,
+                kinds,
+                pagination,
+            } => ActivityQueryBuilder::from_parts(client, address, kinds, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::Activity),
+            #[cfg(feature = "accounts")]
+            QuerySpec::Reconcile {
+                address,
+                pagination,
+                checkpoints,
+                checkpoint_step,
+            } => ReconcileQueryBuilder::from_parts(
+                client,
+                address,
+                pagination,
+                checkpoints,
+                checkpoint_step,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::Reconciliation),
+            #[cfg(feature = "accounts")]
+            QuerySpec::TokenLedger {
+                address,
+                contract_address,
+                pagination,
+            } => TokenLedgerQueryBuilder::from_parts(client, address, contract_address, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::TokenLedger),
+            #[cfg(feature = "accounts")]
+            QuerySpec::HolderSnapshot {
+                contract_address,
+                block_number,
+                excluded,
+                page_size,
+            } => HolderSnapshotBuilder::from_parts(
+                client,
+                contract_address,
+                block_number,
+                excluded,
+                page_size,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::HolderSnapshot),
+            #[cfg(feature = "logs")]
+            QuerySpec::Logs {
+                address,
+                topics,
+                pagination,
+            } => LogQueryBuilder::from_parts(client, address, topics, pagination)
+                .execute()
+                .await
+                .map(QueryOutput::Logs),
+            #[cfg(feature = "verification")]
+            QuerySpec::VerifySolidity {
+                address,
+                source_code,
+                contract_name,
+                compiler_version,
+                optimization_settings,
+                constructor_arguments,
+                code_format,
+                libraries,
+                license_type,
+                evm_version,
+            } => SolidityVerificationBuilder::from_parts(
+                client,
+                address,
+                source_code,
+                contract_name,
+                compiler_version,
+                optimization_settings,
+                constructor_arguments,
+                code_format,
+                libraries,
+                license_type,
+                evm_version,
+            )
+            .submit()
+            .await
+            .map(QueryOutput::Verification),
+            #[cfg(feature = "verification")]
+            QuerySpec::VerifyVyper {
+                address,
+                source_code,
+                contract_name,
+                compiler_version,
+                constructor_arguments,
+                optimization_settings,
+            } => VyperVerificationBuilder::from_parts(
+                client,
+                address,
+                source_code,
+                contract_name,
+                compiler_version,
+                constructor_arguments,
+                optimization_settings,
+            )
+            .submit()
+            .await
+            .map(QueryOutput::Verification),
+            #[cfg(feature = "verification")]
+            QuerySpec::VerifyProxy {
+                address,
+                expected_implementation,
+            } => ProxyVerificationBuilder::from_parts(client, address, expected_implementation)
+                .submit()
+                .await
+                .map(QueryOutput::Verification),
+            #[cfg(feature = "proxy")]
+            QuerySpec::WaitForConfirmations {
+                tx_hash,
+                confirmations,
+                timeout,
+                poll_interval,
+            } => ConfirmationWaitBuilder::from_parts(
+                client,
+                tx_hash,
+                confirmations,
+                timeout,
+                poll_interval,
+            )
+            .execute()
+            .await
+            .map(QueryOutput::Confirmation),
+        }
+    }
+}
@@ -0,0 +1,531 @@
+//! SQLite storage for fetched models (`sqlite` feature)
+//!
+//! [`SqliteStore`] keeps transactions, internal transactions, token
+//! transfers, beacon withdrawals, validated blocks and event logs in one
+//! table each. The columns analysts usually filter on are stored normalized
+//! (addresses lowercase, blocks and timestamps as integers, amounts as exact
+//! decimal strings) next to the full model as JSON, which the query helpers
+//! decode back. Rows are keyed by what identifies them on chain, so storing a
+//! re-fetched page replaces the rows instead of duplicating them.
+//!
+//! | Table | Key |
+//! |-------|-----|
+//! | `transactions` | `hash` |
+//! | `internal_transactions` | `hash`, `trace_id` |
+//! | `token_transfers` | `hash`, `log_index`, `token_id` |
+//! | `beacon_withdrawals` | `withdrawal_index` |
+//! | `validated_blocks` | `block_number` |
+//! | `logs` | `transaction_hash`, `log_index` |
+//!
+//! ```rust,no_run
+//! use keion_etherscan::{storage::SqliteStore, EtherscanClient};
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let store = SqliteStore::open("etherscan.db")?;
+//!
+//!     let address = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+//!     let transactions = client.accounts().transactions(address).execute().await?;
+//!     store.insert_transactions(&transactions)?;
+//!
+//!     let count: i64 = store.connection().query_row(
+//!         "SELECT COUNT(*) FROM transactions WHERE is_error = 1",
+//!         [],
+//!         |row| row.get(0),
+//!     )?;
+//!     println!("{} failed transactions", count);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    models::{
+        ActivityEvent, BeaconWithdrawal, InternalTransaction, TokenStandard, TokenTransfer,
+        Transaction, TransactionLog, ValidatedBlock,
+    },
+    EtherscanError, Result,
+};
+use rusqlite::{params_from_iter, types::Value, Connection, Params};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    hash TEXT PRIMARY KEY,
+    block_number INTEGER NOT NULL,
+    transaction_index INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    from_address TEXT NOT NULL,
+    to_address TEXT,
+    value TEXT NOT NULL,
+    gas_price TEXT NOT NULL,
+    gas_used INTEGER NOT NULL,
+    is_error INTEGER NOT NULL,
+    function_name TEXT,
+    json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_from ON transactions (from_address, block_number);
+CREATE INDEX IF NOT EXISTS transactions_to ON transactions (to_address, block_number);
+
+CREATE TABLE IF NOT EXISTS internal_transactions (
+    hash TEXT NOT NULL,
+    trace_id TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    from_address TEXT NOT NULL,
+    to_address TEXT,
+    contract_address TEXT,
+    value TEXT NOT NULL,
+    type TEXT NOT NULL,
+    is_error INTEGER NOT NULL,
+    json TEXT NOT NULL,
+    PRIMARY KEY (hash, trace_id)
+);
+CREATE INDEX IF NOT EXISTS internal_transactions_from
+    ON internal_transactions (from_address, block_number);
+CREATE INDEX IF NOT EXISTS internal_transactions_to
+    ON internal_transactions (to_address, block_number);
+
+CREATE TABLE IF NOT EXISTS token_transfers (
+    hash TEXT NOT NULL,
+    log_index INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    standard TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    contract_address TEXT NOT NULL,
+    from_address TEXT NOT NULL,
+    to_address TEXT NOT NULL,
+    value TEXT NOT NULL,
+    token_symbol TEXT NOT NULL,
+    token_decimal INTEGER NOT NULL,
+    json TEXT NOT NULL,
+    PRIMARY KEY (hash, log_index, token_id)
+);
+CREATE INDEX IF NOT EXISTS token_transfers_from ON token_transfers (from_address, block_number);
+CREATE INDEX IF NOT EXISTS token_transfers_to ON token_transfers (to_address, block_number);
+CREATE INDEX IF NOT EXISTS token_transfers_contract
+    ON token_transfers (contract_address, block_number);
+
+CREATE TABLE IF NOT EXISTS beacon_withdrawals (
+    withdrawal_index INTEGER PRIMARY KEY,
+    validator_index INTEGER NOT NULL,
+    address TEXT NOT NULL,
+    amount_gwei TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS beacon_withdrawals_address
+    ON beacon_withdrawals (address, block_number);
+
+CREATE TABLE IF NOT EXISTS validated_blocks (
+    block_number INTEGER PRIMARY KEY,
+    validator TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    block_reward TEXT NOT NULL,
+    json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS validated_blocks_validator
+    ON validated_blocks (validator, block_number);
+
+CREATE TABLE IF NOT EXISTS logs (
+    transaction_hash TEXT NOT NULL,
+    log_index INTEGER NOT NULL,
+    block_number INTEGER NOT NULL,
+    address TEXT NOT NULL,
+    topic0 TEXT,
+    topic1 TEXT,
+    topic2 TEXT,
+    topic3 TEXT,
+    data TEXT NOT NULL,
+    removed INTEGER NOT NULL,
+    json TEXT NOT NULL,
+    PRIMARY KEY (transaction_hash, log_index)
+);
+CREATE INDEX IF NOT EXISTS logs_address ON logs (address, block_number);
+CREATE INDEX IF NOT EXISTS logs_topic0 ON logs (topic0, block_number);
+";
+
+/// SQLite database holding fetched models in a normalized schema
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    /// Open or create the database at `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Create a store in a private in-memory database
+    pub fn in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// Use an existing connection, creating missing tables and indexes
+    pub fn with_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Lock the connection to run SQL directly
+    pub fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().expect("store lock poisoned")
+    }
+
+    /// Store normal transactions, replacing rows with the same hash
+    pub fn insert_transactions(&self, transactions: &[Transaction]) -> Result<usize> {
+        self.upsert(
+            "INSERT OR REPLACE INTO transactions (hash, block_number, transaction_index, \
+             timestamp, from_address, to_address, value, gas_price, gas_used, is_error, \
+             function_name, json) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            transactions,
+            |tx| {
+                vec![
+                    key(tx.hash.as_str()),
+                    integer(tx.block()),
+                    integer(tx.index()),
+                    integer(tx.timestamp.value()),
+                    key(tx.from.as_str()),
+                    optional_key(tx.to.as_ref().map(|to| to.as_str())),
+                    text(tx.value.as_str()),
+                    text(tx.gas_price.as_str()),
+                    integer(tx.gas_used.value()),
+                    flag(tx.has_error() || !tx.is_successful()),
+                    optional(tx.function_name.as_deref().filter(|name| !name.is_empty())),
+                ]
+            },
+        )
+    }
+
+    /// Store internal transactions, replacing rows with the same hash and trace ID
+    ///
+    /// Fails without storing anything if a transaction has no trace ID, as
+    /// rows from by-hash queries don't.
+    pub fn insert_internal_transactions(
+        &self,
+        transactions: &[InternalTransaction],
+    ) -> Result<usize> {
+        if let Some(tx) = transactions
+            .iter()
+            .find(|tx| tx.hash.is_none() || tx.trace_id.is_none())
+        {
+            return Err(EtherscanError::InvalidParams(format!(
+                "Internal transaction in block {} has no hash or trace ID to store it under",
+                tx.block()
+            )));
+        }
+
+        self.upsert(
+            "INSERT OR REPLACE INTO internal_transactions (hash, trace_id, block_number, \
+             timestamp, from_address, to_address, contract_address, value, type, is_error, \
+             json) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            transactions,
+            |tx| {
+                vec![
+                    optional_key(tx.hash.as_ref().map(|hash| hash.as_str())),
+                    optional(tx.trace_id.as_deref()),
+                    integer(tx.block()),
+                    integer(tx.timestamp.value()),
+                    key(tx.from.as_str()),
+                    optional_key(tx.to.as_ref().map(|to| to.as_str())),
+                    optional_key(tx.contract_address.as_ref().map(|a| a.as_str())),
+                    text(tx.value.as_str()),
+                    text(&tx.transaction_type),
+                    flag(tx.has_error()),
+                ]
+            },
+        )
+    }
+
+    /// Store token transfers of one standard
+    ///
+    /// Rows with the same hash, log index and token ID are replaced; ERC-20
+    /// transfers have an empty token ID.
+    pub fn insert_token_transfers(
+        &self,
+        standard: TokenStandard,
+        transfers: &[TokenTransfer],
+    ) -> Result<usize> {
+        self.upsert(
+            "INSERT OR REPLACE INTO token_transfers (hash, log_index, token_id, standard, \
+             block_number, timestamp, contract_address, from_address, to_address, value, \
+             token_symbol, token_decimal, json) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            transfers,
+            |transfer| {
+                vec![
+                    key(transfer.hash.as_str()),
+                    integer(transfer.log_index.value()),
+                    text(transfer.nft_token_id().unwrap_or_default()),
+                    text(standard.as_str()),
+                    integer(transfer.block()),
+                    integer(transfer.timestamp.value()),
+                    key(transfer.contract_address.as_str()),
+                    key(transfer.from.as_str()),
+                    key(transfer.to.as_str()),
+                    text(transfer.value.as_str()),
+                    text(&transfer.token_symbol),
+                    integer(transfer.decimals()),
+                ]
+            },
+        )
+    }
+
+    /// Store beacon withdrawals, replacing rows with the same withdrawal index
+    pub fn insert_beacon_withdrawals(&self, withdrawals: &[BeaconWithdrawal]) -> Result<usize> {
+        self.upsert(
+            "INSERT OR REPLACE INTO beacon_withdrawals (withdrawal_index, validator_index, \
+             address, amount_gwei, block_number, timestamp, json) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            withdrawals,
+            |withdrawal| {
+                vec![
+                    integer(withdrawal.withdrawal_index.value()),
+                    integer(withdrawal.validator_index.value()),
+                    key(withdrawal.address.as_str()),
+                    text(withdrawal.amount.as_str()),
+                    integer(withdrawal.block()),
+                    integer(withdrawal.timestamp.value()),
+                ]
+            },
+        )
+    }
+
+    /// Store blocks validated by `validator`, replacing rows with the same number
+    pub fn insert_validated_blocks<S: AsRef<str>>(
+        &self,
+        validator: S,
+        blocks: &[ValidatedBlock],
+    ) -> Result<usize> {
+        self.upsert(
+            "INSERT OR REPLACE INTO validated_blocks (block_number, validator, timestamp, \
+             block_reward, json) VALUES (?1, ?2, ?3, ?4, ?5)",
+            blocks,
+            |block| {
+                vec![
+                    integer(block.block()),
+                    key(validator.as_ref()),
+                    integer(block.timestamp.value()),
+                    text(&block.block_reward.value().to_string()),
+                ]
+            },
+        )
+    }
+
+    /// Store event logs, replacing rows with the same transaction hash and log index
+    pub fn insert_logs(&self, logs: &[TransactionLog]) -> Result<usize> {
+        self.upsert(
+            "INSERT OR REPLACE INTO logs (transaction_hash, log_index, block_number, address, \
+             topic0, topic1, topic2, topic3, data, removed, json) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            logs,
+            |log| {
+                let topic = |index: usize| optional_key(log.topics.get(index).map(String::as_str));
+                vec![
+                    key(log.transaction_hash.as_str()),
+                    integer(log.log_index.value()),
+                    integer(log.block()),
+                    key(log.address.as_str()),
+                    topic(0),
+                    topic(1),
+                    topic(2),
+                    topic(3),
+                    text(&log.data),
+                    flag(log.removed),
+                ]
+            },
+        )
+    }
+
+    /// Store activity events, e.g. the batches of an [`indexer`](crate::indexer) run
+    pub fn insert_events(&self, events: &[ActivityEvent]) -> Result<usize> {
+        let mut transactions = Vec::new();
+        let mut internal = Vec::new();
+        let mut erc20 = Vec::new();
+        let mut erc721 = Vec::new();
+        let mut erc1155 = Vec::new();
+        let mut withdrawals = Vec::new();
+        for event in events {
+            match event {
+                ActivityEvent::Transaction { transaction, .. } => {
+                    transactions.push(transaction.clone())
+                }
+                ActivityEvent::InternalTransaction { transaction, .. } => {
+                    internal.push(transaction.clone())
+                }
+                ActivityEvent::Erc20Transfer { transfer, .. } => erc20.push(transfer.clone()),
+                ActivityEvent::Erc721Transfer { transfer, .. } => erc721.push(transfer.clone()),
+                ActivityEvent::Erc1155Transfer { transfer, .. } => erc1155.push(transfer.clone()),
+                ActivityEvent::BeaconWithdrawal { withdrawal } => {
+                    withdrawals.push(withdrawal.clone())
+                }
+            }
+        }
+
+        Ok(self.insert_transactions(&transactions)?
+            + self.insert_internal_transactions(&internal)?
+            + self.insert_token_transfers(TokenStandard::Erc20, &erc20)?
+            + self.insert_token_transfers(TokenStandard::Erc721, &erc721)?
+            + self.insert_token_transfers(TokenStandard::Erc1155, &erc1155)?
+            + self.insert_beacon_withdrawals(&withdrawals)?)
+    }
+
+    /// Get a stored transaction by hash
+    pub fn transaction<S: AsRef<str>>(&self, hash: S) -> Result<Option<Transaction>> {
+        let hash = hash.as_ref().to_lowercase();
+        Ok(self
+            .select("SELECT json FROM transactions WHERE hash = ?1", [hash])?
+            .pop())
+    }
+
+    /// Get the stored transactions sent or received by an address, in chain order
+    pub fn transactions<S: AsRef<str>>(&self, address: S) -> Result<Vec<Transaction>> {
+        self.select(
+            "SELECT json FROM transactions WHERE from_address = ?1 OR to_address = ?1 \
+             ORDER BY block_number, transaction_index",
+            [address.as_ref().to_lowercase()],
+        )
+    }
+
+    /// Get the stored internal transactions involving an address, in chain order
+    pub fn internal_transactions<S: AsRef<str>>(
+        &self,
+        address: S,
+    ) -> Result<Vec<InternalTransaction>> {
+        self.select(
+            "SELECT json FROM internal_transactions \
+             WHERE from_address = ?1 OR to_address = ?1 OR contract_address = ?1 \
+             ORDER BY block_number, hash, trace_id",
+            [address.as_ref().to_lowercase()],
+        )
+    }
+
+    /// Get the stored transfers of one standard involving an address, in chain order
+    pub fn token_transfers<S: AsRef<str>>(
+        &self,
+        standard: TokenStandard,
+        address: S,
+    ) -> Result<Vec<TokenTransfer>> {
+        self.select(
+            "SELECT json FROM token_transfers \
+             WHERE standard = ?1 AND (from_address = ?2 OR to_address = ?2) \
+             ORDER BY block_number, log_index, token_id",
+            [
+                standard.as_str().to_string(),
+                address.as_ref().to_lowercase(),
+            ],
+        )
+    }
+
+    /// Get the stored transfers of a token contract, in chain order
+    pub fn contract_transfers<S: AsRef<str>>(&self, contract: S) -> Result<Vec<TokenTransfer>> {
+        self.select(
+            "SELECT json FROM token_transfers WHERE contract_address = ?1 \
+             ORDER BY block_number, log_index, token_id",
+            [contract.as_ref().to_lowercase()],
+        )
+    }
+
+    /// Get the stored beacon withdrawals credited to an address, in chain order
+    pub fn beacon_withdrawals<S: AsRef<str>>(&self, address: S) -> Result<Vec<BeaconWithdrawal>> {
+        self.select(
+            "SELECT json FROM beacon_withdrawals WHERE address = ?1 ORDER BY withdrawal_index",
+            [address.as_ref().to_lowercase()],
+        )
+    }
+
+    /// Get the stored blocks validated by an address, in chain order
+    pub fn validated_blocks<S: AsRef<str>>(&self, validator: S) -> Result<Vec<ValidatedBlock>> {
+        self.select(
+            "SELECT json FROM validated_blocks WHERE validator = ?1 ORDER BY block_number",
+            [validator.as_ref().to_lowercase()],
+        )
+    }
+
+    /// Get the stored logs emitted by a contract, in chain order
+    ///
+    /// With a `topic0`, only logs of that event signature are returned.
+    pub fn logs<S: AsRef<str>>(
+        &self,
+        address: S,
+        topic0: Option<&str>,
+    ) -> Result<Vec<TransactionLog>> {
+        let address = address.as_ref().to_lowercase();
+        match topic0 {
+            Some(topic0) => self.select(
+                "SELECT json FROM logs WHERE address = ?1 AND topic0 = ?2 \
+                 ORDER BY block_number, log_index",
+                [address, topic0.to_lowercase()],
+            ),
+            None => self.select(
+                "SELECT json FROM logs WHERE address = ?1 ORDER BY block_number, log_index",
+                [address],
+            ),
+        }
+    }
+
+    /// Insert rows in one SQLite transaction, appending the JSON of each model
+    fn upsert<T, F>(&self, sql: &str, rows: &[T], columns: F) -> Result<usize>
+    where
+        T: Serialize,
+        F: Fn(&T) -> Vec<Value>,
+    {
+        let mut connection = self.connection();
+        let transaction = connection.transaction()?;
+        {
+            let mut statement = transaction.prepare_cached(sql)?;
+            for row in rows {
+                let mut values = columns(row);
+                values.push(Value::Text(serde_json::to_string(row)?));
+                statement.execute(params_from_iter(values))?;
+            }
+        }
+        transaction.commit()?;
+        Ok(rows.len())
+    }
+
+    /// Run a query whose first column is the JSON of a model
+    fn select<T: DeserializeOwned, P: Params>(&self, sql: &str, params: P) -> Result<Vec<T>> {
+        let connection = self.connection();
+        let mut statement = connection.prepare_cached(sql)?;
+        let rows = statement.query_map(params, |row| row.get::<_, String>(0))?;
+
+        let mut models = Vec::new();
+        for json in rows {
+            models.push(serde_json::from_str(&json?)?);
+        }
+        Ok(models)
+    }
+}
+
+fn text(value: &str) -> Value {
+    Value::Text(value.to_string())
+}
+
+fn optional(value: Option<&str>) -> Value {
+    value.map_or(Value::Null, text)
+}
+
+/// Address, hash or topic column, lowercased so lookups match any input case
+fn key(value: &str) -> Value {
+    Value::Text(value.to_lowercase())
+}
+
+fn optional_key(value: Option<&str>) -> Value {
+    value.map_or(Value::Null, key)
+}
+
+fn integer(value: u64) -> Value {
+    Value::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn flag(value: bool) -> Value {
+    Value::Integer(i64::from(value))
+}
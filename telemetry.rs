@@ -0,0 +1,113 @@
+//! Tracing spans and optional metrics for client requests
+//!
+//! See the crate-level documentation for the span fields and metric names.
+
+use crate::{middleware::Request, EtherscanError};
+use std::time::Duration;
+use tracing::{field, Span};
+
+/// Outcome label for successful requests
+const OUTCOME_OK: &str = "ok";
+
+/// Create the span for a request
+///
+/// The `api_key` fingerprint is recorded per attempt, as retries may switch keys.
+pub(crate) fn request_span(request: &Request) -> Span {
+    let span = tracing::info_span!(
+        "etherscan.request",
+        network = %request.network,
+        chain_id = request.network.chain_id(),
+        module = %request.module,
+        action = %request.action,
+        api_key = field::Empty,
+        page = field::Empty,
+        offset = field::Empty,
+        latency_ms = field::Empty,
+        retries = field::Empty,
+        outcome = field::Empty,
+    );
+
+    if let Some(page) = request.param("page") {
+        span.record("page", page);
+    }
+    if let Some(offset) = request.param("offset") {
+        span.record("offset", offset);
+    }
+
+    span
+}
+
+/// Get the outcome label for a request result
+pub(crate) fn outcome<T>(result: &Result<T, EtherscanError>) -> &'static str {
+    match result {
+        Ok(_) => OUTCOME_OK,
+        Err(error) => error.category(),
+    }
+}
+
+/// Record the final outcome of a request on its span and in metrics
+pub(crate) fn record_completion(
+    span: &Span,
+    request: &Request,
+    elapsed: Duration,
+    retries: u32,
+    outcome: &'static str,
+) {
+    span.record("latency_ms", elapsed.as_millis() as u64);
+    span.record("retries", retries);
+    span.record("outcome", outcome);
+
+    if outcome == OUTCOME_OK {
+        tracing::debug!(parent: span, "request completed");
+    } else {
+        tracing::warn!(parent: span, "request failed");
+    }
+
+    #[cfg(feature = "metrics")]
+    {
+        let network = request.network.name();
+        let module = request.module.clone();
+        let action = request.action.clone();
+
+        metrics::counter!(
+            "etherscan_requests_total",
+            "network" => network,
+            "module" => module.clone(),
+            "action" => action.clone(),
+            "outcome" => outcome,
+        )
+        .increment(1);
+
+        metrics::histogram!(
+            "etherscan_request_duration_seconds",
+            "network" => network,
+            "module" => module.clone(),
+            "action" => action.clone(),
+        )
+        .record(elapsed.as_secs_f64());
+
+        if retries > 0 {
+            metrics::counter!(
+                "etherscan_request_retries_total",
+                "network" => network,
+                "module" => module,
+                "action" => action,
+            )
+            .increment(u64::from(retries));
+        }
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    let _ = request;
+}
+
+/// Log a failed attempt that is about to be retried
+pub(crate) fn record_retry(span: &Span, attempt: u32, error: &EtherscanError, delay: Duration) {
+    tracing::info!(
+        parent: span,
+        attempt,
+        category = error.category(),
+        delay_ms = delay.as_millis() as u64,
+        "retrying request"
+    );
+}
@@ -24,6 +24,37 @@ impl TestUtils {
             .unwrap()
     }
 
+    /// Create a client serving the cassette `tests/fixtures/cassettes/<name>.json`
+    ///
+    /// With `KEION_ETHERSCAN_RECORD` set to an API base URL, the cassette is
+    /// recorded again against that API instead, using the key in
+    /// `ETHERSCAN_API_KEY`. The committed cassettes were recorded against the
+    /// mock server seeded with `tests/fixtures/mock-chain.json`:
+    ///
+    /// ```text
+    /// cargo run --features mock-server --bin keion-etherscan-mock -- --seed tests/fixtures/mock-chain.json
+    /// KEION_ETHERSCAN_RECORD=http://127.0.0.1:8080/api cargo test --test test_integration
+    /// ```
+    pub fn cassette_client(name: &str, network: Network) -> EtherscanClient {
+        let path = format!("tests/fixtures/cassettes/{}.json", name);
+        let builder = EtherscanClient::builder().network(network);
+
+        match std::env::var("KEION_ETHERSCAN_RECORD") {
+            Ok(base_url) => builder
+                .api_key(
+                    std::env::var("ETHERSCAN_API_KEY")
+                        .unwrap_or_else(|_| keion_etherscan::mock::MOCK_API_KEY.to_string()),
+                )
+                .base_url(base_url)
+                .record_to(path),
+            Err(_) => builder
+                .api_key("test-api-key-1234567890abcdef")
+                .replay_from(path),
+        }
+        .build()
+        .unwrap()
+    }
+
     /// Valid test addresses for different scenarios
     pub fn valid_address() -> &'static str {
         "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6"
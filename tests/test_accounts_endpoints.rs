@@ -1,7 +1,9 @@
 mod common;
 
 use common::{TestConstants, TestUtils};
-use keion_etherscan::{EtherscanClient, Network, Sort, TransactionType};
+use keion_etherscan::{
+    ActivityKind, EtherscanClient, EtherscanError, Network, Sort, TransactionType,
+};
 
 /// Test all account endpoint builders (non-async tests)
 mod builder_tests {
@@ -200,6 +202,77 @@ mod builder_tests {
         // Without at_block, should default to None (latest)
         assert_eq!(query.get_block_number(), None);
     }
+
+    #[test]
+    fn test_activity_builder() {
+        let client = TestUtils::create_test_client();
+        let accounts = client.accounts();
+
+        let query = accounts.activity(TestUtils::valid_address());
+        assert_eq!(query.get_kinds(), &ActivityKind::ALL);
+        assert_eq!(query.get_pagination().sort, Some(Sort::Ascending));
+
+        let query = query
+            .exclude(ActivityKind::BeaconWithdrawal)
+            .block_range(100, 200)
+            .sort(Sort::Descending);
+        assert_eq!(query.get_kinds().len(), ActivityKind::ALL.len() - 1);
+        assert!(!query.get_kinds().contains(&ActivityKind::BeaconWithdrawal));
+        assert_eq!(query.get_pagination().start_block, Some(100));
+        assert_eq!(query.get_pagination().sort, Some(Sort::Descending));
+    }
+
+    #[test]
+    fn test_reconcile_builder() {
+        let client = TestUtils::create_test_client();
+        let accounts = client.accounts();
+
+        let query = accounts.reconcile(TestUtils::valid_address());
+        assert!(query.get_checkpoints().is_empty());
+        assert_eq!(query.get_checkpoint_step(), None);
+
+        let query = query
+            .block_range(100, 200)
+            .checkpoints(&[150, 200])
+            .checkpoint_every(25);
+        assert_eq!(query.get_address(), TestUtils::valid_address());
+        assert_eq!(query.get_pagination().start_block, Some(100));
+        assert_eq!(query.get_pagination().end_block, Some(200));
+        assert_eq!(query.get_checkpoints(), &[150, 200]);
+        assert_eq!(query.get_checkpoint_step(), Some(25));
+    }
+
+    #[test]
+    fn test_historical_balance_sample_blocks() {
+        let client = TestUtils::create_test_client();
+        let accounts = client.accounts();
+
+        let query = accounts
+            .historical_balance(TestUtils::valid_address())
+            .token(TestUtils::contract_address())
+            .range(100, 125, 10);
+
+        assert_eq!(
+            query.get_contract_address(),
+            Some(TestUtils::contract_address())
+        );
+        assert_eq!(query.sample_blocks().unwrap(), vec![100, 110, 120, 125]);
+
+        let single = accounts
+            .historical_balance(TestUtils::valid_address())
+            .range(100, 100, 10);
+        assert_eq!(single.sample_blocks().unwrap(), vec![100]);
+
+        for invalid in [(100, 200, 0), (200, 100, 10), (0, 10_000_000, 1)] {
+            let query = accounts
+                .historical_balance(TestUtils::valid_address())
+                .range(invalid.0, invalid.1, invalid.2);
+            assert!(matches!(
+                query.sample_blocks(),
+                Err(EtherscanError::InvalidParams(_))
+            ));
+        }
+    }
 }
 
 /// Test edge cases and boundary conditions
@@ -0,0 +1,189 @@
+use keion_etherscan::{BatchResult, ChunkResult, EtherscanError};
+
+fn chunk(
+    index: usize,
+    inputs: &[&str],
+    result: Result<Vec<u32>, EtherscanError>,
+) -> ChunkResult<u32> {
+    ChunkResult {
+        index,
+        inputs: inputs.iter().map(|input| input.to_string()).collect(),
+        result,
+    }
+}
+
+fn sample_batch() -> BatchResult<u32> {
+    BatchResult {
+        chunks: vec![
+            chunk(0, &["a", "b"], Ok(vec![1, 2])),
+            chunk(
+                1,
+                &["c", "d"],
+                Err(EtherscanError::rate_limit("Max rate limit reached", None)),
+            ),
+            chunk(2, &["e"], Ok(vec![5])),
+        ],
+    }
+}
+
+#[test]
+fn test_batch_keeps_successful_results_in_order() {
+    let batch = sample_batch();
+
+    assert!(!batch.is_complete());
+    assert_eq!(batch.results().copied().collect::<Vec<_>>(), vec![1, 2, 5]);
+    assert_eq!(batch.into_results(), vec![1, 2, 5]);
+}
+
+#[test]
+fn test_batch_reports_failed_chunks() {
+    let batch = sample_batch();
+
+    let failures: Vec<_> = batch.failures().collect();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].index, 1);
+    assert!(matches!(
+        failures[0].result,
+        Err(EtherscanError::RateLimit { .. })
+    ));
+    assert_eq!(batch.failed_inputs(), vec!["c", "d"]);
+}
+
+#[test]
+fn test_batch_into_result() {
+    assert!(matches!(
+        sample_batch().into_result(),
+        Err(EtherscanError::RateLimit { .. })
+    ));
+
+    let complete = BatchResult {
+        chunks: vec![chunk(0, &["a"], Ok(vec![1])), chunk(1, &["b"], Ok(vec![2]))],
+    };
+    assert!(complete.is_complete());
+    assert_eq!(complete.into_result().unwrap(), vec![1, 2]);
+}
+
+mod mock_server {
+    use keion_etherscan::middleware::{Middleware, Request};
+    use keion_etherscan::mock::{MockChain, MockContract, MockServer, MOCK_API_KEY};
+    use keion_etherscan::{EtherscanClient, EtherscanError, Result};
+
+    /// Fails every request whose address list contains `address`
+    struct FailChunkWith {
+        address: String,
+    }
+
+    impl Middleware for FailChunkWith {
+        fn on_request(&self, request: &mut Request) -> Result<()> {
+            let addresses = request
+                .param("address")
+                .or_else(|| request.param("contractaddresses"))
+                .unwrap_or_default();
+            if addresses.split(',').any(|address| address == self.address) {
+                return Err(EtherscanError::request("chunk rejected by test"));
+            }
+            Ok(())
+        }
+    }
+
+    fn address(i: usize) -> String {
+        format!("0x{:040x}", 0xa000 + i)
+    }
+
+    fn client(server: &MockServer, fail_with: String) -> EtherscanClient {
+        EtherscanClient::builder()
+            .api_key(MOCK_API_KEY)
+            .base_url(server.url())
+            .rate_limit(0)
+            .middleware(FailChunkWith { address: fail_with })
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_balance_multi_batched_over_twenty_addresses() {
+        let addresses: Vec<String> = (0..45).map(address).collect();
+        let mut chain = MockChain::new();
+        for (i, address) in addresses.iter().enumerate() {
+            chain.add_balance(address, i as u128 + 1);
+        }
+        let server = MockServer::start(chain).await.unwrap();
+        let client = client(&server, addresses[25].clone());
+
+        let batch = client
+            .accounts()
+            .balance_multi_batched(&addresses)
+            .await
+            .unwrap();
+
+        // 45 addresses make chunks of 20, 20 and 5; the second one fails
+        let sizes: Vec<usize> = batch.chunks.iter().map(|c| c.inputs.len()).collect();
+        assert_eq!(sizes, vec![20, 20, 5]);
+        assert!(!batch.is_complete());
+        assert_eq!(
+            batch.failed_inputs(),
+            addresses[20..40]
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(server.request_count(), 2);
+
+        let expected: Vec<String> = addresses[..20]
+            .iter()
+            .chain(&addresses[40..])
+            .cloned()
+            .collect();
+        let returned: Vec<String> = batch
+            .results()
+            .map(|balance| balance.account.as_ref().unwrap().as_str().to_string())
+            .collect();
+        assert_eq!(returned, expected);
+        let wei: Vec<String> = batch.results().map(|b| b.balance.to_string()).collect();
+        assert_eq!(wei[0], "1");
+        assert_eq!(wei[20], "41");
+    }
+
+    #[tokio::test]
+    async fn test_contract_creation_batched_over_five_addresses() {
+        let addresses: Vec<String> = (0..12).map(address).collect();
+        let mut chain = MockChain::new();
+        // Deployed in reverse, so the API answers every chunk out of input order
+        for (i, address) in addresses.iter().enumerate().rev() {
+            let creation_tx = format!("0x{:064x}", i);
+            chain.add_contract(MockContract::new(
+                address.as_str(),
+                "Token",
+                "0x1234567890123456789012345678901234567890",
+                &creation_tx,
+            ));
+        }
+        let server = MockServer::start(chain).await.unwrap();
+        let client = client(&server, addresses[7].clone());
+
+        let batch = client
+            .contracts()
+            .get_contract_creation_batched(&addresses)
+            .await
+            .unwrap();
+
+        let sizes: Vec<usize> = batch.chunks.iter().map(|c| c.inputs.len()).collect();
+        assert_eq!(sizes, vec![5, 5, 2]);
+        assert_eq!(
+            batch.failures().map(|c| c.index).collect::<Vec<_>>(),
+            vec![1]
+        );
+
+        let expected: Vec<&str> = addresses[..5]
+            .iter()
+            .chain(&addresses[10..])
+            .map(String::as_str)
+            .collect();
+        let returned: Vec<String> = batch
+            .results()
+            .map(|creation| creation.contract_address.as_str().to_string())
+            .collect();
+        assert_eq!(returned, expected);
+        assert!(batch.into_result().is_err());
+    }
+}
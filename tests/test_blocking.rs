@@ -0,0 +1,104 @@
+#![cfg(feature = "blocking")]
+
+use keion_etherscan::blocking::EtherscanClient;
+use keion_etherscan::mock::{MockChain, MockContract, MockServer};
+use keion_etherscan::{EtherscanError, Sort};
+use tokio::runtime::Runtime;
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const BOB: &str = "0x1234567890123456789012345678901234567890";
+const CONTRACT: &str = "0x00000000000000000000000000000000c0de0001";
+
+/// Start a mock server on its own runtime so the test thread stays synchronous
+fn serve(chain: MockChain) -> (Runtime, MockServer) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start(chain)).unwrap();
+    (runtime, server)
+}
+
+fn chain() -> MockChain {
+    let mut chain = MockChain::new();
+    chain.set_block_number(100);
+    chain.add_balance(ALICE, 1_500_000_000_000_000_000);
+    for block in 1..=5 {
+        chain.transfer_eth(block, ALICE, BOB, 250_000_000_000_000_000);
+    }
+    chain.add_contract(MockContract::new(CONTRACT, "Token", BOB, "0x01"));
+    chain
+}
+
+#[test]
+fn test_blocking_endpoints() {
+    let (_runtime, server) = serve(chain());
+    let client = EtherscanClient::from_async(server.client()).unwrap();
+
+    let balance = client.accounts().balance(ALICE).unwrap();
+    assert_eq!(balance.balance.to_string(), "1500000000000000000");
+
+    let transactions = client
+        .accounts()
+        .transactions(ALICE)
+        .sort(Sort::Descending)
+        .page(1)
+        .offset(2)
+        .execute()
+        .unwrap();
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[0].block_number.value(), 5);
+
+    assert_eq!(client.proxy().block_number().unwrap(), 100);
+    assert_eq!(client.stats().gas_oracle().unwrap().block(), 100);
+
+    let request = client
+        .contracts()
+        .verify_solidity(CONTRACT)
+        .source_code("contract Token {}")
+        .contract_name("Token")
+        .compiler_version("v0.8.24+commit.e11b9ed9")
+        .submit()
+        .unwrap();
+    let status = client
+        .contracts()
+        .check_verification_status(&request.guid)
+        .unwrap();
+    assert!(status.is_verified());
+}
+
+#[test]
+fn test_blocking_iter_follows_pages() {
+    let (_runtime, server) = serve(chain());
+    let client = EtherscanClient::from_async(server.client()).unwrap();
+
+    let blocks: Vec<u64> = client
+        .accounts()
+        .transactions(ALICE)
+        .offset(2)
+        .iter()
+        .map(|transaction| transaction.unwrap().block_number.value())
+        .collect();
+    assert_eq!(blocks, vec![1, 2, 3, 4, 5]);
+    assert_eq!(server.request_count(), 5);
+
+    // Stopping early leaves the remaining pages unfetched
+    let first = client
+        .accounts()
+        .transactions(ALICE)
+        .offset(2)
+        .iter()
+        .next();
+    assert!(first.unwrap().is_ok());
+    assert_eq!(server.request_count(), 6);
+}
+
+#[test]
+fn test_blocking_errors() {
+    let (_runtime, server) = serve(chain());
+    let client = EtherscanClient::from_async(server.client()).unwrap();
+
+    let error = client.logs().get_logs().execute().unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+
+    let error = client.accounts().balance("not-an-address").unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidAddress(_)));
+    assert_eq!(server.request_count(), 0);
+}
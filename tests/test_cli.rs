@@ -0,0 +1,109 @@
+#![cfg(feature = "cli")]
+
+use keion_etherscan::mock::{MockChain, MockContract, MockServer, MOCK_API_KEY};
+use std::process::Output;
+use tokio::process::Command;
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const BOB: &str = "0x1234567890123456789012345678901234567890";
+const CONTRACT: &str = "0x00000000000000000000000000000000c0de0001";
+
+async fn cli(server: &MockServer, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_keion-etherscan"))
+        .env_remove("ETHERSCAN_API_KEY")
+        .env_remove("ETHERSCAN_BASE_URL")
+        .args(["--base-url", &server.url(), "--api-key", MOCK_API_KEY])
+        .args(args)
+        .output()
+        .await
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn chain() -> MockChain {
+    let mut chain = MockChain::new();
+    chain.add_balance(ALICE, 1_500_000_000_000_000_000);
+    for block in 1..=5 {
+        chain.transfer_eth(block, ALICE, BOB, 250_000_000_000_000_000);
+    }
+    chain.add_contract(MockContract::new(CONTRACT, "Token", BOB, "0x01"));
+    chain
+}
+
+#[tokio::test]
+async fn test_cli_balance_and_gas() {
+    let server = MockServer::start(chain()).await.unwrap();
+
+    let json = stdout(&cli(&server, &["--output", "json", "balance", ALICE]).await);
+    let balances: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(balances[0]["balance"], "1500000000000000000");
+
+    let table = stdout(&cli(&server, &["balance", ALICE]).await);
+    let lines: Vec<&str> = table.lines().collect();
+    assert!(lines[0].starts_with("address"));
+    assert!(lines[1].starts_with("---"));
+    assert!(lines[2].ends_with("1.5"));
+
+    let gas = stdout(&cli(&server, &["-o", "csv", "gas"]).await);
+    assert!(gas.starts_with("field,value\n"));
+    assert!(gas.contains("propose_gwei,1\n"));
+}
+
+#[tokio::test]
+async fn test_cli_transactions_follow_pages() {
+    let server = MockServer::start(chain()).await.unwrap();
+
+    let csv = stdout(&cli(&server, &["-o", "csv", "txs", ALICE, "--page-size", "2"]).await);
+    let rows: Vec<&str> = csv.lines().skip(1).collect();
+    assert_eq!(rows.len(), 5);
+    assert!(rows.iter().all(|row| row.contains(",0.25,")));
+    // Each page restarts at its last block, so one new block per request
+    assert_eq!(server.request_count(), 5);
+
+    let limited = stdout(&cli(&server, &["-o", "json", "txs", ALICE, "--limit", "2"]).await);
+    let transactions: Vec<serde_json::Value> = serde_json::from_str(&limited).unwrap();
+    assert_eq!(transactions.len(), 2);
+}
+
+#[tokio::test]
+async fn test_cli_verify_and_errors() {
+    let server = MockServer::start(chain()).await.unwrap();
+    let source = std::env::temp_dir().join(format!("cli-verify-{}.sol", std::process::id()));
+    std::fs::write(&source, "contract Token {}").unwrap();
+
+    let output = stdout(
+        &cli(
+            &server,
+            &[
+                "verify",
+                CONTRACT,
+                "--source",
+                source.to_str().unwrap(),
+                "--name",
+                "Token",
+                "--compiler",
+                "v0.8.24+commit.e11b9ed9",
+                "--wait",
+            ],
+        )
+        .await,
+    );
+    assert!(output.contains("Pass - Verified"));
+    std::fs::remove_file(&source).unwrap();
+
+    let output = cli(
+        &server,
+        &["abi", "0x0000000000000000000000000000000000000001"],
+    )
+    .await;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error:"));
+}
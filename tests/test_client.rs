@@ -20,6 +20,30 @@ fn test_missing_api_key() {
     assert!(matches!(result, Err(EtherscanError::MissingApiKey)));
 }
 
+#[test]
+fn test_api_key_pool() {
+    let result = EtherscanClient::builder()
+        .api_keys(Vec::<String>::new())
+        .build();
+    assert!(matches!(result, Err(EtherscanError::MissingApiKey)));
+
+    let client = EtherscanClient::builder()
+        .api_keys(["1111111111aaaa", "2222222222bbbb", "1111111111aaaa"])
+        .build()
+        .unwrap();
+    let keys: Vec<String> = client
+        .key_usage()
+        .into_iter()
+        .map(|usage| usage.key)
+        .collect();
+    assert_eq!(keys, vec!["1111...aaaa", "2222...bbbb"]);
+    assert_eq!(client.api_key_preview(), "1111...aaaa");
+
+    let debug = format!("{:?}", client);
+    assert!(!debug.contains("1111111111aaaa"));
+    assert!(!debug.contains("2222222222bbbb"));
+}
+
 #[test]
 fn test_api_key_preview() {
     let client = EtherscanClient::builder()
@@ -60,3 +84,27 @@ fn test_default_builder() {
     let client = builder.api_key("test-key").build().unwrap();
     assert_eq!(client.network(), Network::Mainnet); // Default network
 }
+
+#[test]
+fn test_module_and_action_names() {
+    use keion_etherscan::{Action, Call, Endpoint, Module, TransactionType};
+
+    assert_eq!(Module::from("gastracker"), Module::GasTracker);
+    assert_eq!(Module::Account.to_string(), "account");
+    assert_eq!(Action::from("getLogs"), Action::GetLogs);
+    assert_eq!(Action::EthBlockNumber.as_str(), "eth_blockNumber");
+    assert_eq!(
+        Action::from("dailytx"),
+        Action::Other("dailytx".to_string())
+    );
+    assert_eq!(Action::from(TransactionType::TokenNft), Action::TokenNftTx);
+
+    let call: Call = Call::new("stats", "ethsupply").with_params(&[("chainid", "1")]);
+    assert_eq!(call.module(), Module::Stats);
+    assert_eq!(call.action(), Action::Other("ethsupply".to_string()));
+    assert_eq!(
+        call.params().unwrap(),
+        vec![("chainid".to_string(), "1".to_string())]
+    );
+    assert!(call.form().unwrap().is_empty());
+}
@@ -8,30 +8,18 @@ fn test_error_display() {
     let err = EtherscanError::MissingApiKey;
     assert_eq!(err.to_string(), "API key is required but not provided");
 
-    let err = EtherscanError::Http {
-        status: 404,
-        message: "Not Found".to_string(),
-    };
+    let err = EtherscanError::http(404, "Not Found");
     assert_eq!(err.to_string(), "HTTP error 404: Not Found");
 
-    let err = EtherscanError::Api {
-        message: "Invalid address".to_string(),
-        result: Some("0".to_string()),
-    };
+    let err = EtherscanError::api_with_result("Invalid address", "0");
     assert_eq!(err.to_string(), "API error: Invalid address (result: 0)");
 }
 
 #[test]
 fn test_error_categories() {
     assert_eq!(EtherscanError::MissingApiKey.category(), "configuration");
-    assert_eq!(
-        EtherscanError::Request("test".to_string()).category(),
-        "network"
-    );
-    assert_eq!(
-        EtherscanError::Parse("test".to_string()).category(),
-        "parsing"
-    );
+    assert_eq!(EtherscanError::request("test").category(), "network");
+    assert_eq!(EtherscanError::parse("test").category(), "parsing");
     assert_eq!(
         EtherscanError::InvalidAddress("bad".to_string()).category(),
         "validation"
@@ -44,21 +32,17 @@ fn test_error_categories() {
         .category(),
         "rate_limit"
     );
+    assert_eq!(
+        EtherscanError::plan_restricted("balancehistory", "API Pro endpoint").category(),
+        "plan"
+    );
 }
 
 #[test]
 fn test_retryable_errors() {
-    assert!(EtherscanError::Request("timeout".to_string()).is_retryable());
-    assert!(EtherscanError::Http {
-        status: 500,
-        message: "server error".to_string()
-    }
-    .is_retryable());
-    assert!(EtherscanError::Http {
-        status: 502,
-        message: "bad gateway".to_string()
-    }
-    .is_retryable());
+    assert!(EtherscanError::request("timeout").is_retryable());
+    assert!(EtherscanError::http(500, "server error").is_retryable());
+    assert!(EtherscanError::http(502, "bad gateway").is_retryable());
     assert!(EtherscanError::RateLimit {
         retry_after: Some(60),
         message: "limit".to_string()
@@ -69,23 +53,17 @@ fn test_retryable_errors() {
     // Non-retryable errors
     assert!(!EtherscanError::MissingApiKey.is_retryable());
     assert!(!EtherscanError::InvalidAddress("bad".to_string()).is_retryable());
-    assert!(!EtherscanError::Http {
-        status: 400,
-        message: "bad request".to_string()
-    }
-    .is_retryable());
-    assert!(!EtherscanError::Http {
-        status: 404,
-        message: "not found".to_string()
-    }
-    .is_retryable());
+    assert!(!EtherscanError::http(400, "bad request").is_retryable());
+    assert!(!EtherscanError::http(404, "not found").is_retryable());
 }
 
 #[test]
 fn test_error_constructors() {
     let api_err = EtherscanError::api("Something went wrong");
     match api_err {
-        EtherscanError::Api { message, result } => {
+        EtherscanError::Api {
+            message, result, ..
+        } => {
             assert_eq!(message, "Something went wrong");
             assert_eq!(result, None);
         }
@@ -94,7 +72,9 @@ fn test_error_constructors() {
 
     let api_err_with_result = EtherscanError::api_with_result("Failed", "0");
     match api_err_with_result {
-        EtherscanError::Api { message, result } => {
+        EtherscanError::Api {
+            message, result, ..
+        } => {
             assert_eq!(message, "Failed");
             assert_eq!(result, Some("0".to_string()));
         }
@@ -193,7 +173,9 @@ fn test_error_from_conversions() {
     let json_err = serde_json::from_str::<i32>("invalid json");
     let etherscan_err: EtherscanError = json_err.unwrap_err().into();
     match etherscan_err {
-        EtherscanError::Parse(_) => {} // Expected
+        EtherscanError::Parse { .. } => {} // Expected
         _ => panic!("Expected parse error"),
     }
+    assert!(std::error::Error::source(&etherscan_err).is_some());
+    assert!(etherscan_err.context().is_none());
 }
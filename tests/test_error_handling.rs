@@ -178,6 +178,37 @@ mod address_validation_tests {
             EtherscanError::InvalidAddress(_)
         ));
     }
+
+    #[tokio::test]
+    async fn test_batched_validation() {
+        let client = TestUtils::create_test_client();
+
+        // Empty input is rejected before any request is made
+        let result = client
+            .accounts()
+            .balance_multi_batched(&Vec::<&str>::new())
+            .await;
+        assert!(matches!(result, Err(EtherscanError::InvalidParams(_))));
+
+        let result = client
+            .contracts()
+            .get_contract_creation_batched(&Vec::<&str>::new())
+            .await;
+        assert!(matches!(result, Err(EtherscanError::InvalidParams(_))));
+
+        // A single invalid address fails the whole batch up front
+        let mut addresses: Vec<&str> = (0..45).map(|_| TestUtils::valid_address()).collect();
+        addresses.push(TestUtils::invalid_address_non_hex());
+
+        let result = client.accounts().balance_multi_batched(&addresses).await;
+        assert!(matches!(result, Err(EtherscanError::InvalidAddress(_))));
+
+        let result = client
+            .contracts()
+            .get_contract_creation_batched(&addresses)
+            .await;
+        assert!(matches!(result, Err(EtherscanError::InvalidAddress(_))));
+    }
 }
 
 /// Test API error responses
@@ -192,7 +223,10 @@ mod api_error_tests {
         let error = EtherscanError::api("Invalid address format");
         assert!(matches!(error, EtherscanError::Api { .. }));
 
-        if let EtherscanError::Api { message, result } = error {
+        if let EtherscanError::Api {
+            message, result, ..
+        } = error
+        {
             assert_eq!(message, "Invalid address format");
             assert_eq!(result, None);
         }
@@ -215,25 +249,22 @@ mod api_error_tests {
 
     #[tokio::test]
     async fn test_network_error() {
-        let error = EtherscanError::Request("Connection timeout".to_string());
-        assert!(matches!(error, EtherscanError::Request(_)));
+        let error = EtherscanError::request("Connection timeout");
+        assert!(matches!(error, EtherscanError::Request { .. }));
         assert!(error.is_retryable());
     }
 
     #[tokio::test]
     async fn test_http_error() {
-        let error = EtherscanError::Http {
-            status: 500,
-            message: "Internal Server Error".to_string(),
-        };
+        let error = EtherscanError::http(500, "Internal Server Error");
         assert!(matches!(error, EtherscanError::Http { .. }));
         assert!(error.is_retryable());
     }
 
     #[tokio::test]
     async fn test_parse_error() {
-        let error = EtherscanError::Parse("Invalid JSON response".to_string());
-        assert!(matches!(error, EtherscanError::Parse(_)));
+        let error = EtherscanError::parse("Invalid JSON response");
+        assert!(matches!(error, EtherscanError::Parse { .. }));
         assert!(!error.is_retryable());
     }
 
@@ -245,7 +276,7 @@ mod api_error_tests {
         let api_error = EtherscanError::api("Something went wrong");
         assert_eq!(api_error.category(), "api");
 
-        let network_error = EtherscanError::Request("Timeout".to_string());
+        let network_error = EtherscanError::request("Timeout");
         assert_eq!(network_error.category(), "network");
 
         let rate_limit_error = EtherscanError::rate_limit("Too many requests", None);
@@ -267,8 +298,8 @@ mod malformed_data_tests {
         assert!(parse_result.is_err());
 
         // In the actual implementation, this would be converted to EtherscanError::Parse
-        let error = EtherscanError::Parse("JSON parse error".to_string());
-        assert!(matches!(error, EtherscanError::Parse(_)));
+        let error = EtherscanError::parse("JSON parse error");
+        assert!(matches!(error, EtherscanError::Parse { .. }));
     }
 
     #[test]
@@ -416,10 +447,7 @@ mod edge_case_error_tests {
         let display = format!("{}", address_error);
         assert!(display.contains("Invalid Ethereum address: 0x123"));
 
-        let api_error = EtherscanError::Api {
-            message: "Invalid API key".to_string(),
-            result: Some("Unauthorized".to_string()),
-        };
+        let api_error = EtherscanError::api_with_result("Invalid API key", "Unauthorized");
         let display = format!("{}", api_error);
         assert!(display.contains("API error: Invalid API key"));
         assert!(display.contains("result: Unauthorized"));
@@ -0,0 +1,149 @@
+use keion_etherscan::export::{write_stream, CsvWriter, NdjsonWriter, RecordWriter};
+use keion_etherscan::mock::{MockChain, MockServer};
+use keion_etherscan::{TokenTransfer, Transaction};
+use serde_json::json;
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const BOB: &str = "0x1234567890123456789012345678901234567890";
+
+fn transfer(value: &str, token_name: &str) -> TokenTransfer {
+    serde_json::from_value(json!({
+        "blockNumber": "17000000",
+        "blockHash": format!("0x{:064x}", 1),
+        "hash": format!("0x{:064x}", 2),
+        "transactionIndex": "0",
+        "from": ALICE,
+        "to": BOB,
+        "contractAddress": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+        "value": value,
+        "tokenName": token_name,
+        "tokenSymbol": "USDT",
+        "tokenDecimal": "6",
+        "gasPrice": "1",
+        "gasUsed": "50000",
+        "timeStamp": "1681338479",
+        "logIndex": "3"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_csv_token_transfers() {
+    let mut writer = CsvWriter::new(Vec::new());
+    writer
+        .write_all(&[
+            transfer("1500000", "Tether USD"),
+            transfer("250", "Tether, \"USD\""),
+        ])
+        .unwrap();
+    let csv = String::from_utf8(writer.finish().unwrap()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        "block_number,timestamp,datetime,hash,log_index,contract_address,token_name,\
+         token_symbol,token_decimal,token_id,from,to,value,amount"
+    );
+    assert!(lines[1].starts_with("17000000,1681338479,2023-04-12T22:27:59Z,0x"));
+    assert!(lines[1].ends_with(&format!(
+        ",Tether USD,USDT,6,,{},{},1500000,1.5",
+        ALICE, BOB
+    )));
+    assert!(lines[2].contains(",\"Tether, \"\"USD\"\"\",USDT,"));
+    assert!(lines[2].ends_with(",250,0.00025"));
+}
+
+#[test]
+fn test_ndjson_round_trip() {
+    let transfers = vec![transfer("1", "Tether USD"), transfer("2", "Tether USD")];
+
+    let mut writer = NdjsonWriter::new(Vec::new());
+    writer.write_all(&transfers).unwrap();
+    let ndjson = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    let parsed: Vec<TokenTransfer> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(parsed, transfers);
+}
+
+#[tokio::test]
+async fn test_export_paginated_stream() {
+    let mut chain = MockChain::new();
+    for block in 1..=5 {
+        chain.transfer_eth(block, ALICE, BOB, 250_000_000_000_000_000);
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let mut writer = CsvWriter::new(Vec::new());
+    let stream = client.accounts().transactions(ALICE).offset(2).stream();
+    let written = write_stream(stream, &mut writer).await.unwrap();
+    assert_eq!(written, 5);
+
+    let csv = String::from_utf8(writer.finish().unwrap()).unwrap();
+    let mut lines = csv.lines();
+    let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let value_eth = header.iter().position(|c| *c == "value_eth").unwrap();
+    let fee_eth = header.iter().position(|c| *c == "fee_eth").unwrap();
+
+    let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+    assert_eq!(rows.len(), 5);
+    let blocks: Vec<&str> = rows.iter().map(|row| row[0]).collect();
+    assert_eq!(blocks, vec!["1", "2", "3", "4", "5"]);
+    assert!(rows.iter().all(|row| row[value_eth] == "0.25"));
+    assert!(rows.iter().all(|row| row[fee_eth] == "0.000021"));
+
+    // The NDJSON writer accepts the same stream of models
+    let mut writer = NdjsonWriter::new(Vec::new());
+    let stream = client.accounts().transactions(ALICE).offset(2).stream();
+    write_stream(stream, &mut writer).await.unwrap();
+    let ndjson = String::from_utf8(writer.finish().unwrap()).unwrap();
+    let parsed: Vec<Transaction> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(parsed.len(), 5);
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_typed_columns() {
+    use keion_etherscan::export::ParquetWriter;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    let path = std::env::temp_dir().join(format!("export-{}.parquet", std::process::id()));
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = ParquetWriter::<_, TokenTransfer>::new(file)
+        .unwrap()
+        .batch_size(2);
+    writer
+        .write_all(&[
+            transfer("1", "Tether USD"),
+            transfer("2", "Tether USD"),
+            transfer("3", "Tether USD"),
+        ])
+        .unwrap();
+    writer.finish().unwrap();
+
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.file_metadata().num_rows(), 3);
+    assert_eq!(metadata.num_row_groups(), 2);
+
+    let schema = metadata.file_metadata().schema_descr();
+    assert_eq!(schema.column(0).name(), "block_number");
+    assert_eq!(
+        schema.column(0).physical_type(),
+        parquet::basic::Type::INT64
+    );
+    assert_eq!(schema.column(13).name(), "amount");
+    assert_eq!(
+        schema.column(13).physical_type(),
+        parquet::basic::Type::BYTE_ARRAY
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::TestUtils;
+use keion_etherscan::middleware::{Middleware, Request, Response};
+use keion_etherscan::{EtherscanClient, EtherscanError, Network};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Records every hook invocation as a short string
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn on_request(&self, request: &mut Request) -> keion_etherscan::Result<()> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("request {}/{}", request.module, request.action));
+        Ok(())
+    }
+
+    fn on_response(&self, request: &Request, response: &Response<'_>) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("response {} {}", request.action, response.status));
+    }
+
+    fn on_error(&self, request: &Request, error: &EtherscanError, _elapsed: Duration) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("error {} {}", request.action, error.category()));
+    }
+}
+
+/// Fails every request for a given action
+struct FaultInjector {
+    action: &'static str,
+}
+
+impl Middleware for FaultInjector {
+    fn on_request(&self, request: &mut Request) -> keion_etherscan::Result<()> {
+        if request.action == self.action {
+            return Err(EtherscanError::rate_limit("injected", Some(1)));
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_fault_injection_aborts_request() {
+    let recorder = Recorder::default();
+    let client = EtherscanClient::builder()
+        .api_key("test-api-key-1234567890abcdef")
+        .network(Network::Mainnet)
+        .middleware(recorder.clone())
+        .middleware(FaultInjector { action: "balance" })
+        .build()
+        .unwrap();
+
+    let result = client.accounts().balance(TestUtils::valid_address()).await;
+
+    assert!(matches!(
+        result,
+        Err(EtherscanError::RateLimit {
+            retry_after: Some(1),
+            ..
+        })
+    ));
+
+    // The recorder runs first, sees the request, and is told about the injected error
+    let events = recorder.events.lock().unwrap();
+    assert_eq!(
+        *events,
+        vec![
+            "request account/balance".to_string(),
+            "error balance rate_limit".to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_validation_errors_skip_middleware() {
+    let recorder = Recorder::default();
+    let client = EtherscanClient::builder()
+        .api_key("test-api-key-1234567890abcdef")
+        .middleware(recorder.clone())
+        .build()
+        .unwrap();
+
+    let result = client
+        .accounts()
+        .balance(TestUtils::invalid_address_too_short())
+        .await;
+
+    assert!(matches!(result, Err(EtherscanError::InvalidAddress(_))));
+    assert!(recorder.events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_request_param_helpers() {
+    struct ParamRewriter;
+
+    impl Middleware for ParamRewriter {
+        fn on_request(&self, request: &mut Request) -> keion_etherscan::Result<()> {
+            request.set_param("tag", "earliest");
+            request.set_param("custom", "1");
+            request.remove_param("address");
+            request
+                .headers
+                .insert("x-team", "portfolio".parse().unwrap());
+            Ok(())
+        }
+    }
+
+    let mut request = Request {
+        network: Network::Mainnet,
+        module: "account".to_string(),
+        action: "balance".to_string(),
+        params: vec![
+            (
+                "address".to_string(),
+                TestUtils::valid_address().to_string(),
+            ),
+            ("tag".to_string(), "latest".to_string()),
+        ],
+        headers: Default::default(),
+    };
+
+    ParamRewriter.on_request(&mut request).unwrap();
+
+    assert_eq!(request.param("tag"), Some("earliest"));
+    assert_eq!(request.param("custom"), Some("1"));
+    assert_eq!(request.param("address"), None);
+    assert_eq!(request.headers["x-team"], "portfolio");
+}
@@ -0,0 +1,1866 @@
+use keion_etherscan::mock::{MockChain, MockConfig, MockContract, MockServer, MOCK_API_KEY};
+use keion_etherscan::models::BlockchainData;
+use keion_etherscan::vcr::Cassette;
+use keion_etherscan::{
+    EtherscanClient, EtherscanError, ExecutionStatus, KeySelection, Sort, TokenStandard,
+};
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const BOB: &str = "0x1234567890123456789012345678901234567890";
+
+fn seeded_chain() -> MockChain {
+    let mut chain = MockChain::new();
+    chain.add_balance(ALICE, 2 * 10u128.pow(18));
+    for block in 1..=25 {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+    }
+    chain
+}
+
+#[tokio::test]
+async fn test_mock_balance() {
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+
+    let balance = client.accounts().balance(ALICE).await.unwrap();
+    assert_eq!(balance.eth(), Some(2.0));
+
+    // Unknown addresses have a zero balance
+    let balance = client.accounts().balance(BOB).await.unwrap();
+    assert_eq!(balance.wei(), "0");
+
+    let balances = client
+        .accounts()
+        .balance_multi(&[ALICE, BOB])
+        .await
+        .unwrap();
+    assert_eq!(balances.len(), 2);
+    assert_eq!(server.request_count(), 3);
+}
+
+#[tokio::test]
+async fn test_mock_pagination() {
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+
+    let mut blocks = Vec::new();
+    for page in 1..=3 {
+        let transactions = client
+            .accounts()
+            .transactions(ALICE)
+            .page(page)
+            .offset(10)
+            .sort(Sort::Ascending)
+            .execute()
+            .await
+            .unwrap();
+        blocks.extend(transactions.iter().map(|tx| tx.block()));
+    }
+    assert_eq!(blocks, (1..=25).collect::<Vec<_>>());
+
+    // Past the last page Etherscan answers with an empty list
+    let empty = client
+        .accounts()
+        .transactions(ALICE)
+        .page(4)
+        .offset(10)
+        .execute()
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+
+    let latest = client
+        .accounts()
+        .transactions(BOB)
+        .block_range(20, 22)
+        .sort(Sort::Descending)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(
+        latest.iter().map(|tx| tx.block()).collect::<Vec<_>>(),
+        vec![22, 21, 20]
+    );
+
+    let request = &server.requests()[4];
+    assert_eq!(request.get("startblock").map(String::as_str), Some("20"));
+    assert!(!request.contains_key("apikey"));
+}
+
+#[tokio::test]
+async fn test_mock_result_window() {
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+
+    let result = server
+        .client()
+        .accounts()
+        .transactions(ALICE)
+        .page(2)
+        .offset(10_000)
+        .execute()
+        .await;
+    match result {
+        Err(EtherscanError::Api { result, .. }) => {
+            assert!(result.unwrap().contains("Result window is too large"));
+        }
+        other => panic!("expected API error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_invalid_api_key() {
+    let server = MockServer::start(MockChain::new()).await.unwrap();
+    let client = EtherscanClient::builder()
+        .api_key("wrong-key")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    assert_eq!(error.category(), "api");
+    assert!(error.to_string().contains("Invalid API Key"));
+}
+
+#[tokio::test]
+async fn test_mock_contracts() {
+    let mut chain = MockChain::new();
+    let contracts: Vec<String> = (1..=7)
+        .map(|i| format!("0x{:040x}", 0xc0de0000u64 + i))
+        .collect();
+    for (i, address) in contracts.iter().enumerate() {
+        let creation_tx = format!("0x{:064x}", i);
+        chain.add_contract(MockContract::new(
+            address.as_str(),
+            "Token",
+            BOB,
+            &creation_tx,
+        ));
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let abi = client.contracts().get_abi(&contracts[0]).await.unwrap();
+    assert!(abi.parse_abi().unwrap().is_array());
+
+    let missing = client
+        .contracts()
+        .get_abi("0x0000000000000000000000000000000000000001")
+        .await
+        .unwrap_err();
+    assert_eq!(missing.category(), "api");
+
+    let creations = client
+        .contracts()
+        .get_contract_creation_batched(&contracts)
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    let addresses: Vec<String> = creations
+        .iter()
+        .map(|creation| creation.contract_address.as_str().to_string())
+        .collect();
+    assert_eq!(addresses, contracts);
+}
+
+#[tokio::test]
+async fn test_mock_rate_limit() {
+    let config = MockConfig::default().rate_limit(2);
+    let server = MockServer::start_with(seeded_chain(), config)
+        .await
+        .unwrap();
+    let client = server.client();
+
+    client.accounts().balance(ALICE).await.unwrap();
+    client.accounts().balance(ALICE).await.unwrap();
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::RateLimit { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_http_failures_are_retried() {
+    let config = MockConfig::default().http_failures(2);
+    let server = MockServer::start_with(seeded_chain(), config)
+        .await
+        .unwrap();
+
+    let error = server.client().accounts().balance(ALICE).await.unwrap_err();
+    assert_eq!(error.category(), "http");
+
+    let client = EtherscanClient::builder()
+        .api_key(MOCK_API_KEY)
+        .base_url(server.url())
+        .rate_limit(0)
+        .max_retries(2)
+        .build()
+        .unwrap();
+    let balance = client.accounts().balance(ALICE).await.unwrap();
+    assert_eq!(balance.eth(), Some(2.0));
+    assert_eq!(server.request_count(), 3);
+}
+
+#[tokio::test]
+async fn test_mock_chain_updates_while_running() {
+    let server = MockServer::start(MockChain::new()).await.unwrap();
+    let client = server.client();
+
+    assert!(client
+        .accounts()
+        .transactions(ALICE)
+        .execute()
+        .await
+        .unwrap()
+        .is_empty());
+
+    server.chain().transfer_eth(100, ALICE, BOB, 5);
+    let transactions = client
+        .accounts()
+        .transactions(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(
+        transactions[0].timestamp(),
+        Some(MockChain::block_timestamp(100))
+    );
+}
+
+#[tokio::test]
+async fn test_mock_records_cassette() {
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let path =
+        std::env::temp_dir().join(format!("keion-etherscan-mock-{}.json", std::process::id()));
+
+    let recorder = EtherscanClient::builder()
+        .api_key(MOCK_API_KEY)
+        .base_url(server.url())
+        .record_to(&path)
+        .build()
+        .unwrap();
+    recorder.accounts().balance(ALICE).await.unwrap();
+    recorder.save_cassette().unwrap();
+    drop(server);
+
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 1);
+    assert!(!cassette.interactions[0].body.contains(MOCK_API_KEY));
+
+    let replayer = EtherscanClient::builder()
+        .api_key("unused")
+        .replay_from(&path)
+        .build()
+        .unwrap();
+    let balance = replayer.accounts().balance(ALICE).await.unwrap();
+    assert_eq!(balance.eth(), Some(2.0));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn test_mock_historical_balances() {
+    const TOKEN: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+
+    let mut chain = MockChain::new();
+    chain
+        .set_balance_at(ALICE, 100, 5)
+        .set_balance_at(ALICE, 150, 7)
+        .set_token_balance_at(TOKEN, ALICE, 120, 1_000_000);
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let balance = client
+        .accounts()
+        .historical_balance(ALICE)
+        .at_block(149)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(balance.wei(), "5");
+
+    let series = client
+        .accounts()
+        .historical_balance(ALICE)
+        .range(50, 200, 50)
+        .execute_series()
+        .await
+        .unwrap();
+    let points: Vec<(u64, &str)> = series
+        .iter()
+        .map(|point| (point.block_number, point.wei()))
+        .collect();
+    assert_eq!(points, vec![(50, "0"), (100, "5"), (150, "7"), (200, "7")]);
+
+    let token_balance = client
+        .accounts()
+        .historical_balance(ALICE)
+        .token(TOKEN)
+        .at_block(130)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(token_balance.wei(), "1000000");
+
+    let actions: Vec<String> = server
+        .requests()
+        .iter()
+        .filter_map(|request| request.get("action").cloned())
+        .collect();
+    assert_eq!(actions[0], "balancehistory");
+    assert_eq!(actions.last().unwrap(), "tokenbalancehistory");
+}
+
+#[tokio::test]
+async fn test_mock_historical_balance_requires_pro_plan() {
+    let config = MockConfig::default().free_tier();
+    let server = MockServer::start_with(MockChain::new(), config)
+        .await
+        .unwrap();
+    let client = server.client();
+
+    let error = client
+        .accounts()
+        .historical_balance(ALICE)
+        .at_block(100)
+        .execute()
+        .await
+        .unwrap_err();
+    match error {
+        EtherscanError::PlanRestricted { action, .. } => assert_eq!(action, "balancehistory"),
+        other => panic!("expected plan restriction, got {:?}", other),
+    }
+
+    // The latest balance uses the free action
+    let balance = client
+        .accounts()
+        .historical_balance(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(balance.wei(), "0");
+}
+
+#[tokio::test]
+async fn test_mock_time_ranges() {
+    use keion_etherscan::chrono::DateTime;
+    use keion_etherscan::Closest;
+
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+    let time =
+        |block: u64| DateTime::from_timestamp(MockChain::block_timestamp(block) as i64, 0).unwrap();
+
+    // Halfway between blocks 4 and 5
+    let between_blocks =
+        DateTime::from_timestamp(MockChain::block_timestamp(4) as i64 + 6, 0).unwrap();
+    let blocks = client.blocks();
+    assert_eq!(
+        blocks
+            .block_number_by_time(between_blocks, Closest::Before)
+            .await
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        blocks
+            .block_number_by_time(between_blocks, Closest::After)
+            .await
+            .unwrap(),
+        5
+    );
+
+    let transactions = client
+        .accounts()
+        .transactions(ALICE)
+        .between(between_blocks, time(8))
+        .sort(Sort::Ascending)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(
+        transactions.iter().map(|tx| tx.block()).collect::<Vec<_>>(),
+        vec![5, 6, 7, 8]
+    );
+    assert_eq!(transactions[0].datetime(), Some(time(5)));
+
+    // Both bounds were cached by the direct lookups above or resolved once
+    let lookups = server
+        .requests()
+        .iter()
+        .filter(|request| request.get("action").map(String::as_str) == Some("getblocknobytime"))
+        .count();
+    assert_eq!(lookups, 3);
+
+    let error = client
+        .accounts()
+        .transactions(ALICE)
+        .between(time(8), time(5))
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+
+    let internal = client
+        .accounts()
+        .internal_transactions()
+        .by_block_range(0, 100)
+        .since(time(20))
+        .execute()
+        .await
+        .unwrap();
+    assert!(internal.is_empty());
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.get("startblock").map(String::as_str), Some("20"));
+    assert_eq!(request.get("endblock").map(String::as_str), Some("100"));
+
+    client
+        .accounts()
+        .internal_transactions()
+        .by_block_range(0, 100)
+        .between(between_blocks, time(8))
+        .execute()
+        .await
+        .unwrap();
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.get("startblock").map(String::as_str), Some("5"));
+    assert_eq!(request.get("endblock").map(String::as_str), Some("8"));
+}
+
+#[tokio::test]
+async fn test_mock_activity_feed() {
+    use keion_etherscan::{ActivityKind, Direction, InternalTransaction, TokenTransfer};
+    use serde_json::json;
+
+    const TOKEN: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+
+    let mut chain = MockChain::new();
+    let sent = chain.transfer_eth(10, ALICE, BOB, 1_000);
+    let received = chain.transfer_eth(20, BOB, ALICE, 2_000);
+    chain.transfer_eth(30, ALICE, ALICE, 0);
+    chain.add_internal_transaction(
+        serde_json::from_value::<InternalTransaction>(json!({
+            "blockNumber": "20",
+            "timeStamp": MockChain::block_timestamp(20).to_string(),
+            "hash": received.as_str(),
+            "from": BOB,
+            "to": ALICE,
+            "value": "5",
+            "contractAddress": "",
+            "input": "",
+            "type": "call",
+            "gas": "0",
+            "gasUsed": "0",
+            "traceId": "0_1",
+            "isError": "0",
+            "errCode": ""
+        }))
+        .unwrap(),
+    );
+    chain.add_token_transfer(
+        serde_json::from_value::<TokenTransfer>(json!({
+            "blockNumber": "10",
+            "timeStamp": MockChain::block_timestamp(10).to_string(),
+            "hash": sent.as_str(),
+            "blockHash": "0x0",
+            "transactionIndex": "0",
+            "from": ALICE,
+            "to": BOB,
+            "contractAddress": TOKEN,
+            "value": "1000000",
+            "tokenName": "Tether USD",
+            "tokenSymbol": "USDT",
+            "tokenDecimal": "6",
+            "gasPrice": "1000000000",
+            "gasUsed": "21000",
+            "logIndex": "0"
+        }))
+        .unwrap(),
+    );
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let feed = client
+        .accounts()
+        .activity(ALICE)
+        .block_range(0, 100)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        feed.iter()
+            .map(|group| group.block_number)
+            .collect::<Vec<_>>(),
+        vec![10, 20, 30]
+    );
+    assert_eq!(feed[0].tx_hash.as_ref(), Some(&sent));
+    assert_eq!(
+        feed[0].events.iter().map(|e| e.kind()).collect::<Vec<_>>(),
+        vec![ActivityKind::Transaction, ActivityKind::Erc20Transfer]
+    );
+    assert_eq!(feed[0].direction(), Direction::Out);
+    assert_eq!(feed[1].direction(), Direction::In);
+    assert!(feed[1].contains(ActivityKind::InternalTransaction));
+    assert_eq!(feed[2].direction(), Direction::SelfTransfer);
+
+    // One request per kind, all sharing the block range
+    let requests = server.requests();
+    assert_eq!(requests.len(), ActivityKind::ALL.len());
+    assert!(requests
+        .iter()
+        .all(|request| request.get("endblock").map(String::as_str) == Some("100")));
+
+    let latest_first = client
+        .accounts()
+        .activity(ALICE)
+        .kinds(&[ActivityKind::Transaction])
+        .sort(Sort::Descending)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(latest_first[0].block_number, 30);
+    assert_eq!(server.request_count(), ActivityKind::ALL.len() + 1);
+}
+
+#[tokio::test]
+async fn test_mock_activity_feed_follows_pages() {
+    use keion_etherscan::{ActivityKind, BeaconWithdrawal};
+    use serde_json::json;
+
+    let mut chain = MockChain::new();
+    for block in 1..=5 {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+        chain.add_beacon_withdrawal(
+            serde_json::from_value::<BeaconWithdrawal>(json!({
+                "withdrawalIndex": block.to_string(),
+                "validatorIndex": "42",
+                "address": ALICE,
+                "amount": "1000",
+                "blockNumber": (block + 10).to_string(),
+                "timestamp": MockChain::block_timestamp(block + 10).to_string()
+            }))
+            .unwrap(),
+        );
+    }
+    let server = MockServer::start(chain).await.unwrap();
+
+    let feed = server
+        .client()
+        .accounts()
+        .activity(ALICE)
+        .kinds(&[ActivityKind::Transaction, ActivityKind::BeaconWithdrawal])
+        .offset(2)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        feed.iter()
+            .map(|group| group.block_number)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15]
+    );
+    // Each kind needed several pages of two
+    assert!(server.request_count() > 4);
+}
+
+#[tokio::test]
+async fn test_mock_transaction_details() {
+    let mut chain = seeded_chain();
+    let hash = chain.transfer_eth(30, ALICE, BOB, 5_000);
+    let mut log: keion_etherscan::TransactionLog = serde_json::from_value(serde_json::json!({
+        "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "topics": [
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            "0x000000000000000000000000742d35cc6634c0532925a3b8d19389c4d5e1e4a6",
+            "0x0000000000000000000000001234567890123456789012345678901234567890"
+        ],
+        "data": "0x00000000000000000000000000000000000000000000000000000000000f4240",
+        "blockNumber": "0x1e",
+        "transactionHash": "0x00",
+        "transactionIndex": "0x0",
+        "blockHash": "0x00",
+        "logIndex": "0x0",
+        "removed": false
+    }))
+    .unwrap();
+    log.transaction_hash = hash.clone();
+    chain.add_log(log);
+
+    chain.add_internal_transaction(
+        serde_json::from_value::<keion_etherscan::InternalTransaction>(serde_json::json!({
+            "blockNumber": "30",
+            "timeStamp": MockChain::block_timestamp(30).to_string(),
+            "hash": hash.as_str(),
+            "from": BOB,
+            "to": ALICE,
+            "value": "7",
+            "contractAddress": "",
+            "input": "",
+            "type": "call",
+            "gas": "0",
+            "gasUsed": "0",
+            "traceId": "0_1",
+            "isError": "0",
+            "errCode": ""
+        }))
+        .unwrap(),
+    );
+
+    let failed = chain.transfer_eth(31, ALICE, BOB, 1);
+    let last = chain.transactions.len() - 1;
+    chain.transactions[last].is_error = Some(keion_etherscan::StringNumber::from(1));
+
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let details = client.transactions().details(hash.as_str()).await.unwrap();
+    assert_eq!(details.status, ExecutionStatus::Success);
+    assert_eq!(details.transaction.value_wei(), 5_000);
+    assert_eq!(details.block_number(), Some(30));
+    assert_eq!(details.timestamp(), Some(MockChain::block_timestamp(30)));
+    assert_eq!(details.effective_gas_price, Some(1_000_000_000));
+    assert_eq!(details.fee, Some(21_000 * 1_000_000_000));
+    assert_eq!(details.token_transfers.len(), 1);
+    assert_eq!(details.token_transfers[0].standard, TokenStandard::Erc20);
+    assert_eq!(details.token_transfers[0].value.as_str(), "1000000");
+
+    // By-hash rows come without hash and trace ID, like Etherscan's
+    let internal = &details.internal_transactions;
+    assert_eq!(internal.len(), 1);
+    assert_eq!(internal[0].hash.as_ref(), Some(&hash));
+    assert_eq!(internal[0].trace_id, None);
+    let raw = client
+        .raw("account", "txlistinternal", &[("txhash", hash.as_str())])
+        .await
+        .unwrap();
+    assert!(raw[0].get("hash").is_none());
+    assert!(raw[0].get("traceId").is_none());
+
+    let details = client
+        .transactions()
+        .details(failed.as_str())
+        .await
+        .unwrap();
+    assert_eq!(
+        details.status,
+        ExecutionStatus::Failed {
+            reason: Some("Reverted".to_string())
+        }
+    );
+
+    let missing = format!("0x{}", "ab".repeat(32));
+    let error = client.transactions().details(missing).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::Api { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_reconcile() {
+    const FEE: u128 = 21_000 * 1_000_000_000;
+    let mut chain = MockChain::new();
+    chain.transfer_eth(1, BOB, ALICE, 10u128.pow(18));
+    chain.set_balance_at(ALICE, 1, 10u128.pow(18));
+    let mut balance = 10u128.pow(18);
+    for block in 2..=10 {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+        balance -= 1_000 + FEE;
+        // 500 wei arrive at block 6 without any transaction explaining them
+        let unexplained = if block >= 6 { 500 } else { 0 };
+        chain.set_balance_at(ALICE, block, balance + unexplained);
+    }
+
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let report = client
+        .accounts()
+        .reconcile(ALICE)
+        .checkpoint_every(2)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(report.replay.closing_balance(), balance as i128);
+    assert_eq!(
+        report
+            .checkpoints
+            .iter()
+            .map(|checkpoint| checkpoint.block_number)
+            .collect::<Vec<_>>(),
+        vec![2, 4, 6, 8, 10]
+    );
+    assert!(!report.is_reconciled());
+    assert_eq!(report.discrepancies.len(), 1);
+    let discrepancy = report.discrepancies[0];
+    assert_eq!((discrepancy.start_block, discrepancy.end_block), (5, 6));
+    assert_eq!(discrepancy.introduced, 500);
+
+    // Starting after the drift reads the opening balance from the chain
+    let report = client
+        .accounts()
+        .reconcile(ALICE)
+        .block_range(7, 10)
+        .execute()
+        .await
+        .unwrap();
+    assert!(report.is_reconciled());
+    assert_eq!(report.checkpoints.len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_reconcile_bounds() {
+    use futures::TryStreamExt;
+    use keion_etherscan::ValidatedBlock;
+    use serde_json::json;
+
+    let validated = |block: u64| {
+        serde_json::from_value::<ValidatedBlock>(json!({
+            "blockNumber": block.to_string(),
+            "timeStamp": MockChain::block_timestamp(block).to_string(),
+            "blockReward": "100"
+        }))
+        .unwrap()
+    };
+    let mut chain = MockChain::new();
+    for block in [3, 5, 12] {
+        chain.add_validated_block(ALICE, validated(block));
+    }
+    chain.transfer_eth(4, BOB, ALICE, 1_000);
+    for (block, wei) in [(3, 100), (4, 1_100), (5, 1_200), (12, 1_300)] {
+        chain.set_balance_at(ALICE, block, wei);
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    // Validated blocks come newest first, across pages
+    let blocks: Vec<u64> = client
+        .accounts()
+        .blocks_validated(ALICE)
+        .offset(1)
+        .stream()
+        .map_ok(|block| block.block())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(blocks, vec![12, 5, 3]);
+
+    // Blocks validated outside the range are not replayed
+    let report = client
+        .accounts()
+        .reconcile(ALICE)
+        .block_range(4, 10)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(report.replay.opening_balance, 100);
+    assert_eq!(report.replay.closing_balance(), 1_200);
+    assert!(report.is_reconciled());
+
+    // Without an end block or any events the latest balance is still checked
+    let mut chain = MockChain::new();
+    chain.set_balance_at(ALICE, 5, 700);
+    let server = MockServer::start(chain).await.unwrap();
+    let report = server
+        .client()
+        .accounts()
+        .reconcile(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(report.checkpoints.len(), 1);
+    assert_eq!(report.checkpoints[0].block_number, 5);
+    assert!(!report.is_reconciled());
+
+    // Balances at past blocks need the Pro plan
+    let config = MockConfig::default().free_tier();
+    let server = MockServer::start_with(MockChain::new(), config)
+        .await
+        .unwrap();
+    let error = server
+        .client()
+        .accounts()
+        .reconcile(ALICE)
+        .block_range(1, 10)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::PlanRestricted { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_token_ledger() {
+    use keion_etherscan::TokenTransfer;
+    use serde_json::json;
+
+    const USDT: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+    const DAI: &str = "0x6b175474e89094c44da98b954eedeac495271d0f";
+
+    let transfer = |block: u64, token: &str, from: &str, to: &str, value: &str| {
+        serde_json::from_value::<TokenTransfer>(json!({
+            "blockNumber": block.to_string(),
+            "timeStamp": MockChain::block_timestamp(block).to_string(),
+            "hash": format!("0x{:064x}", block),
+            "blockHash": "0x0",
+            "transactionIndex": "0",
+            "from": from,
+            "to": to,
+            "contractAddress": token,
+            "value": value,
+            "tokenName": "Token",
+            "tokenSymbol": "TKN",
+            "tokenDecimal": "6",
+            "gasPrice": "1000000000",
+            "gasUsed": "21000",
+            "logIndex": "0"
+        }))
+        .unwrap()
+    };
+
+    let mut chain = MockChain::new();
+    chain.add_token_transfer(transfer(10, USDT, BOB, ALICE, "5000000"));
+    chain.add_token_transfer(transfer(20, USDT, ALICE, BOB, "1250000"));
+    chain.add_token_transfer(transfer(30, DAI, BOB, ALICE, "1"));
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let ledger = client
+        .accounts()
+        .token_ledger(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(ledger.flows().unwrap().len(), 2);
+    assert_eq!(ledger.flow(USDT).unwrap().unwrap().net_units(), "3.75");
+    assert_eq!(
+        ledger.at_block(15).flow(USDT).unwrap().unwrap().net_units(),
+        "5"
+    );
+
+    let ledger = client
+        .accounts()
+        .token_ledger(ALICE)
+        .contract_address(DAI)
+        .end_block(25)
+        .execute()
+        .await
+        .unwrap();
+    assert!(ledger.flows().unwrap().is_empty());
+
+    // More transfers than one result window still all count
+    let mut chain = MockChain::new();
+    for index in 0..10_002u64 {
+        chain.add_token_transfer(transfer(1 + index / 2, USDT, BOB, ALICE, "1"));
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let ledger = server
+        .client()
+        .accounts()
+        .token_ledger(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(ledger.flow(USDT).unwrap().unwrap().net_units(), "0.010002");
+}
+
+#[tokio::test]
+async fn test_mock_holder_snapshot() {
+    use keion_etherscan::{HolderSnapshot, TokenTransfer};
+    use serde_json::json;
+
+    const UNI: &str = "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984";
+    const ZERO: &str = "0x0000000000000000000000000000000000000000";
+    const TREASURY: &str = "0x1a9c8182c09f50c8318d769245bea52c32be35bc";
+
+    let transfer = |block: u64, from: &str, to: &str, value: &str| {
+        serde_json::from_value::<TokenTransfer>(json!({
+            "blockNumber": block.to_string(),
+            "timeStamp": MockChain::block_timestamp(block).to_string(),
+            "hash": format!("0x{:064x}", block),
+            "blockHash": "0x0",
+            "transactionIndex": "0",
+            "from": from,
+            "to": to,
+            "contractAddress": UNI,
+            "value": value,
+            "tokenName": "Uniswap",
+            "tokenSymbol": "UNI",
+            "tokenDecimal": "18",
+            "gasPrice": "1000000000",
+            "gasUsed": "21000",
+            "logIndex": "0"
+        }))
+        .unwrap()
+    };
+
+    let mut chain = MockChain::new();
+    chain.add_token_transfer(transfer(1, ZERO, TREASURY, "1000000000000000000000000"));
+    // Two transfers per block so pages of 3 split blocks
+    for block in 2..=7 {
+        chain.add_token_transfer(transfer(block, TREASURY, ALICE, "1500000000000000000"));
+        chain.add_token_transfer(transfer(block, TREASURY, BOB, "1"));
+    }
+    chain.add_token_transfer(transfer(8, ALICE, BOB, "500000000000000000"));
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let all = client
+        .tokens()
+        .transfers(UNI)
+        .offset(3)
+        .execute_all()
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 14);
+
+    let snapshot = client
+        .tokens()
+        .holder_snapshot(UNI, 7)
+        .exclude(TREASURY)
+        .page_size(3)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(snapshot.len(), 2);
+    assert_eq!(snapshot.balance_of(ALICE), 9 * 10u128.pow(18));
+    assert_eq!(snapshot.balance_of(BOB), 6);
+    assert_eq!(snapshot.balance_of(ZERO), 0);
+    assert_eq!(
+        snapshot.to_csv(),
+        format!(
+            "address,balance,amount\n{},9000000000000000000,9\n{},6,0.000000000000000006\n",
+            ALICE, BOB
+        )
+    );
+    let json: serde_json::Value = serde_json::from_str(&snapshot.to_json().unwrap()).unwrap();
+    assert_eq!(json["holders"][0]["balance"], "9000000000000000000");
+
+    // The treasury is kept without the exclusion; the zero address never is
+    let snapshot = client
+        .tokens()
+        .holder_snapshot(UNI, 1)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot.holders[0].address.as_str(), TREASURY);
+
+    // A block with more transfers than a page cannot be split
+    let error = client
+        .tokens()
+        .holder_snapshot(UNI, 7)
+        .page_size(1)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+
+    // Balances that overflow fail instead of saturating
+    let max = i128::MAX.to_string();
+    let transfers = [
+        transfer(1, ZERO, ALICE, &max),
+        transfer(2, ZERO, ALICE, &max),
+    ];
+    let error = HolderSnapshot::new(UNI, 2, &transfers, &[]).unwrap_err();
+    assert!(matches!(error, EtherscanError::Parse { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_watch() {
+    use futures::StreamExt;
+    use keion_etherscan::watch::{CursorStore, MemoryCursorStore, WatchEvent};
+    use keion_etherscan::ActivityKind;
+    use std::time::Duration;
+
+    let mut chain = MockChain::new();
+    for block in 1..=5 {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+    let store = MemoryCursorStore::new();
+
+    let watch = || {
+        client
+            .watch(ALICE)
+            .kinds(&[ActivityKind::Transaction])
+            .start_block(1)
+            .confirmations(2)
+            .reorg_depth(3)
+            .poll_interval(Duration::from_millis(10))
+            .cursor_store(store.clone())
+    };
+    async fn next<S>(events: &mut S) -> (bool, u64)
+    where
+        S: futures::Stream<Item = keion_etherscan::Result<WatchEvent>> + Unpin,
+    {
+        let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .expect("watcher produced no event");
+        let event = event.unwrap().unwrap();
+        (event.is_removal(), event.event().block())
+    }
+
+    let mut events = Box::pin(watch().stream());
+
+    // Only blocks with two confirmations are reported
+    assert_eq!(next(&mut events).await, (false, 1));
+    assert_eq!(next(&mut events).await, (false, 2));
+    assert_eq!(next(&mut events).await, (false, 3));
+
+    server.chain().transfer_eth(6, ALICE, BOB, 1_000);
+    assert_eq!(next(&mut events).await, (false, 4));
+
+    // Block 4 is reorged out while block 5 becomes safe
+    server.chain().transactions.retain(|tx| tx.block() != 4);
+    server.chain().transfer_eth(7, BOB, ALICE, 1_000);
+    assert_eq!(next(&mut events).await, (true, 4));
+    assert_eq!(next(&mut events).await, (false, 5));
+    drop(events);
+
+    // The cursor covers events up to the one before the last delivered
+    let cursor = store.load(&watch().cursor_key()).unwrap().unwrap();
+    assert_eq!(cursor.next_block, 5);
+
+    // A restarted watcher only repeats the event that was being handled
+    let mut events = Box::pin(watch().stream());
+    server.chain().transfer_eth(8, ALICE, BOB, 1_000);
+    assert_eq!(next(&mut events).await, (false, 5));
+    assert_eq!(next(&mut events).await, (false, 6));
+}
+
+#[tokio::test]
+async fn test_mock_watch_first_poll() {
+    use futures::StreamExt;
+    use keion_etherscan::ActivityKind;
+    use std::time::Duration;
+
+    let mut chain = MockChain::new();
+    for block in 1..=10 {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+    let watch = || {
+        client
+            .watch(ALICE)
+            .kinds(&[ActivityKind::Transaction])
+            .confirmations(0)
+            .reorg_depth(5)
+            .poll_interval(Duration::from_millis(10))
+    };
+    let next_block = |event: Option<keion_etherscan::Result<_>>| {
+        let event: keion_etherscan::watch::WatchEvent = event.unwrap().unwrap();
+        assert!(!event.is_removal());
+        event.event().block()
+    };
+
+    // Without a start block the reorg window below the head is not reported
+    let mut events = Box::pin(watch().stream());
+    let first = tokio::time::timeout(Duration::from_millis(100), events.next()).await;
+    assert!(first.is_err());
+    server.chain().transfer_eth(11, ALICE, BOB, 1_000);
+    let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+        .await
+        .unwrap();
+    assert_eq!(next_block(event), 11);
+    drop(events);
+
+    // Nor are the blocks before an explicit start
+    let mut events = Box::pin(watch().start_block(9).stream());
+    let mut blocks = Vec::new();
+    for _ in 0..3 {
+        let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .unwrap();
+        blocks.push(next_block(event));
+    }
+    assert_eq!(blocks, vec![9, 10, 11]);
+}
+
+#[tokio::test]
+async fn test_mock_block_watcher_from_genesis() {
+    use futures::{StreamExt, TryStreamExt};
+    use keion_etherscan::middleware::{Middleware, Request};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    /// Asks for an unmined block the first time genesis is requested
+    struct LaggingNode(AtomicBool);
+
+    impl Middleware for LaggingNode {
+        fn on_request(&self, request: &mut Request) -> keion_etherscan::Result<()> {
+            if request.param("tag") == Some("0x0") && !self.0.swap(true, Ordering::SeqCst) {
+                request.set_param("tag", "0xffffff");
+            }
+            Ok(())
+        }
+    }
+
+    let mut chain = MockChain::new();
+    chain.set_block_number(1);
+    let server = MockServer::start(chain).await.unwrap();
+    let client = EtherscanClient::builder()
+        .api_key(MOCK_API_KEY)
+        .base_url(server.url())
+        .rate_limit(0)
+        .middleware(LaggingNode(AtomicBool::new(false)))
+        .build()
+        .unwrap();
+
+    // Block 0 is not found at first and is retried on the next poll
+    let blocks = client
+        .proxy()
+        .watch_blocks()
+        .start_block(0)
+        .poll_interval(Duration::from_millis(10))
+        .stream();
+    let blocks: Vec<u64> = tokio::time::timeout(
+        Duration::from_secs(5),
+        blocks.take(2).try_collect::<Vec<_>>(),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .iter()
+    .map(|block| block.block())
+    .collect();
+    assert_eq!(blocks, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn test_mock_block_watcher_and_confirmations() {
+    use futures::StreamExt;
+    use keion_etherscan::{ConfirmationOutcome, RpcTransaction};
+    use serde_json::json;
+    use std::time::Duration;
+
+    const POLL: Duration = Duration::from_millis(10);
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn pending(hash: &str, nonce: u64) -> RpcTransaction {
+        serde_json::from_value(json!({
+            "hash": hash,
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null,
+            "from": ALICE,
+            "to": BOB,
+            "value": "0x1",
+            "nonce": format!("0x{:x}", nonce),
+            "gas": "0x5208",
+            "input": "0x"
+        }))
+        .unwrap()
+    }
+
+    let mut chain = MockChain::new();
+    let mined = chain.transfer_eth(10, ALICE, BOB, 1_000);
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+    let proxy = client.proxy();
+
+    // New blocks are yielded in order, including ones mined between polls
+    let mut blocks = Box::pin(proxy.watch_blocks().poll_interval(POLL).stream());
+    let grow = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        server.chain().set_block_number(12);
+    };
+    let (first, _) = tokio::join!(tokio::time::timeout(TIMEOUT, blocks.next()), grow);
+    assert_eq!(first.unwrap().unwrap().unwrap().block(), 11);
+    let second = tokio::time::timeout(TIMEOUT, blocks.next()).await.unwrap();
+    assert_eq!(second.unwrap().unwrap().block(), 12);
+
+    // Confirmed once the receipt is five blocks deep
+    let waiter = proxy
+        .wait_for_confirmations(mined.as_str(), 5, TIMEOUT)
+        .poll_interval(POLL);
+    let grow = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        server.chain().set_block_number(14);
+    };
+    let (outcome, _) = tokio::join!(waiter.execute(), grow);
+    match outcome.unwrap() {
+        ConfirmationOutcome::Confirmed {
+            receipt,
+            confirmations,
+        } => {
+            assert_eq!(receipt.block(), 10);
+            assert_eq!(confirmations, 5);
+        }
+        other => panic!("unexpected outcome {:?}", other),
+    }
+
+    // A pending transaction whose nonce gets used by another one was replaced
+    let replaced = format!("0x{:064x}", 0xaa);
+    server
+        .chain()
+        .pending_transactions
+        .push(pending(&replaced, 1));
+    let waiter = proxy
+        .wait_for_confirmations(&replaced, 1, TIMEOUT)
+        .poll_interval(POLL);
+    let replace = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let mut chain = server.chain();
+        chain.pending_transactions.clear();
+        chain.transfer_eth(15, ALICE, BOB, 2);
+    };
+    let (outcome, _) = tokio::join!(waiter.execute(), replace);
+    assert_eq!(outcome.unwrap(), ConfirmationOutcome::Replaced { nonce: 1 });
+
+    // One whose nonce is still free was dropped
+    let dropped = format!("0x{:064x}", 0xbb);
+    server
+        .chain()
+        .pending_transactions
+        .push(pending(&dropped, 7));
+    let waiter = proxy
+        .wait_for_confirmations(&dropped, 1, TIMEOUT)
+        .poll_interval(POLL);
+    let drop_it = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        server.chain().pending_transactions.clear();
+    };
+    let (outcome, _) = tokio::join!(waiter.execute(), drop_it);
+    assert_eq!(outcome.unwrap(), ConfirmationOutcome::Dropped);
+
+    // An unknown hash is waited for until the timeout
+    let error = proxy
+        .wait_for_confirmations(format!("0x{:064x}", 0xcc), 1, Duration::from_millis(50))
+        .poll_interval(POLL)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::Timeout(_)));
+}
+
+#[tokio::test]
+async fn test_mock_indexer_resumes() {
+    use keion_etherscan::indexer::{CheckpointStore, IndexTarget, MemoryCheckpointStore};
+    use keion_etherscan::ActivityKind;
+
+    let mut chain = MockChain::new();
+    for block in [1, 2, 2, 3, 5, 8] {
+        chain.transfer_eth(block, ALICE, BOB, 1_000);
+    }
+    chain.set_block_number(10);
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+    let store = MemoryCheckpointStore::new();
+    let indexer = || {
+        client
+            .indexer()
+            .target(ALICE, ActivityKind::Transaction)
+            .confirmations(1)
+            .page_size(3)
+            .checkpoint_store(store.clone())
+    };
+    let key = indexer().checkpoint_key(&IndexTarget::new(ALICE, ActivityKind::Transaction));
+
+    // The sink fails on the second batch: only the first is checkpointed
+    let mut batches = Vec::new();
+    let error = indexer()
+        .run(|batch| {
+            if !batches.is_empty() {
+                return Err(EtherscanError::Internal("disk full".to_string()));
+            }
+            batches.push((batch.start_block, batch.end_block, batch.events.len()));
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::Internal(_)));
+    // A page of three ends inside block 2, which is left for the next page
+    assert_eq!(batches, vec![(0, 1, 1)]);
+    assert_eq!(store.load(&key).unwrap(), Some(1));
+
+    // The next run resumes right after the checkpoint
+    let mut batches = Vec::new();
+    let summaries = indexer()
+        .run(|batch| {
+            batches.push((batch.start_block, batch.end_block, batch.events.len()));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert_eq!(batches, vec![(2, 2, 2), (3, 7, 2), (8, 9, 1)]);
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].start_block, 2);
+    assert_eq!(summaries[0].end_block, 9);
+    assert_eq!(summaries[0].events, 5);
+    assert_eq!(store.load(&key).unwrap(), Some(9));
+
+    // Nothing new below the confirmed head
+    let summaries = indexer()
+        .run(|_| panic!("no batch expected"))
+        .await
+        .unwrap();
+    assert!(summaries[0].is_up_to_date());
+}
+
+const TRANSFER_TOPIC: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+fn event_log(block: u64, log_index: u64, topics: &[&str]) -> keion_etherscan::TransactionLog {
+    // Shaped like a `logs` module entry: hex timestamp, no `removed` flag
+    serde_json::from_value(serde_json::json!({
+        "address": USDC,
+        "topics": topics,
+        "data": "0x",
+        "blockNumber": format!("0x{:x}", block),
+        "timeStamp": format!("0x{:x}", MockChain::block_timestamp(block)),
+        "transactionHash": format!("0x{:064x}", block),
+        "transactionIndex": "0x0",
+        "blockHash": format!("0x{:064x}", block),
+        "logIndex": format!("0x{:x}", log_index)
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_mock_logs() {
+    use futures::TryStreamExt;
+
+    let from_alice = format!("0x{:0>64}", &ALICE[2..]);
+    let mut chain = MockChain::new();
+    for block in 1..=5 {
+        chain.add_log(event_log(block, 0, &[TRANSFER_TOPIC, &from_alice]));
+        chain.add_log(event_log(
+            block,
+            1,
+            &[TRANSFER_TOPIC, &format!("0x{:064x}", 0)],
+        ));
+    }
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let page = client
+        .logs()
+        .get_logs()
+        .address(USDC)
+        .topic(0, TRANSFER_TOPIC)
+        .block_range(2, 3)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 4);
+    assert_eq!(page[0].timestamp(), Some(MockChain::block_timestamp(2)));
+
+    // Pages of three are followed across blocks, matching both topics
+    let logs: Vec<_> = client
+        .logs()
+        .get_logs()
+        .topic(0, TRANSFER_TOPIC)
+        .topic(1, &from_alice)
+        .offset(3)
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    let blocks: Vec<u64> = logs.iter().map(|log| log.block()).collect();
+    assert_eq!(blocks, vec![1, 2, 3, 4, 5]);
+    let request = &server.requests()[1];
+    assert_eq!(request.get("topic0_1_opr").map(String::as_str), Some("and"));
+
+    let error = client.logs().get_logs().execute().await.unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+
+    // Logs carry at most four topics
+    let error = client
+        .logs()
+        .get_logs()
+        .address(USDC)
+        .topic(4, TRANSFER_TOPIC)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+}
+
+#[tokio::test]
+async fn test_mock_gas_oracle_and_verification() {
+    let contract = "0x00000000000000000000000000000000c0de0001";
+    let mut chain = MockChain::new();
+    chain.set_block_number(100);
+    chain.add_contract(MockContract::new(contract, "Token", BOB, "0x01"));
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let oracle = client.stats().gas_oracle().await.unwrap();
+    assert_eq!(oracle.block(), 100);
+    assert_eq!(oracle.propose_wei(), Some(1_000_000_000));
+    assert_eq!(oracle.base_fee_wei(), Some(500_000_000));
+
+    let request = client
+        .contracts()
+        .verify_solidity(contract)
+        .source_code("contract Token {}")
+        .contract_name("Token")
+        .compiler_version("v0.8.24+commit.e11b9ed9")
+        .optimization(true, 200)
+        .submit()
+        .await
+        .unwrap();
+    let status = client
+        .contracts()
+        .check_verification_status(&request.guid)
+        .await
+        .unwrap();
+    assert!(status.is_verified());
+
+    // The source code travels in the POST body, not the query string
+    let submitted = &server.requests()[1];
+    assert_eq!(submitted.get("sourceCode").unwrap(), "contract Token {}");
+    assert_eq!(submitted.get("runs").unwrap(), "200");
+
+    let error = client
+        .contracts()
+        .verify_solidity(BOB)
+        .source_code("contract Token {}")
+        .contract_name("Token")
+        .compiler_version("v0.8.24+commit.e11b9ed9")
+        .submit()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::Api { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_builders_move_into_tasks() {
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+
+    let query = client.accounts().transactions(ALICE).page(1).offset(10);
+    drop(client);
+    let transactions = tokio::spawn(query.execute()).await.unwrap().unwrap();
+    assert_eq!(transactions.len(), 10);
+}
+
+#[tokio::test]
+async fn test_mock_query_spec_round_trip() {
+    use keion_etherscan::spec::{QueryOutput, QuerySpec};
+
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+
+    let builder = client
+        .accounts()
+        .transactions(ALICE)
+        .block_range(5, 9)
+        .sort(Sort::Ascending);
+    let json = serde_json::to_value(builder.spec()).unwrap();
+    assert_eq!(json["query"], "transactions");
+    assert_eq!(json["pagination"]["start_block"], 5);
+    assert!(json["pagination"].get("page").is_none());
+
+    let spec: QuerySpec = serde_json::from_value(json).unwrap();
+    assert_eq!(spec, builder.spec());
+
+    let expected = builder.execute().await.unwrap();
+    match spec.execute(&client).await.unwrap() {
+        QueryOutput::Transactions(transactions) => assert_eq!(transactions, expected),
+        other => panic!("unexpected output: {:?}", other),
+    }
+
+    let spec: QuerySpec = serde_json::from_str(&format!(
+        r#"{{"query":"historical_balance","address":"{}"}}"#,
+        ALICE
+    ))
+    .unwrap();
+    match spec.execute(&client).await.unwrap() {
+        QueryOutput::Balance(balance) => assert_eq!(balance.eth(), Some(2.0)),
+        other => panic!("unexpected output: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_submission_specs() {
+    use keion_etherscan::{
+        spec::{QueryOutput, QuerySpec},
+        ConfirmationOutcome,
+    };
+    use std::time::Duration;
+
+    let contract = "0x00000000000000000000000000000000c0de0001";
+    let mut chain = MockChain::new();
+    let mined = chain.transfer_eth(10, ALICE, BOB, 1_000);
+    chain.set_block_number(20);
+    chain.add_contract(MockContract::new(contract, "Token", BOB, "0x01"));
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    let builder = client
+        .contracts()
+        .verify_solidity(contract)
+        .source_code("contract Token {}")
+        .contract_name("Token")
+        .compiler_version("v0.8.24+commit.e11b9ed9")
+        .optimization(true, 200);
+    let json = serde_json::to_value(builder.spec()).unwrap();
+    assert_eq!(json["query"], "verify_solidity");
+    assert_eq!(json["code_format"], "solidity-single-file");
+
+    // Executing the spec submits the verification
+    let spec: QuerySpec = serde_json::from_value(json).unwrap();
+    assert_eq!(spec, builder.spec());
+    match spec.execute(&client).await.unwrap() {
+        QueryOutput::Verification(request) => assert!(!request.guid.is_empty()),
+        other => panic!("unexpected output: {:?}", other),
+    }
+    let submitted = server.requests().pop().unwrap();
+    assert_eq!(submitted.get("sourceCode").unwrap(), "contract Token {}");
+
+    let spec = client
+        .proxy()
+        .wait_for_confirmations(mined.as_str(), 5, Duration::from_secs(5))
+        .poll_interval(Duration::from_millis(10))
+        .spec();
+    let spec: QuerySpec = serde_json::from_str(&serde_json::to_string(&spec).unwrap()).unwrap();
+    match spec.execute(&client).await.unwrap() {
+        QueryOutput::Confirmation(ConfirmationOutcome::Confirmed { confirmations, .. }) => {
+            assert_eq!(confirmations, 11)
+        }
+        other => panic!("unexpected output: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_raw_calls() {
+    use keion_etherscan::{Action, Call, Module};
+
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = server.client();
+
+    // Actions the crate does not model
+    let price = client.raw(Module::Stats, "ethprice", &[]).await.unwrap();
+    assert!(price["ethusd"].is_string());
+
+    // Typed calls deserialize into any model
+    let call = Call::<keion_etherscan::Balance>::new(Module::Account, Action::Balance)
+        .param("address", ALICE)
+        .param("tag", "latest");
+    let balance = client.call(&call).await.unwrap();
+    assert_eq!(balance.eth(), Some(2.0));
+
+    let error = client.raw("stats", "notanaction", &[]).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::Api { .. }));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].get("module").map(String::as_str), Some("stats"));
+    assert_eq!(
+        requests[1].get("action").map(String::as_str),
+        Some("balance")
+    );
+}
+
+#[tokio::test]
+async fn test_mock_typed_endpoints() {
+    use keion_etherscan::endpoints::{
+        accounts::{GetBalance, GetBalanceMulti},
+        contracts::{GetAbi, GetContractCreation},
+        proxy::EthBlockNumber,
+        Endpoint,
+    };
+    use keion_etherscan::Tag;
+
+    let contract = "0x00000000000000000000000000000000c0de0001";
+    let mut chain = MockChain::new();
+    chain.add_contract(MockContract::new(contract, "Token", BOB, "0x01"));
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+
+    // Per-action endpoints go through `call` and deserialize into their model
+    let endpoint = GetAbi::new(contract.to_uppercase().replace("0X", "0x"));
+    assert_eq!(endpoint.action().as_str(), "getabi");
+    let abi = client.call(&endpoint).await.unwrap();
+    assert_eq!(
+        abi.abi,
+        client.contracts().get_abi(contract).await.unwrap().abi
+    );
+    assert_eq!(
+        server.requests()[0].get("address").map(String::as_str),
+        Some(contract)
+    );
+
+    let balance = client
+        .call(&GetBalance::new(ALICE, Tag::Latest))
+        .await
+        .unwrap();
+    assert_eq!(balance, client.accounts().balance(ALICE).await.unwrap());
+    assert_eq!(
+        client.call(&EthBlockNumber).await.unwrap().value(),
+        client.proxy().block_number().await.unwrap()
+    );
+
+    // Invalid input fails before anything is sent
+    let error = client
+        .call(&GetContractCreation::new::<&str>(&[]))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+    let error = client
+        .call(&GetBalanceMulti::new(&[ALICE; 21]))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+    assert_eq!(server.requests().len(), 6);
+}
+
+#[tokio::test]
+async fn test_mock_errors_carry_context_without_api_key() {
+    use keion_etherscan::{Action, Module, Network};
+    use std::error::Error;
+
+    let config = MockConfig::default().http_failures(1);
+    let server = MockServer::start_with(seeded_chain(), config)
+        .await
+        .unwrap();
+    let client = server.client();
+    assert!(!format!("{:?}", client).contains(MOCK_API_KEY));
+
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    let context = error.context().unwrap();
+    assert_eq!(context.network, Network::Mainnet);
+    assert_eq!(context.module, "account");
+    assert_eq!(context.action, "balance");
+    assert_eq!(context.status, Some(502));
+    assert!(context.url.contains("apikey=REDACTED"));
+    assert!(context.body.as_deref().unwrap().contains("Bad Gateway"));
+
+    // Results that do not match the model keep the raw body and the serde error
+    let error = client
+        .call(
+            &keion_etherscan::Call::<u64>::new(Module::Account, Action::Balance)
+                .param("address", ALICE),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.category(), "parsing");
+    assert!(error.source().is_some());
+    assert_eq!(error.context().unwrap().status, Some(200));
+
+    // A key the server rejects still stays out of the message
+    let client = EtherscanClient::builder()
+        .api_key("secret-key-1234")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::Api { .. }));
+    for text in [error.to_string(), format!("{:?}", error)] {
+        assert!(!text.contains("secret-key-1234"));
+    }
+    assert!(error.to_string().contains("account/balance"));
+}
+
+#[tokio::test]
+async fn test_mock_key_pool() {
+    const TEAM_KEY: &str = "team-b-api-key";
+    const REVOKED_KEY: &str = "revoked-api-key";
+
+    let config = MockConfig::default().accept_api_key(TEAM_KEY);
+    let server = MockServer::start_with(seeded_chain(), config)
+        .await
+        .unwrap();
+    let builder = || {
+        EtherscanClient::builder()
+            .base_url(server.url())
+            .rate_limit(0)
+    };
+    let calls = |client: &EtherscanClient| -> Vec<u64> {
+        client.key_usage().iter().map(|usage| usage.calls).collect()
+    };
+
+    // Round-robin takes the keys in turn
+    let client = builder()
+        .api_keys([MOCK_API_KEY, TEAM_KEY])
+        .build()
+        .unwrap();
+    for _ in 0..3 {
+        client.accounts().balance(ALICE).await.unwrap();
+    }
+    assert_eq!(calls(&client), vec![2, 1]);
+
+    // Least-used picks the key with the fewest calls
+    let client = builder()
+        .api_keys([MOCK_API_KEY, TEAM_KEY])
+        .key_selection(KeySelection::LeastUsed)
+        .build()
+        .unwrap();
+    for _ in 0..4 {
+        client.accounts().balance(ALICE).await.unwrap();
+    }
+    assert_eq!(calls(&client), vec![2, 2]);
+
+    // A rejected key cools down and the retry moves to the next key
+    let client = builder()
+        .api_keys([REVOKED_KEY, MOCK_API_KEY])
+        .max_retries(1)
+        .build()
+        .unwrap();
+    for _ in 0..3 {
+        client.accounts().balance(ALICE).await.unwrap();
+    }
+    assert_eq!(calls(&client), vec![1, 3]);
+    let usage = client.key_usage();
+    assert!(usage[0].cooling_down);
+    assert!(!usage[1].cooling_down);
+
+    // Without retries the error surfaces, with every key redacted
+    let client = builder()
+        .api_keys([REVOKED_KEY, "another-revoked-key"])
+        .build()
+        .unwrap();
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    assert!(error.is_invalid_api_key());
+    assert!(!format!("{:?}", error).contains(REVOKED_KEY));
+}
+
+#[tokio::test]
+async fn test_mock_key_usage_file() {
+    let path = std::env::temp_dir().join(format!("key-usage-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let server = MockServer::start(seeded_chain()).await.unwrap();
+    let client = || {
+        EtherscanClient::builder()
+            .api_key(MOCK_API_KEY)
+            .base_url(server.url())
+            .rate_limit(0)
+            .key_usage_file(&path)
+            .build()
+            .unwrap()
+    };
+
+    let first = client();
+    first.accounts().balance(ALICE).await.unwrap();
+    first.accounts().balance(ALICE).await.unwrap();
+    drop(first);
+
+    // Counters survive a restart, and the file only holds a key preview
+    let second = client();
+    assert_eq!(second.key_usage()[0].calls, 2);
+    second.accounts().balance(ALICE).await.unwrap();
+    assert_eq!(second.key_usage()[0].calls, 3);
+
+    // Calls reach the file in the background while the client is in use
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(5);
+    while !std::fs::read_to_string(&path).unwrap().contains(": 3") {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "usage was not written"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    // Clients sharing the file add their calls instead of overwriting each other
+    let third = client();
+    let fourth = client();
+    third.accounts().balance(ALICE).await.unwrap();
+    third.accounts().balance(ALICE).await.unwrap();
+    fourth.accounts().balance(ALICE).await.unwrap();
+    drop(third);
+    drop(fourth);
+    drop(second);
+    assert_eq!(client().key_usage()[0].calls, 6);
+
+    // Writers running at the same time, background and final ones included,
+    // take turns on the file
+    let clients: Vec<EtherscanClient> = (0..8).map(|_| client()).collect();
+    for client in &clients {
+        client.accounts().balance(ALICE).await.unwrap();
+    }
+    let threads: Vec<_> = clients
+        .into_iter()
+        .map(|client| std::thread::spawn(move || drop(client)))
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    // Background writes may still be in flight after the drops
+    tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+    assert_eq!(client().key_usage()[0].calls, 14);
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(!text.contains(MOCK_API_KEY));
+    std::fs::remove_file(&path).unwrap();
+    let _ = std::fs::remove_file(path.with_extension("json.lock"));
+}
+
+#[tokio::test]
+async fn test_mock_multi_chain() {
+    use keion_etherscan::{MultiChainClient, Network, Pagination};
+
+    let mainnet = MockServer::start(seeded_chain()).await.unwrap();
+    let mut arbitrum_chain = MockChain::new();
+    arbitrum_chain.add_balance(ALICE, 5 * 10u128.pow(17));
+    arbitrum_chain.transfer_eth(1, ALICE, BOB, 1_000);
+    let arbitrum = MockServer::start(arbitrum_chain).await.unwrap();
+    let polygon = MockServer::start(MockChain::new()).await.unwrap();
+
+    let client_for = |server: &MockServer, network: Network, api_key: &str| {
+        EtherscanClient::builder()
+            .api_key(api_key)
+            .network(network)
+            .base_url(server.url())
+            .rate_limit(0)
+            .build()
+            .unwrap()
+    };
+    let client = MultiChainClient::builder()
+        .client(client_for(&mainnet, Network::Mainnet, MOCK_API_KEY))
+        .client(client_for(&arbitrum, Network::Arbitrum, MOCK_API_KEY))
+        .client(client_for(&polygon, Network::Polygon, "wrong-key"))
+        .build()
+        .unwrap();
+    assert_eq!(
+        client.networks(),
+        vec![Network::Mainnet, Network::Arbitrum, Network::Polygon]
+    );
+
+    // Results keep the network order and report the failing chain
+    let balances = client.balances(ALICE).await;
+    assert!(!balances.is_complete());
+    let successes: Vec<(Network, Option<f64>)> = balances
+        .successes()
+        .map(|(network, balance)| (network, balance.eth()))
+        .collect();
+    assert_eq!(
+        successes,
+        vec![
+            (Network::Mainnet, Some(2.0)),
+            (Network::Arbitrum, Some(0.5))
+        ]
+    );
+    assert_eq!(balances.failed_networks(), vec![Network::Polygon]);
+    let polygon_result = balances.get(Network::Polygon).unwrap();
+    assert_eq!(polygon_result.native_currency(), "POL");
+    assert!(polygon_result
+        .result
+        .as_ref()
+        .unwrap_err()
+        .is_invalid_api_key());
+    assert!(balances.into_result().is_err());
+
+    let transactions = client
+        .transactions(ALICE, &Pagination::new().page(1).offset(10))
+        .await;
+    let counts: Vec<(Network, usize)> = transactions
+        .successes()
+        .map(|(network, transactions)| (network, transactions.len()))
+        .collect();
+    assert_eq!(counts, vec![(Network::Mainnet, 10), (Network::Arbitrum, 1)]);
+
+    // Every chain is reported, whatever the outcome
+    let tokens = client.token_balances(ALICE).await;
+    assert_eq!(tokens.chains.len(), 3);
+    assert_eq!(mainnet.request_count(), 3);
+    assert_eq!(arbitrum.request_count(), 3);
+
+    // Networks may only be added once
+    let duplicate = MultiChainClient::builder()
+        .api_key(MOCK_API_KEY)
+        .networks([Network::Mainnet, Network::Arbitrum])
+        .network_with_key(Network::Mainnet, "other-key")
+        .build();
+    assert!(matches!(duplicate, Err(EtherscanError::InvalidParams(_))));
+    assert!(MultiChainClient::builder().build().is_err());
+}
@@ -1,8 +1,10 @@
 use keion_etherscan::{
     AccountInfo, Address, Balance, BigNumber, CodeFormat, ContractAbi, ContractCreation,
-    ContractSource, HexNumber, InternalTransaction, LibraryLink, MultiBalance,
-    OptimizationSettings, ProxyVerificationStatus, StringNumber, TokenBalance, TokenTransfer,
-    Transaction, TxHash, VerificationRequest, VerificationStatus,
+    ContractSource, DecodedTransfer, EtherscanError, ExecutionStatus, GasOracle, HexNumber,
+    InternalTransaction, LibraryLink, MultiBalance, OptimizationSettings, ProxyVerificationStatus,
+    RpcTransaction, StringNumber, Timestamp, TokenBalance, TokenLedger, TokenStandard,
+    TokenTransfer, Transaction, TransactionDetails, TransactionLog, TransactionReceipt, TxHash,
+    VerificationRequest, VerificationStatus,
 };
 use serde_json;
 
@@ -27,6 +29,24 @@ fn test_hex_number_deserialization() {
     assert_eq!(num2.value(), 0x1a2b);
 }
 
+#[test]
+fn test_number_wrappers_round_trip() {
+    // Both wrappers serialize as plain JSON numbers and read them back
+    let num = StringNumber::from(12345);
+    assert_eq!(serde_json::to_string(&num).unwrap(), "12345");
+    assert_eq!(
+        serde_json::from_str::<StringNumber>(&serde_json::to_string(&num).unwrap()).unwrap(),
+        num
+    );
+
+    let hex = HexNumber::from(0x1a2b);
+    assert_eq!(serde_json::to_string(&hex).unwrap(), "6699");
+    assert_eq!(
+        serde_json::from_str::<HexNumber>(&serde_json::to_string(&hex).unwrap()).unwrap(),
+        hex
+    );
+}
+
 #[test]
 fn test_big_number() {
     let big = BigNumber::from("123456789012345678901234567890".to_string());
@@ -216,6 +236,10 @@ fn test_contract_abi_deserialization() {
     // Test ABI parsing
     let parsed = abi.parse_abi().unwrap();
     assert!(parsed.is_array());
+
+    // `getabi` returns the ABI as a bare string
+    let bare: ContractAbi = serde_json::from_str(r#""[]""#).unwrap();
+    assert_eq!(bare.abi, "[]");
 }
 
 #[test]
@@ -403,6 +427,32 @@ fn test_verification_request_deserialization() {
     let request: VerificationRequest = serde_json::from_str(json).unwrap();
 
     assert_eq!(request.guid, "ezq878u486pzijgvynpjq");
+
+    // The verification actions answer with the bare GUID
+    let request: VerificationRequest = serde_json::from_str(r#""ezq878u486pzijgvynpjq""#).unwrap();
+    assert_eq!(request.guid, "ezq878u486pzijgvynpjq");
+
+    let status: VerificationStatus = serde_json::from_str(r#""Pending in queue""#).unwrap();
+    assert!(status.is_pending());
+}
+
+#[test]
+fn test_gas_oracle_deserialization() {
+    let oracle: GasOracle = serde_json::from_value(serde_json::json!({
+        "LastBlock": "21000000",
+        "SafeGasPrice": "0.482",
+        "ProposeGasPrice": "0.5",
+        "FastGasPrice": "12",
+        "suggestBaseFee": "0.481963218",
+        "gasUsedRatio": "0.44,0.52,0.38,0.61,0.47"
+    }))
+    .unwrap();
+
+    assert_eq!(oracle.block(), 21_000_000);
+    assert_eq!(oracle.safe_wei(), Some(482_000_000));
+    assert_eq!(oracle.propose_wei(), Some(500_000_000));
+    assert_eq!(oracle.fast_wei(), Some(12_000_000_000));
+    assert_eq!(oracle.base_fee_wei(), Some(481_963_218));
 }
 
 #[test]
@@ -617,3 +667,330 @@ fn test_optimization_settings_edge_cases() {
     assert!(!disabled_with_runs.enabled);
     assert_eq!(disabled_with_runs.runs, 200);
 }
+
+fn transfer_log(topics: &[&str], data: &str) -> TransactionLog {
+    serde_json::from_value(serde_json::json!({
+        "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "topics": topics,
+        "data": data,
+        "blockNumber": "0x10",
+        "transactionHash": "0xabc",
+        "transactionIndex": "0x0",
+        "blockHash": "0xdef",
+        "logIndex": "0x3",
+        "removed": false
+    }))
+    .unwrap()
+}
+
+const TRANSFER: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+const FROM_TOPIC: &str = "0x000000000000000000000000742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const TO_TOPIC: &str = "0x0000000000000000000000001234567890123456789012345678901234567890";
+
+#[test]
+fn test_decoded_transfers() {
+    // ERC-20 amount wider than u128 is still decoded exactly
+    let erc20 = transfer_log(
+        &[TRANSFER, FROM_TOPIC, TO_TOPIC],
+        "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    );
+    let transfers = DecodedTransfer::from_log(&erc20);
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].standard, TokenStandard::Erc20);
+    assert_eq!(
+        transfers[0].from.as_str(),
+        "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6"
+    );
+    assert_eq!(
+        transfers[0].to.as_str(),
+        "0x1234567890123456789012345678901234567890"
+    );
+    assert_eq!(
+        transfers[0].value.as_str(),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+    assert_eq!(transfers[0].log_index, 3);
+
+    // ERC-721 indexes the token ID and carries no data
+    let erc721 = transfer_log(
+        &[
+            TRANSFER,
+            FROM_TOPIC,
+            TO_TOPIC,
+            "0x000000000000000000000000000000000000000000000000000000000000002a",
+        ],
+        "0x",
+    );
+    let transfers = DecodedTransfer::from_log(&erc721);
+    assert_eq!(transfers[0].standard, TokenStandard::Erc721);
+    assert_eq!(transfers[0].token_id.as_ref().unwrap().as_str(), "42");
+    assert_eq!(transfers[0].value.as_str(), "1");
+
+    // ERC-1155 batch: ids [1, 2] and values [10, 20]
+    let batch_data = ["40", "a0", "2", "1", "2", "2", "a", "14"]
+        .iter()
+        .map(|word| format!("{:0>64}", word))
+        .collect::<String>();
+    let batch = transfer_log(
+        &[
+            "0x4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb",
+            FROM_TOPIC,
+            FROM_TOPIC,
+            TO_TOPIC,
+        ],
+        &format!("0x{}", batch_data),
+    );
+    let transfers = DecodedTransfer::from_log(&batch);
+    let decoded: Vec<(&str, &str)> = transfers
+        .iter()
+        .map(|transfer| {
+            (
+                transfer.token_id.as_ref().unwrap().as_str(),
+                transfer.value.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(decoded, vec![("1", "10"), ("2", "20")]);
+    assert!(transfers
+        .iter()
+        .all(|t| t.standard == TokenStandard::Erc1155));
+
+    // Other events are ignored
+    let approval = transfer_log(
+        &[
+            "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925",
+            FROM_TOPIC,
+            TO_TOPIC,
+        ],
+        "0x0000000000000000000000000000000000000000000000000000000000000001",
+    );
+    assert!(DecodedTransfer::from_log(&approval).is_empty());
+}
+
+#[test]
+fn test_transaction_details_fee() {
+    let transaction: RpcTransaction = serde_json::from_value(serde_json::json!({
+        "hash": "0xabc",
+        "blockHash": "0xdef",
+        "blockNumber": "0x10",
+        "transactionIndex": "0x0",
+        "from": "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6",
+        "to": "0x1234567890123456789012345678901234567890",
+        "value": "0xde0b6b3a7640000",
+        "nonce": "0x1",
+        "gas": "0x5208",
+        "gasPrice": "0x9502f9000",
+        "maxFeePerGas": "0x9502f9000",
+        "maxPriorityFeePerGas": "0x3b9aca00",
+        "input": "0x",
+        "type": "0x2"
+    }))
+    .unwrap();
+    assert_eq!(transaction.value_wei(), 10u128.pow(18));
+    assert_eq!(transaction.block(), Some(16));
+
+    let mut receipt: TransactionReceipt = serde_json::from_value(serde_json::json!({
+        "blockHash": "0xdef",
+        "blockNumber": "0x10",
+        "contractAddress": null,
+        "cumulativeGasUsed": "0x5208",
+        "effectiveGasPrice": "0x77359400",
+        "from": "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6",
+        "gasUsed": "0x5208",
+        "logs": [],
+        "logsBloom": "0x00",
+        "status": "0x1",
+        "to": "0x1234567890123456789012345678901234567890",
+        "transactionHash": "0xabc",
+        "transactionIndex": "0x0",
+        "type": "0x2"
+    }))
+    .unwrap();
+
+    // The effective price (2 gwei) wins over the fee cap (40 gwei)
+    let details = TransactionDetails::new(
+        transaction.clone(),
+        Some(receipt.clone()),
+        Vec::new(),
+        Some(Timestamp::from(1_700_000_000)),
+        None,
+    );
+    assert!(details.is_successful());
+    assert_eq!(details.effective_gas_price, Some(2_000_000_000));
+    assert_eq!(details.fee, Some(21_000 * 2_000_000_000));
+    assert_eq!(details.gas_used(), Some(21_000));
+
+    // Legacy receipts without effectiveGasPrice fall back to gasPrice
+    receipt.effective_gas_price = None;
+    receipt.status = HexNumber::from(0);
+    let details = TransactionDetails::new(
+        transaction.clone(),
+        Some(receipt),
+        Vec::new(),
+        None,
+        Some("Reverted".to_string()),
+    );
+    assert_eq!(details.effective_gas_price, Some(40_000_000_000));
+    assert_eq!(details.fee, Some(21_000 * 40_000_000_000));
+    assert_eq!(
+        details.status,
+        ExecutionStatus::Failed {
+            reason: Some("Reverted".to_string())
+        }
+    );
+
+    // Pending transactions have no fee yet
+    let details = TransactionDetails::new(transaction, None, Vec::new(), None, None);
+    assert!(details.is_pending());
+    assert_eq!(details.fee, None);
+}
+
+fn token_transfer(value: serde_json::Value) -> TokenTransfer {
+    let mut transfer = serde_json::json!({
+        "blockNumber": "1",
+        "timeStamp": "1600000000",
+        "hash": "0xabc",
+        "blockHash": "0xdef",
+        "transactionIndex": "0",
+        "from": "0x1234567890123456789012345678901234567890",
+        "to": "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6",
+        "contractAddress": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "tokenName": "USD Coin",
+        "tokenSymbol": "USDC",
+        "gasPrice": "1000000000",
+        "gasUsed": "21000"
+    });
+    transfer
+        .as_object_mut()
+        .unwrap()
+        .extend(value.as_object().unwrap().clone());
+    serde_json::from_value(transfer).unwrap()
+}
+
+#[test]
+fn test_token_transfer_shapes() {
+    // tokennfttx has no value field
+    let nft =
+        token_transfer(serde_json::json!({ "tokenID": "7", "tokenDecimal": "0", "logIndex": "1" }));
+    assert_eq!(nft.value.as_str(), "1");
+    assert_eq!(nft.nft_token_id(), Some("7"));
+
+    // token1155tx names the amount tokenValue and has no decimals or log index
+    let erc1155 = token_transfer(serde_json::json!({ "tokenID": "3", "tokenValue": "25" }));
+    assert_eq!(erc1155.value.as_str(), "25");
+    assert_eq!(erc1155.decimals(), 0);
+    assert_eq!(erc1155.log_index.value(), 0);
+}
+
+#[test]
+fn test_token_ledger() {
+    const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+    const BOB: &str = "0x1234567890123456789012345678901234567890";
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    let erc20 = |block: u64, from: &str, to: &str, value: &str| {
+        token_transfer(serde_json::json!({
+            "blockNumber": block.to_string(),
+            "from": from,
+            "to": to,
+            "value": value,
+            "tokenDecimal": "6",
+            "logIndex": "0"
+        }))
+    };
+    let nft = |block: u64, from: &str, to: &str, id: &str| {
+        token_transfer(serde_json::json!({
+            "blockNumber": block.to_string(),
+            "from": from,
+            "to": to,
+            "contractAddress": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+            "tokenID": id,
+            "tokenDecimal": "0",
+            "logIndex": "0"
+        }))
+    };
+    let erc1155 = |block: u64, from: &str, to: &str, id: &str, value: &str| {
+        token_transfer(serde_json::json!({
+            "blockNumber": block.to_string(),
+            "from": from,
+            "to": to,
+            "contractAddress": "0x76be3b62873462d2142405439777e971754e8e77",
+            "tokenID": id,
+            "tokenValue": value
+        }))
+    };
+
+    let ledger = TokenLedger::new(
+        ALICE,
+        vec![
+            erc20(30, ALICE, BOB, "250000"),
+            erc20(10, BOB, ALICE, "1000000"),
+            // Larger than u64: still exact
+            erc20(40, BOB, ALICE, "123456789012345678901234"),
+        ],
+        vec![
+            nft(10, BOB, ALICE, "1"),
+            nft(20, BOB, ALICE, "2"),
+            nft(30, ALICE, BOB, "1"),
+        ],
+        vec![
+            erc1155(10, BOB, ALICE, "5", "10"),
+            erc1155(20, ALICE, BOB, "5", "4"),
+            erc1155(20, BOB, ALICE, "6", "1"),
+            erc1155(30, ALICE, BOB, "6", "1"),
+        ],
+    );
+
+    let flow = ledger.flow(USDC).unwrap().unwrap();
+    assert_eq!(flow.inflow, 123456789012345679901234);
+    assert_eq!(flow.outflow, 250_000);
+    assert_eq!(flow.net(), Some(123456789012345679651234));
+    assert_eq!(flow.net_units(), "123456789012345679.651234");
+    assert_eq!(flow.transfers, 3);
+    assert_eq!(ledger.last_block(), Some(40));
+
+    let holdings = ledger.nft_holdings();
+    assert_eq!(holdings.len(), 1);
+    assert_eq!(holdings[0].token_ids.iter().collect::<Vec<_>>(), vec!["2"]);
+
+    let balances = ledger.erc1155_balances().unwrap();
+    assert_eq!(balances.len(), 1);
+    assert_eq!(
+        (balances[0].token_id.as_str(), balances[0].balance),
+        ("5", 6)
+    );
+
+    // Rewind to block 25
+    let past = ledger.at_block(25);
+    let flow = past.flow(USDC).unwrap().unwrap();
+    assert_eq!(
+        (flow.inflow_units(), flow.outflow_units()),
+        ("1".to_string(), "0".to_string())
+    );
+    let holdings = past.nft_holdings();
+    let held: Vec<&String> = holdings[0].token_ids.iter().collect();
+    assert_eq!(held, vec!["1", "2"]);
+    let balances: Vec<(String, i128)> = past
+        .erc1155_balances()
+        .unwrap()
+        .into_iter()
+        .map(|balance| (balance.token_id, balance.balance))
+        .collect();
+    assert_eq!(balances, vec![("5".to_string(), 6), ("6".to_string(), 1)]);
+    assert!(ledger.at_block(5).flows().unwrap().is_empty());
+
+    // Amounts beyond u128 fail instead of counting as zero
+    let huge = "1".repeat(40);
+    let ledger = TokenLedger::new(ALICE, vec![erc20(10, BOB, ALICE, &huge)], vec![], vec![]);
+    assert!(matches!(ledger.flows(), Err(EtherscanError::Parse { .. })));
+    let ledger = TokenLedger::new(
+        ALICE,
+        vec![],
+        vec![],
+        vec![erc1155(10, BOB, ALICE, "5", &u128::MAX.to_string())],
+    );
+    assert!(matches!(
+        ledger.erc1155_balances(),
+        Err(EtherscanError::Parse { .. })
+    ));
+}
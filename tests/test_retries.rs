@@ -0,0 +1,86 @@
+//! Retries, empty list results and rate-limit mapping, against the mock server
+
+use keion_etherscan::mock::{MockChain, MockConfig, MockServer, MOCK_API_KEY};
+use keion_etherscan::{EtherscanClient, EtherscanError};
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+
+fn client(server: &MockServer, api_key: &str, max_retries: u32) -> EtherscanClient {
+    EtherscanClient::builder()
+        .api_key(api_key)
+        .base_url(server.url())
+        .rate_limit(0)
+        .max_retries(max_retries)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_no_retries_by_default() {
+    let config = MockConfig::default().http_failures(1);
+    let server = MockServer::start_with(MockChain::new(), config)
+        .await
+        .unwrap();
+
+    let error = server.client().accounts().balance(ALICE).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::Http { status: 502, .. }));
+    assert_eq!(server.request_count(), 1);
+}
+
+#[tokio::test]
+async fn test_retries_stop_after_max_retries() {
+    let config = MockConfig::default().http_failures(5);
+    let server = MockServer::start_with(MockChain::new(), config)
+        .await
+        .unwrap();
+
+    let error = client(&server, MOCK_API_KEY, 2)
+        .accounts()
+        .balance(ALICE)
+        .await
+        .unwrap_err();
+    assert_eq!(error.category(), "http");
+    assert_eq!(server.request_count(), 3);
+}
+
+#[tokio::test]
+async fn test_api_errors_are_not_retried() {
+    let server = MockServer::start(MockChain::new()).await.unwrap();
+
+    let error = client(&server, "wrong-key", 3)
+        .accounts()
+        .balance(ALICE)
+        .await
+        .unwrap_err();
+    assert!(!error.is_retryable());
+    assert_eq!(server.request_count(), 1);
+}
+
+#[tokio::test]
+async fn test_rate_limit_results_map_to_rate_limit_errors() {
+    let config = MockConfig::default().rate_limit(1);
+    let server = MockServer::start_with(MockChain::new(), config)
+        .await
+        .unwrap();
+    let client = server.client();
+
+    client.accounts().balance(ALICE).await.unwrap();
+    let error = client.accounts().balance(ALICE).await.unwrap_err();
+    assert!(matches!(error, EtherscanError::RateLimit { .. }));
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn test_empty_lists_are_not_errors() {
+    // Etherscan reports "No transactions found" as status 0 with an empty result
+    let server = MockServer::start(MockChain::new()).await.unwrap();
+
+    let transactions = server
+        .client()
+        .accounts()
+        .transactions(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    assert!(transactions.is_empty());
+}
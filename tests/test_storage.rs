@@ -0,0 +1,192 @@
+#![cfg(feature = "sqlite")]
+
+use keion_etherscan::mock::{MockChain, MockServer};
+use keion_etherscan::storage::SqliteStore;
+use keion_etherscan::{
+    ActivityKind, BeaconWithdrawal, EtherscanError, InternalTransaction, TokenStandard,
+    TokenTransfer,
+};
+use serde_json::json;
+
+const ALICE: &str = "0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6";
+const BOB: &str = "0x1234567890123456789012345678901234567890";
+const USDT: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+
+fn transfer(block: u64, log_index: u64, value: &str) -> TokenTransfer {
+    serde_json::from_value(json!({
+        "blockNumber": block.to_string(),
+        "blockHash": format!("0x{:064x}", block),
+        "hash": format!("0x{:064x}", block),
+        "transactionIndex": "0",
+        "from": ALICE,
+        "to": BOB,
+        "contractAddress": USDT,
+        "value": value,
+        "tokenName": "Tether USD",
+        "tokenSymbol": "USDT",
+        "tokenDecimal": "6",
+        "gasPrice": "1",
+        "gasUsed": "50000",
+        "timeStamp": MockChain::block_timestamp(block).to_string(),
+        "logIndex": log_index.to_string()
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_token_transfers_upsert() {
+    let store = SqliteStore::in_memory().unwrap();
+
+    let page = [
+        transfer(1, 0, "100"),
+        transfer(1, 1, "200"),
+        transfer(2, 0, "300"),
+    ];
+    assert_eq!(
+        store
+            .insert_token_transfers(TokenStandard::Erc20, &page)
+            .unwrap(),
+        3
+    );
+
+    // Re-fetching an overlapping page updates rows instead of duplicating them
+    let page = [transfer(2, 0, "301"), transfer(3, 0, "400")];
+    store
+        .insert_token_transfers(TokenStandard::Erc20, &page)
+        .unwrap();
+
+    let stored = store.token_transfers(TokenStandard::Erc20, BOB).unwrap();
+    let values: Vec<&str> = stored.iter().map(|t| t.value.as_str()).collect();
+    assert_eq!(values, vec!["100", "200", "301", "400"]);
+    assert_eq!(store.contract_transfers(USDT).unwrap(), stored);
+    assert!(store
+        .token_transfers(TokenStandard::Erc721, BOB)
+        .unwrap()
+        .is_empty());
+
+    let total: i64 = store
+        .connection()
+        .query_row(
+            "SELECT SUM(CAST(value AS INTEGER)) FROM token_transfers WHERE to_address = ?1",
+            [BOB],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(total, 1001);
+}
+
+#[test]
+fn test_withdrawals_round_trip() {
+    let store = SqliteStore::in_memory().unwrap();
+    let withdrawal: BeaconWithdrawal = serde_json::from_value(json!({
+        "withdrawalIndex": "7",
+        "validatorIndex": "42",
+        "address": ALICE,
+        "amount": "3000000",
+        "blockNumber": "17000000",
+        "timestamp": "1681338479"
+    }))
+    .unwrap();
+
+    store
+        .insert_beacon_withdrawals(&[withdrawal.clone(), withdrawal.clone()])
+        .unwrap();
+    assert_eq!(store.beacon_withdrawals(ALICE).unwrap(), vec![withdrawal]);
+}
+
+#[test]
+fn test_checksummed_addresses_are_found() {
+    let store = SqliteStore::in_memory().unwrap();
+    let mut checksummed = transfer(1, 0, "100");
+    checksummed.from.0 = "0x742d35Cc6634C0532925a3b8D19389C4D5E1E4A6".to_string();
+    checksummed.contract_address.0 = "0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string();
+
+    store
+        .insert_token_transfers(TokenStandard::Erc20, &[checksummed.clone()])
+        .unwrap();
+    // Stored again with lowercase keys, the row is replaced
+    store
+        .insert_token_transfers(TokenStandard::Erc20, &[transfer(1, 0, "100")])
+        .unwrap();
+
+    assert_eq!(
+        store
+            .token_transfers(TokenStandard::Erc20, ALICE)
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(store.contract_transfers(USDT).unwrap().len(), 1);
+}
+
+#[test]
+fn test_internal_transactions_need_a_trace_id() {
+    let store = SqliteStore::in_memory().unwrap();
+    // Shaped like a row of a by-hash query, which has no trace ID
+    let internal: InternalTransaction = serde_json::from_value(json!({
+        "blockNumber": "5",
+        "timeStamp": MockChain::block_timestamp(5).to_string(),
+        "hash": format!("0x{:064x}", 5),
+        "from": ALICE,
+        "to": BOB,
+        "value": "1",
+        "contractAddress": "",
+        "input": "",
+        "type": "call",
+        "gas": "0",
+        "gasUsed": "0",
+        "isError": "0",
+        "errCode": ""
+    }))
+    .unwrap();
+
+    let error = store.insert_internal_transactions(&[internal]).unwrap_err();
+    assert!(matches!(error, EtherscanError::InvalidParams(_)));
+    assert!(store.internal_transactions(ALICE).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_store_indexed_events() {
+    let mut chain = MockChain::new();
+    let hashes: Vec<_> = (1..=4)
+        .map(|block| chain.transfer_eth(block, ALICE, BOB, 1_000 * block as u128))
+        .collect();
+    let server = MockServer::start(chain).await.unwrap();
+    let client = server.client();
+    let store = SqliteStore::in_memory().unwrap();
+
+    client
+        .indexer()
+        .target(ALICE, ActivityKind::Transaction)
+        .page_size(2)
+        .run(|batch| store.insert_events(&batch.events).map(|_| ()))
+        .await
+        .unwrap();
+
+    let stored = store.transactions(ALICE).unwrap();
+    let stored_hashes: Vec<_> = stored.iter().map(|tx| tx.hash.clone()).collect();
+    assert_eq!(stored_hashes, hashes);
+    assert_eq!(store.transactions(BOB).unwrap(), stored);
+    assert_eq!(
+        store
+            .transaction(hashes[2].as_str())
+            .unwrap()
+            .unwrap()
+            .block(),
+        3
+    );
+
+    // Fetching the same transactions again leaves one row each
+    let transactions = client
+        .accounts()
+        .transactions(ALICE)
+        .execute()
+        .await
+        .unwrap();
+    store.insert_transactions(&transactions).unwrap();
+    let count: i64 = store
+        .connection()
+        .query_row("SELECT COUNT(*) FROM transactions", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 4);
+}
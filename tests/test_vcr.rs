@@ -0,0 +1,199 @@
+mod common;
+
+use common::TestUtils;
+use keion_etherscan::mock::{MockChain, MockServer, MOCK_API_KEY};
+use keion_etherscan::vcr::{Cassette, Interaction};
+use keion_etherscan::{EtherscanClient, EtherscanError, Network, Sort};
+use std::path::PathBuf;
+
+const CASSETTE: &str = "tests/fixtures/cassettes/accounts.json";
+
+fn replay_client(path: &str) -> EtherscanClient {
+    EtherscanClient::builder()
+        .api_key("test-api-key-1234567890abcdef")
+        .network(Network::Mainnet)
+        .replay_from(path)
+        .build()
+        .unwrap()
+}
+
+fn temp_cassette(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "keion-etherscan-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+#[tokio::test]
+async fn test_replay_balance() {
+    let client = replay_client(CASSETTE);
+
+    let balance = client
+        .accounts()
+        .balance(TestUtils::valid_address_mixed_case())
+        .await
+        .unwrap();
+
+    assert_eq!(balance.wei(), "1000000000000000000");
+    assert_eq!(balance.eth(), Some(1.0));
+}
+
+#[tokio::test]
+async fn test_replay_paginated_transactions() {
+    let client = replay_client(CASSETTE);
+
+    let first_page = client
+        .accounts()
+        .transactions(TestUtils::valid_address())
+        .page(1)
+        .offset(10)
+        .sort(Sort::Ascending)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(first_page.len(), 1);
+    assert_eq!(first_page[0].block(), 12345678);
+
+    // Parameter order does not matter when matching
+    let second_page = client
+        .accounts()
+        .transactions(TestUtils::valid_address())
+        .sort(Sort::Ascending)
+        .offset(10)
+        .page(2)
+        .execute()
+        .await
+        .unwrap();
+    assert!(second_page.is_empty());
+}
+
+#[tokio::test]
+async fn test_replay_api_error() {
+    let client = replay_client(CASSETTE);
+
+    let result = client
+        .contracts()
+        .get_abi(TestUtils::contract_address())
+        .await;
+
+    match result {
+        Err(EtherscanError::Api { result, .. }) => {
+            assert_eq!(result.as_deref(), Some("Contract source code not verified"))
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_replay_unmatched_request_fails() {
+    let client = replay_client(CASSETTE);
+
+    let result = client
+        .accounts()
+        .balance(TestUtils::validator_address())
+        .await;
+
+    assert!(matches!(result, Err(EtherscanError::Internal(_))));
+}
+
+#[tokio::test]
+async fn test_replay_repeats_sequences() {
+    let path = temp_cassette("sequence");
+    let interaction = |body: &str| Interaction {
+        network: Network::Mainnet,
+        module: "account".to_string(),
+        action: "balance".to_string(),
+        params: vec![
+            (
+                "address".to_string(),
+                TestUtils::valid_address().to_string(),
+            ),
+            ("tag".to_string(), "latest".to_string()),
+        ],
+        status: 200,
+        body: body.to_string(),
+    };
+    Cassette {
+        interactions: vec![
+            interaction(r#"{"status":"1","message":"OK","result":"1"}"#),
+            interaction(r#"{"status":"1","message":"OK","result":"2"}"#),
+        ],
+    }
+    .save(&path)
+    .unwrap();
+
+    let client = replay_client(path.to_str().unwrap());
+    let mut balances = Vec::new();
+    for _ in 0..3 {
+        let balance = client
+            .accounts()
+            .balance(TestUtils::valid_address())
+            .await
+            .unwrap();
+        balances.push(balance.wei().to_string());
+    }
+
+    // Recordings are served in order, then the last one repeats
+    assert_eq!(balances, vec!["1", "2", "2"]);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_record_writes_on_save_and_drop() {
+    let address = TestUtils::valid_address();
+    let mut chain = MockChain::new();
+    chain.add_balance(address, 7);
+    let server = MockServer::start(chain).await.unwrap();
+    let path = temp_cassette("record");
+    let _ = std::fs::remove_file(&path);
+
+    let client = EtherscanClient::builder()
+        .api_key(MOCK_API_KEY)
+        .base_url(server.url())
+        .rate_limit(0)
+        .record_to(&path)
+        .build()
+        .unwrap();
+
+    client.accounts().balance(address).await.unwrap();
+    // Interactions stay in memory until saved
+    assert!(!path.exists());
+    client.save_cassette().unwrap();
+    assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 1);
+
+    client.proxy().block_number().await.unwrap();
+    drop(client);
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 2);
+    assert!(cassette.interactions[0].body.contains("\"7\""));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_missing_cassette_fails_to_build() {
+    let result = EtherscanClient::builder()
+        .api_key("test-api-key-1234567890abcdef")
+        .replay_from("tests/fixtures/cassettes/does-not-exist.json")
+        .build();
+
+    assert!(matches!(result, Err(EtherscanError::Internal(_))));
+}
+
+#[test]
+fn test_cassette_round_trip() {
+    let path = temp_cassette("round-trip");
+    let cassette = Cassette::load(CASSETTE).unwrap();
+
+    cassette.save(&path).unwrap();
+    assert_eq!(Cassette::load(&path).unwrap(), cassette);
+
+    // Recorded interactions never contain the API key
+    assert!(cassette
+        .interactions
+        .iter()
+        .all(|interaction| interaction.params.iter().all(|(key, _)| key != "apikey")));
+
+    std::fs::remove_file(path).unwrap();
+}
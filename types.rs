@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Supported Ethereum networks
@@ -48,6 +49,17 @@ impl Network {
         }
     }
 
+    /// Get the symbol of the network's native currency
+    pub fn native_currency(&self) -> &'static str {
+        match self {
+            Network::Mainnet | Network::Goerli | Network::Sepolia => "ETH",
+            Network::BinanceSmartChain => "BNB",
+            Network::Polygon => "POL",
+            Network::Fantom => "FTM",
+            Network::Arbitrum | Network::Optimism => "ETH",
+        }
+    }
+
     /// Get the chain ID for the network
     pub fn chain_id(&self) -> u64 {
         match self {
@@ -75,6 +87,28 @@ impl std::fmt::Display for Network {
     }
 }
 
+/// Parses the serde names (`mainnet`, `sepolia`, `bsc`, ...), case-insensitively
+impl std::str::FromStr for Network {
+    type Err = crate::EtherscanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" => Ok(Network::Mainnet),
+            "goerli" => Ok(Network::Goerli),
+            "sepolia" => Ok(Network::Sepolia),
+            "bsc" => Ok(Network::BinanceSmartChain),
+            "polygon" => Ok(Network::Polygon),
+            "fantom" => Ok(Network::Fantom),
+            "arbitrum" => Ok(Network::Arbitrum),
+            "optimism" => Ok(Network::Optimism),
+            _ => Err(crate::EtherscanError::InvalidParams(format!(
+                "Unknown network: {}",
+                s
+            ))),
+        }
+    }
+}
+
 /// Sort order for API responses
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -138,6 +172,26 @@ impl std::fmt::Display for Tag {
     }
 }
 
+/// Which block to pick when resolving a timestamp to a block number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Closest {
+    /// Last block mined at or before the timestamp
+    Before,
+    /// First block mined at or after the timestamp
+    After,
+}
+
+impl Closest {
+    /// Value of the `closest` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Closest::Before => "before",
+            Closest::After => "after",
+        }
+    }
+}
+
 /// Block type for certain API calls
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -189,14 +243,47 @@ pub struct EtherscanResponse<T> {
     pub result: T,
 }
 
+/// JSON-RPC response wrapper returned by the `proxy` module
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsonRpcResponse {
+    // Required so that arbitrary objects are not mistaken for JSON-RPC
+    #[allow(dead_code)]
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    #[serde(default)]
+    pub error: Option<JsonRpcError>,
+}
+
+/// Error object of a JSON-RPC response
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
 /// Pagination parameters for API requests
-#[derive(Debug, Clone, Default)]
+///
+/// Unset fields are left out when serialized.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Pagination {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_block: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub end_block: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<Sort>,
+    /// Start of a time range, resolved to `start_block` before the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    /// End of a time range, resolved to `end_block` before the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateTime<Utc>>,
 }
 
 impl Pagination {
@@ -235,6 +322,30 @@ impl Pagination {
         self
     }
 
+    /// Start at the first block mined at or after `time`
+    pub fn since(mut self, time: DateTime<Utc>) -> Self {
+        self.since = Some(time);
+        self
+    }
+
+    /// End at the last block mined at or before `time`
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.until = Some(time);
+        self
+    }
+
+    /// Limit to blocks mined between `start` and `end` (inclusive)
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.since = Some(start);
+        self.until = Some(end);
+        self
+    }
+
+    /// Check whether a time range still has to be resolved to blocks
+    pub fn has_time_range(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
     /// Convert to query parameters
     pub fn to_params(&self) -> Vec<(&'static str, String)> {
         let mut params = Vec::new();
@@ -0,0 +1,25 @@
+//! Helpers shared across modules
+
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counter keeping the temporary files of concurrent writes apart
+static TEMPORARY_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Replace the contents of `path` with `bytes`
+///
+/// Write then rename so a crash never leaves a truncated file. The temporary
+/// file sits next to `path` and is named after the process, so concurrent
+/// writers never share one.
+pub(crate) fn write_atomic(path: &Path, bytes: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let temporary = path.with_extension(format!(
+        "{}.{}.tmp",
+        std::process::id(),
+        TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&temporary, bytes)
+        .and_then(|_| std::fs::rename(&temporary, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&temporary);
+        })
+}
@@ -0,0 +1,260 @@
+//! Record/replay ("VCR") support for deterministic offline tests
+//!
+//! In record mode the client performs real HTTP requests and keeps every
+//! request/response pair in memory. The cassette file is written when the
+//! last clone of the client is dropped, or earlier with
+//! [`EtherscanClient::save_cassette`](crate::EtherscanClient::save_cassette).
+//! In replay mode no network traffic
+//! happens: responses are served from the cassette and a request without a
+//! recorded counterpart fails with [`EtherscanError::Internal`].
+//!
+//! Interactions are matched on network, module, action and query parameters
+//! (order-insensitive). The API key is never part of a recorded interaction.
+//! When the same request was recorded several times, replay serves the
+//! recordings in order and then keeps repeating the last one.
+//!
+//! ```rust,no_run
+//! use keion_etherscan::EtherscanClient;
+//!
+//! # fn main() -> keion_etherscan::Result<()> {
+//! // Capture once against the real API...
+//! let recorder = EtherscanClient::builder()
+//!     .api_key("your-api-key-here")
+//!     .record_to("tests/fixtures/cassettes/accounts.json")
+//!     .build()?;
+//! // ...run the requests to capture, then
+//! recorder.save_cassette()?;
+//!
+//! // ...and replay forever in CI
+//! let replayer = EtherscanClient::builder()
+//!     .api_key("unused")
+//!     .replay_from("tests/fixtures/cassettes/accounts.json")
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{middleware::Request, EtherscanError, Network, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Whether a client records new interactions or replays recorded ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Perform real requests and save them to the cassette
+    Record,
+    /// Serve responses from the cassette without touching the network
+    Replay,
+}
+
+/// A single recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Network the request was sent to
+    pub network: Network,
+    /// API module
+    pub module: String,
+    /// API action
+    pub action: String,
+    /// Query parameters and POST form fields, sorted by key, without the API key
+    pub params: Vec<(String, String)>,
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Raw response body
+    pub body: String,
+}
+
+impl Interaction {
+    fn matches(
+        &self,
+        network: Network,
+        module: &str,
+        action: &str,
+        params: &[(String, String)],
+    ) -> bool {
+        self.network == network
+            && self.module == module
+            && self.action == action
+            && self.params == params
+    }
+}
+
+/// Collection of recorded interactions, stored as JSON
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in the order they were recorded
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Load a cassette from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            EtherscanError::Internal(format!("Failed to read cassette {}: {}", path.display(), e))
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the cassette as pretty-printed JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                EtherscanError::Internal(format!(
+                    "Failed to create cassette directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content).map_err(|e| {
+            EtherscanError::Internal(format!(
+                "Failed to write cassette {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+#[derive(Debug)]
+struct VcrState {
+    cassette: Cassette,
+    replayed: Vec<bool>,
+    /// Whether interactions were recorded since the last save
+    unsaved: bool,
+}
+
+/// Cassette attached to a client
+#[derive(Debug)]
+pub(crate) struct Vcr {
+    mode: VcrMode,
+    path: PathBuf,
+    state: Mutex<VcrState>,
+}
+
+impl Vcr {
+    pub(crate) fn new(mode: VcrMode, path: PathBuf) -> Result<Self> {
+        let cassette = match mode {
+            VcrMode::Record => Cassette::default(),
+            VcrMode::Replay => Cassette::load(&path)?,
+        };
+        let replayed = vec![false; cassette.interactions.len()];
+
+        Ok(Self {
+            mode,
+            path,
+            state: Mutex::new(VcrState {
+                cassette,
+                replayed,
+                unsaved: false,
+            }),
+        })
+    }
+
+    pub(crate) fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Find the recorded response for a request
+    pub(crate) fn replay(
+        &self,
+        request: &Request,
+        form: &[(String, String)],
+    ) -> Result<(u16, String)> {
+        let params = sorted_params(request, form);
+        let mut state = self.state.lock().expect("cassette lock poisoned");
+
+        let matching: Vec<usize> = state
+            .cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| {
+                interaction.matches(request.network, &request.module, &request.action, &params)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        let index = matching
+            .iter()
+            .copied()
+            .find(|&index| !state.replayed[index])
+            .or_else(|| matching.last().copied())
+            .ok_or_else(|| {
+                EtherscanError::Internal(format!(
+                    "No recorded interaction in {} for {}/{} with params {:?}",
+                    self.path.display(),
+                    request.module,
+                    request.action,
+                    params
+                ))
+            })?;
+
+        state.replayed[index] = true;
+        let interaction = &state.cassette.interactions[index];
+        Ok((interaction.status, interaction.body.clone()))
+    }
+
+    /// Append a response to the cassette
+    ///
+    /// Nothing is written until [`save`](Self::save) or drop.
+    pub(crate) fn record(
+        &self,
+        request: &Request,
+        form: &[(String, String)],
+        status: u16,
+        body: &str,
+    ) {
+        let mut state = self.state.lock().expect("cassette lock poisoned");
+
+        state.cassette.interactions.push(Interaction {
+            network: request.network,
+            module: request.module.clone(),
+            action: request.action.clone(),
+            params: sorted_params(request, form),
+            status,
+            body: body.to_string(),
+        });
+        state.replayed.push(false);
+        state.unsaved = true;
+    }
+
+    /// Write the recorded interactions to the cassette file, if any are unsaved
+    pub(crate) fn save(&self) -> Result<()> {
+        let mut state = self.state.lock().expect("cassette lock poisoned");
+        if !state.unsaved {
+            return Ok(());
+        }
+        state.cassette.save(&self.path)?;
+        state.unsaved = false;
+        Ok(())
+    }
+}
+
+impl Drop for Vcr {
+    fn drop(&mut self) {
+        if let Err(error) = self.save() {
+            tracing::warn!(path = %self.path.display(), %error, "failed to save cassette");
+        }
+    }
+}
+
+fn sorted_params(request: &Request, form: &[(String, String)]) -> Vec<(String, String)> {
+    let mut params: Vec<(String, String)> = request
+        .params
+        .iter()
+        .chain(form)
+        .filter(|(key, _)| !key.eq_ignore_ascii_case("apikey"))
+        .cloned()
+        .collect();
+    params.sort();
+    params
+}
@@ -0,0 +1,439 @@
+//! Polling watcher that streams new activity of an address
+//!
+//! [`EtherscanClient::watch`] polls the account list endpoints (through the
+//! activity feed) from the last processed block and yields every new
+//! transaction or transfer once it has the configured number of
+//! confirmations. The last `reorg_depth` blocks are fetched again on every
+//! poll: an event that disappeared is reported as [`WatchEvent::Removed`].
+//!
+//! Progress is kept in a [`WatchCursor`]. With a [`CursorStore`] the cursor is
+//! saved whenever the consumer asks for more events after draining a poll, so
+//! a restarted watcher resumes where the previous one stopped without
+//! skipping or repeating events (only the event being handled during a crash
+//! can be delivered twice).
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use keion_etherscan::{watch::FileCursorStore, EtherscanClient};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> keion_etherscan::Result<()> {
+//!     let client = EtherscanClient::new("YOUR_API_KEY")?;
+//!     let mut events = Box::pin(
+//!         client
+//!             .watch("0x742d35cc6634c0532925a3b8d19389c4d5e1e4a6")
+//!             .confirmations(12)
+//!             .poll_interval(Duration::from_secs(30))
+//!             .cursor_store(FileCursorStore::new("watch-state"))
+//!             .stream(),
+//!     );
+//!
+//!     while let Some(event) = events.next().await {
+//!         println!("{:?}", event?);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    models::{ActivityEvent, ActivityKind},
+    utils::write_atomic,
+    EtherscanClient, EtherscanError, Result,
+};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub use crate::endpoints::proxy::DEFAULT_POLL_INTERVAL;
+
+/// Default number of recent blocks re-checked for reorgs on every poll
+pub const DEFAULT_REORG_DEPTH: u64 = 12;
+
+/// Change in the activity of a watched address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WatchEvent {
+    /// A new event reached the confirmation depth
+    Added {
+        /// The event
+        event: ActivityEvent,
+    },
+    /// A previously added event is no longer on chain after a reorg
+    Removed {
+        /// The event as it was added
+        event: ActivityEvent,
+    },
+}
+
+impl WatchEvent {
+    /// Get the underlying activity event
+    pub fn event(&self) -> &ActivityEvent {
+        match self {
+            WatchEvent::Added { event } | WatchEvent::Removed { event } => event,
+        }
+    }
+
+    /// Check if this event retracts an earlier one
+    pub fn is_removal(&self) -> bool {
+        matches!(self, WatchEvent::Removed { .. })
+    }
+}
+
+/// Progress of a watcher
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchCursor {
+    /// First block not processed yet
+    pub next_block: u64,
+
+    /// Events emitted within the reorg window, re-checked on every poll
+    pub recent: Vec<ActivityEvent>,
+
+    /// First block the watcher started from; the reorg window never reaches
+    /// below it, so blocks before the start are not reported
+    #[serde(default)]
+    pub start_block: u64,
+}
+
+/// Persistence for watcher cursors, keyed by network and address
+pub trait CursorStore: Send + Sync + fmt::Debug {
+    /// Load a saved cursor
+    fn load(&self, key: &str) -> Result<Option<WatchCursor>>;
+
+    /// Save a cursor, replacing any previous one
+    fn save(&self, key: &str, cursor: &WatchCursor) -> Result<()>;
+}
+
+/// Cursor store kept in memory, shared by its clones
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCursorStore {
+    cursors: Arc<Mutex<HashMap<String, WatchCursor>>>,
+}
+
+impl MemoryCursorStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CursorStore for MemoryCursorStore {
+    fn load(&self, key: &str) -> Result<Option<WatchCursor>> {
+        Ok(self
+            .cursors
+            .lock()
+            .expect("cursor store lock poisoned")
+            .get(key)
+            .cloned())
+    }
+
+    fn save(&self, key: &str, cursor: &WatchCursor) -> Result<()> {
+        self.cursors
+            .lock()
+            .expect("cursor store lock poisoned")
+            .insert(key.to_string(), cursor.clone());
+        Ok(())
+    }
+}
+
+/// Cursor store writing one JSON file per cursor into a directory
+#[derive(Debug, Clone)]
+pub struct FileCursorStore {
+    directory: PathBuf,
+}
+
+impl FileCursorStore {
+    /// Store cursors in `directory`, which is created on first save
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        self.directory.join(format!("{}.json", name))
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&self, key: &str) -> Result<Option<WatchCursor>> {
+        let path = self.path(key);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(EtherscanError::Internal(format!(
+                    "Failed to read cursor {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|e| EtherscanError::parse(format!("Invalid cursor {}: {}", path.display(), e)))
+    }
+
+    fn save(&self, key: &str, cursor: &WatchCursor) -> Result<()> {
+        let path = self.path(key);
+        let text = serde_json::to_string(cursor)
+            .map_err(|e| EtherscanError::Internal(format!("Failed to encode cursor: {}", e)))?;
+
+        std::fs::create_dir_all(&self.directory)
+            .and_then(|_| write_atomic(&path, text))
+            .map_err(|e| {
+                EtherscanError::Internal(format!(
+                    "Failed to write cursor {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+    }
+}
+
+/// Builder for an address watcher
+#[derive(Debug)]
+pub struct WatchBuilder {
+    client: EtherscanClient,
+    address: String,
+    kinds: Vec<ActivityKind>,
+    confirmations: u64,
+    reorg_depth: u64,
+    poll_interval: Duration,
+    start_block: Option<u64>,
+    store: Option<Arc<dyn CursorStore>>,
+}
+
+impl WatchBuilder {
+    pub(crate) fn new(client: &EtherscanClient, address: &str) -> Self {
+        Self {
+            client: client.clone(),
+            address: address.to_lowercase(),
+            kinds: ActivityKind::ALL.to_vec(),
+            confirmations: 0,
+            reorg_depth: DEFAULT_REORG_DEPTH,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            start_block: None,
+            store: None,
+        }
+    }
+
+    /// Only watch the given kinds of activity
+    pub fn kinds(mut self, kinds: &[ActivityKind]) -> Self {
+        self.kinds = kinds.to_vec();
+        self
+    }
+
+    /// Skip a kind of activity
+    pub fn exclude(mut self, kind: ActivityKind) -> Self {
+        self.kinds.retain(|&k| k != kind);
+        self
+    }
+
+    /// Only emit events with at least this many blocks on top (default: 0)
+    pub fn confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Re-check this many recent blocks for reorgs on every poll (default: 12)
+    pub fn reorg_depth(mut self, blocks: u64) -> Self {
+        self.reorg_depth = blocks;
+        self
+    }
+
+    /// Set the time between two polls (default: 12 seconds)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Start from this block when there is no saved cursor
+    ///
+    /// Without it, a new watcher only reports activity after the current head.
+    pub fn start_block(mut self, block: u64) -> Self {
+        self.start_block = Some(block);
+        self
+    }
+
+    /// Persist the cursor in `store`
+    pub fn cursor_store<S: CursorStore + 'static>(mut self, store: S) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    // Getter methods for testing
+
+    /// Watched address
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Kinds of activity watched
+    pub fn get_kinds(&self) -> &[ActivityKind] {
+        &self.kinds
+    }
+
+    /// Required confirmations
+    pub fn get_confirmations(&self) -> u64 {
+        self.confirmations
+    }
+
+    /// Number of blocks re-checked for reorgs
+    pub fn get_reorg_depth(&self) -> u64 {
+        self.reorg_depth
+    }
+
+    /// Key the cursor is stored under
+    pub fn cursor_key(&self) -> String {
+        format!("{}-{}", self.client.network(), self.address)
+    }
+
+    /// Start watching
+    ///
+    /// The stream never ends. A failed poll yields an error and the next poll
+    /// retries from the same cursor.
+    pub fn stream(self) -> impl Stream<Item = Result<WatchEvent>> {
+        let watcher = Watcher {
+            key: self.cursor_key(),
+            builder: self,
+            cursor: None,
+            buffer: VecDeque::new(),
+            batch_end: None,
+            dirty: false,
+            polled: false,
+        };
+
+        futures::stream::unfold(watcher, |mut watcher| async move {
+            let item = watcher.next().await;
+            Some((item, watcher))
+        })
+    }
+}
+
+/// State of a running watcher
+struct Watcher {
+    builder: WatchBuilder,
+    key: String,
+    /// Cursor covering every event handed out so far
+    cursor: Option<WatchCursor>,
+    buffer: VecDeque<WatchEvent>,
+    /// Next block to process once the buffer is drained
+    batch_end: Option<u64>,
+    dirty: bool,
+    polled: bool,
+}
+
+impl Watcher {
+    async fn next(&mut self) -> Result<WatchEvent> {
+        loop {
+            // Asking for more means the previous event was handled
+            if self.dirty {
+                if let (Some(store), Some(cursor)) = (&self.builder.store, &self.cursor) {
+                    store.save(&self.key, cursor)?;
+                }
+                self.dirty = false;
+            }
+
+            if let Some(event) = self.buffer.pop_front() {
+                if let Some(cursor) = &mut self.cursor {
+                    match &event {
+                        WatchEvent::Added { event } => cursor.recent.push(event.clone()),
+                        WatchEvent::Removed { event } => {
+                            let id = event.id();
+                            cursor.recent.retain(|recent| recent.id() != id);
+                        }
+                    }
+                }
+                self.dirty = true;
+                return Ok(event);
+            }
+
+            if let (Some(next_block), Some(cursor)) = (self.batch_end.take(), &mut self.cursor) {
+                cursor.next_block = next_block;
+                let window_start = next_block.saturating_sub(self.builder.reorg_depth);
+                cursor.recent.retain(|event| event.block() >= window_start);
+                self.dirty = true;
+                continue;
+            }
+
+            if self.polled {
+                tokio::time::sleep(self.builder.poll_interval).await;
+            }
+            self.polled = true;
+            self.poll().await?;
+        }
+    }
+
+    /// Fetch the reorg window and confirmed new blocks into the buffer
+    async fn poll(&mut self) -> Result<()> {
+        let client = &self.builder.client;
+        let head = client.proxy().block_number().await?;
+        let safe_head = head.saturating_sub(self.builder.confirmations);
+
+        let cursor = match &mut self.cursor {
+            Some(cursor) => cursor,
+            None => {
+                let saved = match &self.builder.store {
+                    Some(store) => store.load(&self.key)?,
+                    None => None,
+                };
+                let fresh = || {
+                    let start_block = self.builder.start_block.unwrap_or(safe_head + 1);
+                    WatchCursor {
+                        next_block: start_block,
+                        recent: Vec::new(),
+                        start_block,
+                    }
+                };
+                self.cursor.insert(saved.unwrap_or_else(fresh))
+            }
+        };
+
+        let from = cursor
+            .next_block
+            .saturating_sub(self.builder.reorg_depth)
+            .max(cursor.start_block);
+        if safe_head < from {
+            return Ok(());
+        }
+
+        let current: Vec<ActivityEvent> = client
+            .accounts()
+            .activity(&self.builder.address)
+            .kinds(&self.builder.kinds)
+            .block_range(from, safe_head)
+            .execute()
+            .await?
+            .into_iter()
+            .flat_map(|group| group.events)
+            .collect();
+        let current_ids: HashSet<String> = current.iter().map(ActivityEvent::id).collect();
+        let known: HashSet<String> = cursor.recent.iter().map(ActivityEvent::id).collect();
+
+        // Events above the safe head are kept until they can be re-checked
+        let removed = cursor.recent.iter().filter(|event| {
+            (from..=safe_head).contains(&event.block()) && !current_ids.contains(&event.id())
+        });
+        self.buffer.extend(removed.map(|event| WatchEvent::Removed {
+            event: event.clone(),
+        }));
+        self.buffer.extend(
+            current
+                .into_iter()
+                .filter(|event| !known.contains(&event.id()))
+                .map(|event| WatchEvent::Added { event }),
+        );
+        self.batch_end = Some(cursor.next_block.max(safe_head + 1));
+
+        Ok(())
+    }
+}